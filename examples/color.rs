@@ -12,6 +12,7 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -65,6 +66,7 @@ impl Game for Colors {
                 },
                 position: Point::new(0.0, 0.0),
                 size: (500.0, 500.0),
+                ..Quad::default()
             },
             target,
         );
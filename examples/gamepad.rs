@@ -14,6 +14,7 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -15,6 +15,7 @@ pub fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -19,6 +19,8 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        samples: Some(4),
+        ..WindowSettings::default()
     })
 }
 
@@ -42,8 +44,10 @@ struct Example {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ShapeOption {
     Rectangle,
+    RoundedRectangle,
     Circle,
     Ellipse,
+    Hexagon,
     Polyline,
 }
 
@@ -103,6 +107,15 @@ impl Game for Example {
                 width: 200.0,
                 height: 100.0,
             }),
+            ShapeOption::RoundedRectangle => Shape::RoundedRectangle {
+                bounds: Rectangle {
+                    x: frame.width() / 4.0 - 100.0,
+                    y: frame.height() / 2.0 - 50.0,
+                    width: 200.0,
+                    height: 100.0,
+                },
+                radius: self.radius,
+            },
             ShapeOption::Circle => Shape::Circle {
                 center: Point::new(frame.width() / 4.0, frame.height() / 2.0),
                 radius: self.radius,
@@ -113,6 +126,12 @@ impl Game for Example {
                 vertical_radius: self.vertical_radius,
                 rotation: 0.0,
             },
+            ShapeOption::Hexagon => Shape::RegularPolygon {
+                center: Point::new(frame.width() / 4.0, frame.height() / 2.0),
+                radius: self.radius,
+                sides: 6,
+                rotation: 0.0,
+            },
             ShapeOption::Polyline => Shape::Polyline {
                 points: self.polyline_points.clone(),
             },
@@ -182,7 +201,15 @@ impl UserInterface for Example {
 
         match self.shape {
             ShapeOption::Rectangle => {}
-            ShapeOption::Circle => {
+            ShapeOption::RoundedRectangle => {
+                controls = controls.push(radius_slider(
+                    "Corner radius:",
+                    &mut self.radius_slider,
+                    self.radius,
+                    Message::RadiusChanged,
+                ));
+            }
+            ShapeOption::Circle | ShapeOption::Hexagon => {
                 controls = controls.push(radius_slider(
                     "Radius:",
                     &mut self.radius_slider,
@@ -244,8 +271,10 @@ enum Message {
 fn shape_selector(current: ShapeOption) -> Element<'static, Message> {
     let options = [
         ShapeOption::Rectangle,
+        ShapeOption::RoundedRectangle,
         ShapeOption::Circle,
         ShapeOption::Ellipse,
+        ShapeOption::Hexagon,
         ShapeOption::Polyline,
     ]
     .iter()
@@ -21,6 +21,7 @@ fn main() -> Result<()> {
         resizable: false,
         fullscreen: false,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -149,7 +150,7 @@ impl Game for Particles {
                     height: 1,
                 },
                 position: particle.position + velocity * delta_factor,
-                scale: (1.0, 1.0),
+                ..Sprite::default()
             }
         });
 
@@ -18,6 +18,7 @@ fn main() {
         resizable: false,
         maximized: false,
         fullscreen: false,
+        ..WindowSettings::default()
     })
     .expect("An error occured while starting the game");
 }
@@ -0,0 +1,256 @@
+//! Play sound effects and music.
+//!
+//! This module is only available when the `audio` feature is enabled. It uses
+//! [`rodio`], which mixes and plays sounds in its own thread.
+//!
+//! # Sounds
+//! A [`Sound`] contains encoded audio data. WAV, Vorbis, and FLAC files are
+//! supported. You can load a [`Sound`] alongside the rest of your assets with
+//! [`Sound::load`].
+//!
+//! # Buses
+//! Every sound is played on a [`Bus`]. Each [`Bus`] has its own volume, which
+//! allows you to control your music and your sound effects independently.
+//!
+//! [`rodio`]: https://github.com/RustAudio/rodio
+//! [`Sound`]: struct.Sound.html
+//! [`Sound::load`]: struct.Sound.html#method.load
+//! [`Bus`]: struct.Bus.html
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rodio::Source;
+
+use crate::graphics::Gpu;
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// A loaded sound.
+///
+/// Cloning a [`Sound`] is cheap, it only clones a handle to its data.
+///
+/// [`Sound`]: struct.Sound.html
+#[derive(Clone)]
+pub struct Sound {
+    data: Arc<[u8]>,
+}
+
+impl Sound {
+    /// Loads a [`Sound`] from the given path.
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    pub fn new<P: AsRef<Path>>(gpu: &Gpu, path: P) -> Result<Sound> {
+        Sound::from_bytes(gpu.read_asset(path)?)
+    }
+
+    /// Creates a [`Task`] that loads a [`Sound`] from the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Sound`]: struct.Sound.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Sound> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| Sound::new(gpu, &p))
+    }
+
+    /// Creates a [`Sound`] from encoded audio data.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Sound> {
+        let sound = Sound {
+            data: Arc::from(bytes),
+        };
+
+        // Make sure the data can be decoded before playing it
+        let _ = sound.decoder()?;
+
+        Ok(sound)
+    }
+
+    fn decoder(&self) -> Result<rodio::Decoder<Cursor<Arc<[u8]>>>> {
+        rodio::Decoder::new(Cursor::new(self.data.clone()))
+            .map_err(|error| Error::Audio(error.to_string()))
+    }
+}
+
+impl fmt::Debug for Sound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sound {{ bytes: {} }}", self.data.len())
+    }
+}
+
+/// A group of playing sounds sharing the same volume.
+///
+/// The default [`Bus`] is always available. You can create more with
+/// [`Audio::create_bus`].
+///
+/// [`Bus`]: struct.Bus.html
+/// [`Audio::create_bus`]: struct.Audio.html#method.create_bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bus(usize);
+
+/// A sound being played by [`Audio`].
+///
+/// [`Audio`]: struct.Audio.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Playback {
+    bus: Bus,
+    id: u64,
+}
+
+/// The audio output of your game.
+///
+/// Dropping the [`Audio`] stops all of its sounds.
+///
+/// [`Audio`]: struct.Audio.html
+pub struct Audio {
+    device: rodio::Device,
+    buses: Vec<BusState>,
+    next_id: u64,
+}
+
+struct BusState {
+    volume: f32,
+    sinks: HashMap<u64, rodio::Sink>,
+}
+
+impl BusState {
+    fn new() -> BusState {
+        BusState {
+            volume: 1.0,
+            sinks: HashMap::new(),
+        }
+    }
+}
+
+impl Audio {
+    /// Opens the default audio output device.
+    pub fn new() -> Result<Audio> {
+        let device = rodio::default_output_device().ok_or_else(|| {
+            Error::Audio(String::from("No audio output device available"))
+        })?;
+
+        Ok(Audio {
+            device,
+            buses: vec![BusState::new()],
+            next_id: 0,
+        })
+    }
+
+    /// Creates a new [`Bus`] with full volume.
+    ///
+    /// [`Bus`]: struct.Bus.html
+    pub fn create_bus(&mut self) -> Bus {
+        self.buses.push(BusState::new());
+
+        Bus(self.buses.len() - 1)
+    }
+
+    /// Plays a [`Sound`] once on the given [`Bus`].
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Bus`]: struct.Bus.html
+    pub fn play(&mut self, sound: &Sound, bus: Bus) -> Result<Playback> {
+        let source = sound.decoder()?;
+
+        Ok(self.append(bus, source))
+    }
+
+    /// Plays a [`Sound`] on the given [`Bus`], repeating it until it is
+    /// stopped.
+    ///
+    /// [`Sound`]: struct.Sound.html
+    /// [`Bus`]: struct.Bus.html
+    pub fn play_looping(
+        &mut self,
+        sound: &Sound,
+        bus: Bus,
+    ) -> Result<Playback> {
+        let source = sound.decoder()?.buffered().repeat_infinite();
+
+        Ok(self.append(bus, source))
+    }
+
+    /// Stops a [`Playback`].
+    ///
+    /// Nothing happens if it has already finished.
+    ///
+    /// [`Playback`]: struct.Playback.html
+    pub fn stop(&mut self, playback: Playback) {
+        let sinks = &mut self.bus_mut(playback.bus).sinks;
+
+        if let Some(sink) = sinks.remove(&playback.id) {
+            sink.stop();
+        }
+    }
+
+    /// Stops all the sounds playing on the given [`Bus`].
+    ///
+    /// [`Bus`]: struct.Bus.html
+    pub fn stop_bus(&mut self, bus: Bus) {
+        for (_, sink) in self.bus_mut(bus).sinks.drain() {
+            sink.stop();
+        }
+    }
+
+    /// Returns the volume of the given [`Bus`].
+    ///
+    /// [`Bus`]: struct.Bus.html
+    pub fn volume(&self, bus: Bus) -> f32 {
+        self.buses[bus.0].volume
+    }
+
+    /// Sets the volume of the given [`Bus`].
+    ///
+    /// `1.0` is the original volume of the sounds and `0.0` is silence. It
+    /// affects the sounds that are already playing.
+    ///
+    /// [`Bus`]: struct.Bus.html
+    pub fn set_volume(&mut self, bus: Bus, volume: f32) {
+        let bus = self.bus_mut(bus);
+
+        bus.volume = volume.max(0.0);
+
+        for sink in bus.sinks.values() {
+            sink.set_volume(bus.volume);
+        }
+    }
+
+    fn append<S>(&mut self, bus: Bus, source: S) -> Playback
+    where
+        S: Source + Send + 'static,
+        S::Item: rodio::Sample + Send,
+    {
+        let sink = rodio::Sink::new(&self.device);
+        let id = self.next_id;
+        let state = &mut self.buses[bus.0];
+
+        // Forget the sounds that have already finished
+        state.sinks.retain(|_, sink| !sink.empty());
+
+        sink.set_volume(state.volume);
+        sink.append(source);
+
+        let _ = state.sinks.insert(id, sink);
+        self.next_id += 1;
+
+        Playback { bus, id }
+    }
+
+    fn bus_mut(&mut self, bus: Bus) -> &mut BusState {
+        &mut self.buses[bus.0]
+    }
+}
+
+impl fmt::Debug for Audio {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Audio {{ buses: {} }}", self.buses.len())
+    }
+}
@@ -33,7 +33,10 @@ pub struct Debug {
     ui_durations: TimeBuffer,
     debug_start: time::Instant,
     debug_durations: TimeBuffer,
+    frame_stats: graphics::FrameStats,
+    shader_error: Option<String>,
     text: Vec<(String, String)>,
+    adapter_name: String,
     draw_rate: u16,
     frames_until_refresh: u16,
 }
@@ -60,7 +63,10 @@ impl Debug {
             ui_durations: TimeBuffer::new(200),
             debug_start: now,
             debug_durations: TimeBuffer::new(200),
+            frame_stats: graphics::FrameStats::default(),
+            shader_error: None,
             text: Vec::new(),
+            adapter_name: gpu.adapter_name().to_owned(),
             draw_rate: 10,
             frames_until_refresh: 0,
         }
@@ -183,6 +189,30 @@ impl Debug {
         self.debug_durations.average()
     }
 
+    pub(crate) fn frame_stats_updated(&mut self, stats: graphics::FrameStats) {
+        self.frame_stats = stats;
+    }
+
+    /// Returns the [`FrameStats`] of the last presented frame.
+    ///
+    /// [`FrameStats`]: graphics/struct.FrameStats.html
+    pub fn frame_stats(&self) -> graphics::FrameStats {
+        self.frame_stats
+    }
+
+    pub(crate) fn shader_error_updated(&mut self, error: Option<&str>) {
+        if self.shader_error.as_ref().map(String::as_str) != error {
+            self.shader_error = error.map(String::from);
+        }
+    }
+
+    /// Returns the last error produced by [`Shader::hot_reload`], if any.
+    ///
+    /// [`Shader::hot_reload`]: graphics/struct.Shader.html#method.hot_reload
+    pub fn shader_error(&self) -> Option<&str> {
+        self.shader_error.as_ref().map(String::as_str)
+    }
+
     pub(crate) fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -220,6 +250,7 @@ impl Debug {
             ("Draw:", self.draw_duration(), None),
             ("UI:", self.ui_duration(), None),
             ("Debug:", self.debug_duration(), None),
+            ("Present:", self.frame_stats.present_cpu_time, None),
             ("Frame:", frame_duration, Some(fps.to_string() + " fps")),
         ];
 
@@ -231,6 +262,27 @@ impl Debug {
 
             self.text.push((String::from(*title), formatted_duration));
         }
+
+        let stats = self.frame_stats;
+
+        self.text.push((
+            String::from("Draw calls:"),
+            format!(
+                "{} ({} quads, {} triangles)",
+                stats.draw_calls, stats.quads, stats.triangles
+            ),
+        ));
+
+        self.text
+            .push((String::from("GPU:"), self.adapter_name.clone()));
+
+        if let Some(error) = &self.shader_error {
+            for (i, line) in error.lines().enumerate() {
+                let title = if i == 0 { "Shader:" } else { "" };
+
+                self.text.push((String::from(title), String::from(line)));
+            }
+        }
     }
 
     fn draw_text(&mut self, frame: &mut graphics::Frame<'_>) {
@@ -24,6 +24,8 @@ impl Debug {
     pub(crate) fn ui_finished(&mut self) {}
     pub(crate) fn debug_started(&mut self) {}
     pub(crate) fn debug_finished(&mut self) {}
+    pub(crate) fn frame_stats_updated(&mut self, _: graphics::FrameStats) {}
+    pub(crate) fn shader_error_updated(&mut self, _: Option<&str>) {}
 
     #[allow(dead_code)]
     pub(crate) fn toggle(&mut self) {}
@@ -32,6 +34,16 @@ impl Debug {
         false
     }
 
+    #[allow(missing_docs)]
+    pub fn frame_stats(&self) -> graphics::FrameStats {
+        graphics::FrameStats::default()
+    }
+
+    #[allow(missing_docs)]
+    pub fn shader_error(&self) -> Option<&str> {
+        None
+    }
+
     #[allow(missing_docs)]
     pub fn draw(&mut self, _frame: &mut graphics::Frame<'_>) {}
 }
@@ -0,0 +1,228 @@
+//! Ask the player to pick files with the native dialogs of the system.
+//!
+//! Dialogs run in a background thread, so the game keeps drawing while they
+//! are open. Opening a dialog returns a [`Pending`] result that you can poll
+//! in [`Game::interact`] or turn into a message of your [`UserInterface`].
+//!
+//! ```no_run
+//! use coffee::dialogs::FileDialog;
+//!
+//! let mut pending = FileDialog::new()
+//!     .title("Import sprite")
+//!     .filter("Images", &["*.png", "*.jpg"])
+//!     .open();
+//!
+//! // Later, once per frame...
+//! if let Some(path) = pending.poll() {
+//!     // `path` is `None` if the player cancelled the dialog
+//! }
+//! ```
+//!
+//! This module is only available with the `dialogs` feature.
+//!
+//! [`Pending`]: struct.Pending.html
+//! [`Game::interact`]: ../trait.Game.html#method.interact
+//! [`UserInterface`]: ../ui/trait.UserInterface.html
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+
+/// A native file dialog.
+///
+/// Configure it with its builder methods and show it with [`open`],
+/// [`open_multiple`], [`save`], or [`pick_directory`].
+///
+/// [`open`]: #method.open
+/// [`open_multiple`]: #method.open_multiple
+/// [`save`]: #method.save
+/// [`pick_directory`]: #method.pick_directory
+#[derive(Debug, Clone, Default)]
+pub struct FileDialog {
+    title: String,
+    directory: Option<PathBuf>,
+    filter: Option<(String, Vec<String>)>,
+}
+
+impl FileDialog {
+    /// Creates a new [`FileDialog`] without a title nor a filter.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn new() -> FileDialog {
+        FileDialog::default()
+    }
+
+    /// Sets the title of the [`FileDialog`].
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn title(mut self, title: &str) -> FileDialog {
+        self.title = String::from(title);
+        self
+    }
+
+    /// Sets the directory the [`FileDialog`] starts in.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn directory<P: AsRef<Path>>(mut self, directory: P) -> FileDialog {
+        self.directory = Some(directory.as_ref().to_path_buf());
+        self
+    }
+
+    /// Only shows the files matching the given patterns, like `"*.png"`,
+    /// under the given description.
+    pub fn filter(
+        mut self,
+        description: &str,
+        patterns: &[&str],
+    ) -> FileDialog {
+        self.filter = Some((
+            String::from(description),
+            patterns
+                .iter()
+                .map(|pattern| String::from(*pattern))
+                .collect(),
+        ));
+        self
+    }
+
+    /// Shows the [`FileDialog`] to pick an existing file.
+    ///
+    /// The result is `None` if the player cancels the dialog.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn open(self) -> Pending<Option<PathBuf>> {
+        Pending::spawn(move || {
+            let path = self.default_path(None);
+            let filter = self.patterns();
+
+            tinyfiledialogs::open_file_dialog(
+                &self.title,
+                &path,
+                filter.as_ref().map(|(patterns, description)| {
+                    (patterns.as_slice(), *description)
+                }),
+            )
+            .map(PathBuf::from)
+        })
+    }
+
+    /// Shows the [`FileDialog`] to pick multiple existing files.
+    ///
+    /// The result is `None` if the player cancels the dialog.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn open_multiple(self) -> Pending<Option<Vec<PathBuf>>> {
+        Pending::spawn(move || {
+            let path = self.default_path(None);
+            let filter = self.patterns();
+
+            tinyfiledialogs::open_file_dialog_multi(
+                &self.title,
+                &path,
+                filter.as_ref().map(|(patterns, description)| {
+                    (patterns.as_slice(), *description)
+                }),
+            )
+            .map(|paths| paths.into_iter().map(PathBuf::from).collect())
+        })
+    }
+
+    /// Shows the [`FileDialog`] to choose where to save a file, suggesting
+    /// the given file name.
+    ///
+    /// The file is not created. The result is `None` if the player cancels
+    /// the dialog.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn save(self, file_name: &str) -> Pending<Option<PathBuf>> {
+        let file_name = String::from(file_name);
+
+        Pending::spawn(move || {
+            let path = self.default_path(Some(&file_name));
+
+            let result = match self.patterns() {
+                Some((patterns, description)) => {
+                    tinyfiledialogs::save_file_dialog_with_filter(
+                        &self.title,
+                        &path,
+                        &patterns,
+                        description,
+                    )
+                }
+                None => tinyfiledialogs::save_file_dialog(&self.title, &path),
+            };
+
+            result.map(PathBuf::from)
+        })
+    }
+
+    /// Shows the [`FileDialog`] to pick a directory.
+    ///
+    /// The filter is ignored. The result is `None` if the player cancels the
+    /// dialog.
+    ///
+    /// [`FileDialog`]: struct.FileDialog.html
+    pub fn pick_directory(self) -> Pending<Option<PathBuf>> {
+        Pending::spawn(move || {
+            let path = self.default_path(None);
+
+            tinyfiledialogs::select_folder_dialog(&self.title, &path)
+                .map(PathBuf::from)
+        })
+    }
+
+    fn default_path(&self, file_name: Option<&str>) -> String {
+        let path = match (&self.directory, file_name) {
+            (Some(directory), Some(file_name)) => directory.join(file_name),
+            (Some(directory), None) => directory.clone(),
+            (None, Some(file_name)) => PathBuf::from(file_name),
+            (None, None) => PathBuf::new(),
+        };
+
+        path.to_string_lossy().into_owned()
+    }
+
+    fn patterns(&self) -> Option<(Vec<&str>, &str)> {
+        self.filter.as_ref().map(|(description, patterns)| {
+            (
+                patterns.iter().map(String::as_str).collect(),
+                description.as_str(),
+            )
+        })
+    }
+}
+
+/// The result of a dialog that may still be open.
+///
+/// Call [`poll`] once per frame until it returns the result.
+///
+/// [`poll`]: #method.poll
+#[derive(Debug)]
+pub struct Pending<T> {
+    receiver: mpsc::Receiver<T>,
+}
+
+impl<T: Send + 'static> Pending<T> {
+    fn spawn<F>(dialog: F) -> Pending<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+
+        let _ = thread::spawn(move || {
+            // The receiver may be gone if the `Pending` result was dropped
+            let _ = sender.send(dialog());
+        });
+
+        Pending { receiver }
+    }
+}
+
+impl<T> Pending<T> {
+    /// Returns the result of the dialog, if it has been closed.
+    ///
+    /// The result is only returned once. After that, it always returns
+    /// `None`.
+    pub fn poll(&mut self) -> Option<T> {
+        self.receiver.try_recv().ok()
+    }
+}
@@ -1,12 +1,20 @@
+mod close_behavior;
+mod frame_limiter;
 mod r#loop;
 
+pub use close_behavior::CloseBehavior;
 pub(crate) use r#loop::Loop;
 
-use crate::graphics::{CursorIcon, Frame, Window, WindowSettings};
+use crate::graphics::{
+    CursorIcon, Frame, SecondaryWindow, Window, WindowSettings,
+};
 use crate::input::{keyboard, Input};
 use crate::load::{LoadingScreen, Task};
 use crate::{Debug, Result, Timer};
 
+#[cfg(feature = "raw")]
+use crate::graphics::window::winit;
+
 /// The entrypoint of the engine. It describes your game logic.
 ///
 /// Implementors of this trait should hold the game state and any assets
@@ -81,6 +89,25 @@ pub trait Game {
     /// [`update`]: #method.update
     fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer);
 
+    /// Draws a secondary window of the [`Game`].
+    ///
+    /// This function will be called once per frame for every open secondary
+    /// window, right after [`draw`]. Open secondary windows with
+    /// [`Window::spawn_secondary`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`draw`]: #tymethod.draw
+    /// [`Window::spawn_secondary`]: graphics/struct.Window.html#method.spawn_secondary
+    fn draw_secondary(
+        &mut self,
+        _window: SecondaryWindow,
+        _frame: &mut Frame<'_>,
+        _timer: &Timer,
+    ) {
+    }
+
     /// Consumes [`Input`] to let users interact with the [`Game`].
     ///
     /// Right before an [`update`], input events will be processed and this
@@ -140,11 +167,14 @@ pub trait Game {
     /// the `debug` feature enabled.
     ///
     /// By default, it shows [`Debug`], which displays a brief summary about
-    /// game performance in the top left corner.
+    /// game performance in the top left corner. [`Debug`] also gives you
+    /// access to the timings and the [`FrameStats`] of the last frame, so you
+    /// can build your own debug overlay here.
     ///
     /// [`draw`]: #tymethod.draw
     /// [`DEBUG_KEY`]: #associatedconstant.DEBUG_KEY
     /// [`Debug`]: struct.Debug.html
+    /// [`FrameStats`]: graphics/struct.FrameStats.html
     fn debug(
         &self,
         _input: &Self::Input,
@@ -154,21 +184,123 @@ pub trait Game {
         debug.draw(frame);
     }
 
+    /// Handles a close request from the operating system to the game window.
+    ///
+    /// Return [`CloseBehavior::Keep`] to keep the window open. For instance,
+    /// you can show a "save before quitting?" prompt and call
+    /// [`Window::request_exit`] once the player answers.
+    ///
+    /// By default, it calls the deprecated [`on_close_request`] and returns
+    /// [`CloseBehavior::Close`] if it returns `true`, or
+    /// [`CloseBehavior::Keep`] otherwise. Therefore, games implementing
+    /// [`on_close_request`] keep working.
+    ///
+    /// ```
+    /// # use coffee::graphics::{Frame, Window};
+    /// # use coffee::load::Task;
+    /// # use coffee::{CloseBehavior, Game, Timer};
+    /// #
+    /// struct Editor {
+    ///     has_unsaved_changes: bool,
+    ///     is_quit_prompt_visible: bool,
+    /// }
+    ///
+    /// impl Game for Editor {
+    /// #     type Input = ();
+    /// #     type LoadingScreen = ();
+    /// #
+    /// #     fn load(_window: &Window) -> Task<Editor> {
+    /// #         Task::succeed(|| Editor {
+    /// #             has_unsaved_changes: false,
+    /// #             is_quit_prompt_visible: false,
+    /// #         })
+    /// #     }
+    /// #
+    /// #     fn draw(&mut self, _frame: &mut Frame<'_>, _timer: &Timer) {}
+    /// #
+    ///     fn on_close_requested(&mut self) -> CloseBehavior {
+    ///         if self.has_unsaved_changes {
+    ///             // Ask the player first and call `Window::request_exit`
+    ///             // once they answer
+    ///             self.is_quit_prompt_visible = true;
+    ///
+    ///             CloseBehavior::Keep
+    ///         } else {
+    ///             CloseBehavior::Close
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`CloseBehavior::Keep`]: enum.CloseBehavior.html#variant.Keep
+    /// [`CloseBehavior::Close`]: enum.CloseBehavior.html#variant.Close
+    /// [`Window::request_exit`]: graphics/struct.Window.html#method.request_exit
+    /// [`on_close_request`]: #method.on_close_request
+    fn on_close_requested(&mut self) -> CloseBehavior {
+        #[allow(deprecated)]
+        let should_close = self.on_close_request();
+
+        if should_close {
+            CloseBehavior::Close
+        } else {
+            CloseBehavior::Keep
+        }
+    }
+
     /// Handles a close request from the operating system to the game window.
     ///
     /// This function should return true to allow the game loop to end,
     /// otherwise false.
     ///
     /// By default, it does nothing and returns true.
+    ///
+    /// Implement [`on_close_requested`] instead. Returning `false` here is
+    /// the same as returning [`CloseBehavior::Keep`] there.
+    ///
+    /// [`on_close_requested`]: #method.on_close_requested
+    /// [`CloseBehavior::Keep`]: enum.CloseBehavior.html#variant.Keep
+    #[deprecated(since = "0.5.0", note = "use `on_close_requested` instead")]
     fn on_close_request(&mut self) -> bool {
         true
     }
 
+    /// Handles a raw event of the event loop, before Coffee processes it.
+    ///
+    /// Use it to consume events that Coffee does not model yet, like pen
+    /// input or device events. It is called for every event, including the
+    /// ones of secondary windows.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// This method is only available with the `raw` feature.
+    #[cfg(feature = "raw")]
+    fn on_raw_event(&mut self, _event: &winit::event::Event<'_, ()>) {}
+
+    /// Returns whether the game is paused or not.
+    ///
+    /// While paused, the [`Timer`] stops ticking and [`update`] is not called.
+    /// However, [`interact`] and [`draw`] keep running, allowing you to show a
+    /// responsive pause menu. Time spent paused is discarded, so there is no
+    /// burst of updates when the game is resumed.
+    ///
+    /// By default, it always returns false.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`update`]: #method.update
+    /// [`interact`]: #method.interact
+    /// [`draw`]: #tymethod.draw
+    fn is_paused(&self) -> bool {
+        false
+    }
+
     /// Returns whether the game is finished or not.
     ///
-    /// If this function returns true, the game will be closed gracefully.
+    /// If this function returns true, the game will be closed gracefully. You
+    /// can also close the game with [`Window::request_exit`].
     ///
     /// By default, it always returns false.
+    ///
+    /// [`Window::request_exit`]: graphics/struct.Window.html#method.request_exit
     fn is_finished(&self) -> bool {
         false
     }
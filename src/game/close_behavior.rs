@@ -0,0 +1,18 @@
+/// What to do when the window of a [`Game`] is requested to close.
+///
+/// It is returned by [`Game::on_close_requested`].
+///
+/// [`Game`]: trait.Game.html
+/// [`Game::on_close_requested`]: trait.Game.html#method.on_close_requested
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseBehavior {
+    /// The game loop ends and the window is closed.
+    Close,
+
+    /// The window is kept open.
+    ///
+    /// You can close it later with [`Window::request_exit`].
+    ///
+    /// [`Window::request_exit`]: graphics/struct.Window.html#method.request_exit
+    Keep,
+}
@@ -0,0 +1,105 @@
+use std::thread;
+use std::time;
+
+// Sleeping is imprecise in most platforms. We sleep until we are close
+// enough to the deadline and then spin for the remaining time.
+const SPIN_THRESHOLD: time::Duration = time::Duration::from_millis(2);
+
+#[derive(Debug)]
+pub(crate) struct FrameLimiter {
+    last_frame: time::Instant,
+}
+
+impl FrameLimiter {
+    pub(crate) fn new() -> FrameLimiter {
+        FrameLimiter {
+            last_frame: time::Instant::now(),
+        }
+    }
+
+    pub(crate) fn wait(&mut self, max_frames_per_second: Option<u16>) {
+        let frames_per_second = match max_frames_per_second {
+            Some(fps) if fps > 0 => fps,
+            _ => {
+                self.last_frame = time::Instant::now();
+                return;
+            }
+        };
+
+        let target_delta =
+            time::Duration::from_secs(1) / u32::from(frames_per_second);
+        let deadline = self.last_frame + target_delta;
+
+        loop {
+            let now = time::Instant::now();
+
+            if now >= deadline {
+                break;
+            }
+
+            let remaining = deadline - now;
+
+            if remaining > SPIN_THRESHOLD {
+                thread::sleep(remaining - SPIN_THRESHOLD);
+            } else {
+                thread::yield_now();
+            }
+        }
+
+        let now = time::Instant::now();
+
+        // If we are running behind by more than a frame, we avoid trying to
+        // catch up by drawing a burst of frames.
+        self.last_frame = if now - deadline > target_delta {
+            now
+        } else {
+            deadline
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wait_keeps_the_frame_deadline_when_on_time() {
+        let start = time::Instant::now();
+        let mut frame_limiter = FrameLimiter { last_frame: start };
+
+        frame_limiter.wait(Some(10));
+
+        assert!(
+            time::Instant::now() - start >= time::Duration::from_millis(100)
+        );
+        assert_eq!(
+            frame_limiter.last_frame,
+            start + time::Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn wait_resets_when_behind_by_more_than_a_frame() {
+        let now = time::Instant::now();
+        let mut frame_limiter = FrameLimiter {
+            last_frame: now - time::Duration::from_secs(1),
+        };
+
+        frame_limiter.wait(Some(10));
+
+        assert!(frame_limiter.last_frame >= now);
+    }
+
+    #[test]
+    fn wait_resets_without_a_limit() {
+        let now = time::Instant::now();
+        let mut frame_limiter = FrameLimiter {
+            last_frame: now - time::Duration::from_secs(1),
+        };
+
+        frame_limiter.wait(None);
+
+        assert!(frame_limiter.last_frame >= now);
+        assert!(time::Instant::now() - now < time::Duration::from_millis(100));
+    }
+}
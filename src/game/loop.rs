@@ -1,11 +1,19 @@
 use crate::debug::Debug;
 use crate::graphics::window::winit;
-use crate::graphics::{Window, WindowSettings};
-use crate::input::{self, gamepad, keyboard, mouse, window, Input};
+use crate::graphics::{Point, Window, WindowSettings};
+use crate::input::{
+    self, keyboard, mouse, touch, window, Input, SecondaryEvent,
+};
 use crate::load::{Join, LoadingScreen, Task};
 use crate::{Result, Timer};
+
+use super::frame_limiter::FrameLimiter;
+use super::CloseBehavior;
 use std::convert::TryInto;
 
+#[cfg(feature = "gamepad")]
+use crate::input::gamepad;
+
 pub trait Loop<Game: super::Game> {
     type Attributes;
 
@@ -54,103 +62,307 @@ pub trait Loop<Game: super::Game> {
 
         let mut game_loop = Self::new(configuration, &mut game, &mut window);
         let mut input = Game::Input::new();
+        #[cfg(feature = "gamepad")]
         let mut gamepads = gamepad::Tracker::new();
         debug.loading_finished();
 
         let mut timer = Timer::new(Game::TICKS_PER_SECOND);
+        let mut frame_limiter = FrameLimiter::new();
+        let mut is_suspended = false;
 
         // Initialization
         debug.frame_started();
         timer.update();
 
-        event_loop.run(move |event, _, control_flow| match event {
-            winit::event::Event::NewEvents(_) => {
-                debug.interact_started();
-            }
-            winit::event::Event::MainEventsCleared => {
-                if let Some(tracker) = &mut gamepads {
-                    while let Some((id, event, time)) = tracker.next_event() {
+        event_loop.run(move |event, target, control_flow| {
+            #[cfg(feature = "raw")]
+            game.on_raw_event(&event);
+
+            match event {
+                winit::event::Event::NewEvents(_) => {
+                    debug.interact_started();
+                }
+                winit::event::Event::Suspended => {
+                    is_suspended = true;
+
+                    game_loop.on_input(
+                        &mut input,
+                        input::Event::Window(window::Event::Suspended),
+                    );
+                }
+                winit::event::Event::Resumed => {
+                    is_suspended = false;
+
+                    game_loop.on_input(
+                        &mut input,
+                        input::Event::Window(window::Event::Resumed),
+                    );
+                }
+                winit::event::Event::MainEventsCleared => {
+                    for secondary in window.open_secondaries(target) {
                         game_loop.on_input(
                             &mut input,
-                            input::Event::Gamepad { id, event, time },
+                            input::Event::Secondary {
+                                window: secondary,
+                                event: SecondaryEvent::Closed,
+                            },
                         );
                     }
-                }
 
-                game.interact(&mut input, &mut window);
-                input.clear();
-                debug.interact_finished();
+                    #[cfg(feature = "gamepad")]
+                    {
+                        if let Some(tracker) = &mut gamepads {
+                            while let Some((id, event, time)) =
+                                tracker.next_event()
+                            {
+                                game_loop.on_input(
+                                    &mut input,
+                                    input::Event::Gamepad { id, event, time },
+                                );
+                            }
+                        }
+                    }
 
-                if timer.tick() {
-                    debug.update_started();
-                    game.update(&window);
-                    debug.update_finished();
-                }
+                    game.interact(&mut input, &mut window);
+                    input.clear();
+                    debug.interact_finished();
 
-                window.request_redraw();
+                    if (game.is_paused() || is_suspended) != timer.is_paused() {
+                        if timer.is_paused() {
+                            timer.resume();
+                        } else {
+                            timer.pause();
+                        }
+                    }
 
-                if game.is_finished() {
-                    *control_flow = winit::event_loop::ControlFlow::Exit;
-                }
-            }
-            winit::event::Event::RedrawRequested { .. } => {
-                debug.draw_started();
-                game.draw(&mut window.frame(), &timer);
-                debug.draw_finished();
-
-                game_loop.after_draw(
-                    &mut game,
-                    &mut input,
-                    &mut window,
-                    &mut debug,
-                );
-
-                if debug.is_enabled() {
-                    debug.debug_started();
-                    game.debug(&input, &mut window.frame(), &mut debug);
-                    debug.debug_finished();
-                }
+                    if timer.tick() {
+                        debug.update_started();
+                        game.update(&window);
+                        debug.update_finished();
+                    }
 
-                window.swap_buffers();
-                debug.frame_finished();
+                    if !is_suspended {
+                        window.request_redraw();
+                    }
 
-                debug.frame_started();
-                window.request_redraw();
-                timer.update();
-            }
-            winit::event::Event::WindowEvent { event, .. } => match event {
-                winit::event::WindowEvent::CloseRequested => {
-                    if game.on_close_request() {
+                    if game.is_finished() || window.is_exit_requested() {
                         *control_flow = winit::event_loop::ControlFlow::Exit;
                     }
                 }
-                winit::event::WindowEvent::Resized(logical_size) => {
-                    window.resize(logical_size);
+                winit::event::Event::RedrawRequested(id)
+                    if id == window.id() && !is_suspended =>
+                {
+                    debug.draw_started();
+                    game.draw(&mut window.frame(), &timer);
+                    debug.draw_finished();
+
+                    game_loop.after_draw(
+                        &mut game,
+                        &mut input,
+                        &mut window,
+                        &mut debug,
+                    );
+
+                    if debug.is_enabled() {
+                        debug.debug_started();
+                        game.debug(&input, &mut window.frame(), &mut debug);
+                        debug.debug_finished();
+                    }
+
+                    window.draw_custom_cursor();
+
+                    for secondary in window.secondary_windows() {
+                        if let Some(mut frame) =
+                            window.secondary_frame(secondary)
+                        {
+                            game.draw_secondary(secondary, &mut frame, &timer);
+                        }
+                    }
+
+                    window.swap_buffers();
+                    debug.frame_stats_updated(window.gpu().last_frame_stats());
+                    debug.shader_error_updated(window.gpu().shader_error());
+                    frame_limiter.wait(window.max_frames_per_second());
+                    debug.frame_finished();
+
+                    debug.frame_started();
+                    window.request_redraw();
+                    timer.update();
                 }
-                _ => {
-                    match event {
-                        winit::event::WindowEvent::KeyboardInput {
-                            input:
-                                winit::event::KeyboardInput {
-                                    virtual_keycode,
-                                    state: winit::event::ElementState::Released,
-                                    ..
-                                },
-                            ..
-                        } if Game::DEBUG_KEY.is_some() => {
-                            if virtual_keycode == Game::DEBUG_KEY {
-                                debug.toggle();
+                winit::event::Event::WindowEvent { window_id, event }
+                    if window_id != window.id() =>
+                {
+                    if let Some(secondary) = window.find_secondary(window_id) {
+                        let secondary_event = match event {
+                            winit::event::WindowEvent::CloseRequested => {
+                                window.close_secondary(secondary);
+
+                                Some(SecondaryEvent::Closed)
                             }
+                            winit::event::WindowEvent::Resized(size) => {
+                                window.resize_secondary(secondary, size);
+
+                                None
+                            }
+                            winit::event::WindowEvent::ScaleFactorChanged {
+                                scale_factor,
+                                new_inner_size,
+                            } => {
+                                window.update_secondary_scale_factor(
+                                    secondary,
+                                    scale_factor as f32,
+                                );
+                                window.resize_secondary(
+                                    secondary,
+                                    *new_inner_size,
+                                );
+
+                                Some(SecondaryEvent::Window(
+                                    window::Event::ScaleFactorChanged {
+                                        scale_factor: scale_factor as f32,
+                                    },
+                                ))
+                            }
+                            _ => {
+                                let scale_factor = window
+                                    .secondary_scale_factor(secondary)
+                                    .unwrap_or(1.0);
+
+                                try_into_input_event(event)
+                                    .map(|event| {
+                                        into_logical(
+                                            event,
+                                            scale_factor,
+                                            window.dpi_scaling(),
+                                        )
+                                    })
+                                    .and_then(try_into_secondary_event)
+                            }
+                        };
+
+                        if let Some(event) = secondary_event {
+                            game_loop.on_input(
+                                &mut input,
+                                input::Event::Secondary {
+                                    window: secondary,
+                                    event,
+                                },
+                            );
+                        }
+                    }
+                }
+                winit::event::Event::WindowEvent { event, .. } => match event {
+                    winit::event::WindowEvent::CloseRequested => {
+                        if game.on_close_requested() == CloseBehavior::Close {
+                            *control_flow =
+                                winit::event_loop::ControlFlow::Exit;
                         }
-                        _ => {}
                     }
+                    winit::event::WindowEvent::Resized(new_size) => {
+                        let is_final = window.constrain_size(new_size);
 
-                    if let Some(input_event) = try_into_input_event(event) {
-                        game_loop.on_input(&mut input, input_event);
+                        window.resize(new_size);
+
+                        if is_final {
+                            game_loop.on_input(
+                                &mut input,
+                                input::Event::Window(window::Event::Resized {
+                                    width: window.width(),
+                                    height: window.height(),
+                                }),
+                            );
+                        }
                     }
-                }
-            },
-            _ => {}
+                    winit::event::WindowEvent::HoveredFile(path) => {
+                        window.hover_file(path);
+
+                        game_loop.on_input(
+                            &mut input,
+                            input::Event::Window(window::Event::FileHovered),
+                        );
+                    }
+                    winit::event::WindowEvent::HoveredFileCancelled => {
+                        window.cancel_file_hover();
+
+                        game_loop.on_input(
+                            &mut input,
+                            input::Event::Window(
+                                window::Event::FileHoverCancelled,
+                            ),
+                        );
+                    }
+                    winit::event::WindowEvent::DroppedFile(path) => {
+                        window.drop_file(path);
+
+                        game_loop.on_input(
+                            &mut input,
+                            input::Event::Window(window::Event::FileDropped),
+                        );
+                    }
+                    winit::event::WindowEvent::ScaleFactorChanged {
+                        scale_factor,
+                        new_inner_size,
+                    } => {
+                        window.update_scale_factor(scale_factor as f32);
+                        window.resize(*new_inner_size);
+
+                        game_loop.on_input(
+                            &mut input,
+                            input::Event::Window(
+                                window::Event::ScaleFactorChanged {
+                                    scale_factor: scale_factor as f32,
+                                },
+                            ),
+                        );
+                    }
+                    _ => {
+                        match event {
+                            winit::event::WindowEvent::KeyboardInput {
+                                input:
+                                    winit::event::KeyboardInput {
+                                        virtual_keycode,
+                                        state: input::ButtonState::Released,
+                                        ..
+                                    },
+                                ..
+                            } if Game::DEBUG_KEY.is_some() => {
+                                if virtual_keycode == Game::DEBUG_KEY {
+                                    debug.toggle();
+                                }
+                            }
+                            winit::event::WindowEvent::CursorMoved {
+                                position,
+                                ..
+                            } => {
+                                window.update_cursor_position(Some(
+                                    Point::new(
+                                        position.x as f32,
+                                        position.y as f32,
+                                    ),
+                                ));
+                            }
+                            winit::event::WindowEvent::CursorLeft {
+                                ..
+                            } => {
+                                window.update_cursor_position(None);
+                            }
+                            _ => {}
+                        }
+
+                        if let Some(input_event) = try_into_input_event(event) {
+                            game_loop.on_input(
+                                &mut input,
+                                into_logical(
+                                    input_event,
+                                    window.scale_factor(),
+                                    window.dpi_scaling(),
+                                ),
+                            );
+                        }
+                    }
+                },
+                _ => {}
+            }
         });
     }
 }
@@ -186,7 +398,12 @@ fn try_into_input_event(
                     delta_y: y,
                 }))
             }
-            _ => None,
+            winit::event::MouseScrollDelta::PixelDelta(position) => {
+                Some(input::Event::Mouse(mouse::Event::WheelScrolledPixels {
+                    delta_x: position.x as f32,
+                    delta_y: position.y as f32,
+                }))
+            }
         },
         winit::event::WindowEvent::CursorMoved { position, .. } => {
             Some(input::Event::Mouse(mouse::Event::CursorMoved {
@@ -194,6 +411,29 @@ fn try_into_input_event(
                 y: position.y as f32,
             }))
         }
+        winit::event::WindowEvent::Touch(winit::event::Touch {
+            phase,
+            location,
+            id,
+            ..
+        }) => {
+            let (x, y) = (location.x as f32, location.y as f32);
+
+            Some(input::Event::Touch(match phase {
+                winit::event::TouchPhase::Started => {
+                    touch::Event::Started { id, x, y }
+                }
+                winit::event::TouchPhase::Moved => {
+                    touch::Event::Moved { id, x, y }
+                }
+                winit::event::TouchPhase::Ended => {
+                    touch::Event::Ended { id, x, y }
+                }
+                winit::event::TouchPhase::Cancelled => {
+                    touch::Event::Cancelled { id, x, y }
+                }
+            }))
+        }
         winit::event::WindowEvent::CursorEntered { .. } => {
             Some(input::Event::Mouse(mouse::Event::CursorEntered))
         }
@@ -215,6 +455,68 @@ fn try_into_input_event(
     }
 }
 
+fn into_logical(
+    event: input::Event,
+    scale_factor: f32,
+    dpi_scaling: bool,
+) -> input::Event {
+    let scale = if dpi_scaling { scale_factor } else { 1.0 };
+
+    match event {
+        input::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+            input::Event::Mouse(mouse::Event::CursorMoved {
+                x: x / scale,
+                y: y / scale,
+            })
+        }
+        input::Event::Mouse(mouse::Event::WheelScrolledPixels {
+            delta_x,
+            delta_y,
+        }) => {
+            // Pixel deltas are already logical, so they only need to be
+            // scaled when DPI scaling is disabled
+            let scale = scale_factor / scale;
+
+            input::Event::Mouse(mouse::Event::WheelScrolledPixels {
+                delta_x: delta_x * scale,
+                delta_y: delta_y * scale,
+            })
+        }
+        input::Event::Touch(event) => input::Event::Touch(match event {
+            touch::Event::Started { id, x, y } => touch::Event::Started {
+                id,
+                x: x / scale,
+                y: y / scale,
+            },
+            touch::Event::Moved { id, x, y } => touch::Event::Moved {
+                id,
+                x: x / scale,
+                y: y / scale,
+            },
+            touch::Event::Ended { id, x, y } => touch::Event::Ended {
+                id,
+                x: x / scale,
+                y: y / scale,
+            },
+            touch::Event::Cancelled { id, x, y } => touch::Event::Cancelled {
+                id,
+                x: x / scale,
+                y: y / scale,
+            },
+        }),
+        _ => event,
+    }
+}
+
+fn try_into_secondary_event(event: input::Event) -> Option<SecondaryEvent> {
+    match event {
+        input::Event::Keyboard(event) => Some(SecondaryEvent::Keyboard(event)),
+        input::Event::Mouse(event) => Some(SecondaryEvent::Mouse(event)),
+        input::Event::Window(event) => Some(SecondaryEvent::Window(event)),
+        _ => None,
+    }
+}
+
 pub struct Default {}
 
 impl<Game: super::Game> Loop<Game> for Default
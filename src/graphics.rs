@@ -15,9 +15,10 @@
 //! is necessary to perform any kind of graphical operation, like loading
 //! resources and drawing.
 //!
-//! As of now, you will only have one [`Gpu`] available at a given time.
-//! However, in the future, the graphics module may allow recording graphical
-//! operations concurrently.
+//! As of now, you will only have one [`Gpu`] available at a given time. It is
+//! shared by the main [`Window`] and any secondary window opened with
+//! [`Window::spawn_secondary`]. However, in the future, the graphics module
+//! may allow recording graphical operations concurrently.
 //!
 //! ## Targets
 //! A [`Target`] represents a drawable target on a specific [`Gpu`]. A
@@ -74,6 +75,8 @@
 //! [targets]: #targets
 //! [resources]: #resources
 //! [`Gpu`]: struct.Gpu.html
+//! [`Window`]: struct.Window.html
+//! [`Window::spawn_secondary`]: struct.Window.html#method.spawn_secondary
 //! [`Target`]: struct.Target.html
 //! [`Transformation`]: struct.Transformation.html
 //! [`Frame`]: struct.Frame.html
@@ -105,40 +108,98 @@ mod backend_wgpu;
 ))]
 use backend_wgpu as gpu;
 
+mod animated_image;
+mod atlas_builder;
 mod batch;
+mod blend_mode;
+mod camera;
 mod canvas;
+mod capabilities;
 mod color;
+mod compressed;
+mod coordinate_system;
+mod debug_draw;
 mod font;
+#[cfg(feature = "system-fonts")]
+mod font_style;
+mod format;
+mod frame_stats;
+mod geometry;
+mod gradient;
 mod image;
 mod mesh;
+mod nine_slice;
 mod point;
+mod post_process;
 mod quad;
+mod recorder;
 mod rectangle;
+mod sampler;
+mod shader;
 mod shape;
+#[cfg(feature = "shaping")]
+mod shaping;
 mod sprite;
+mod stroke;
 mod target;
 mod text;
 mod transformation;
 mod vector;
+mod viewport;
+mod wrap_mode;
 
+pub mod sprite_sheet;
 pub mod texture_array;
 pub(crate) mod window;
 
 pub use self::image::Image;
+pub use animated_image::AnimatedImage;
+pub use atlas_builder::AtlasBuilder;
 pub use batch::Batch;
+pub use blend_mode::BlendMode;
+pub use camera::Camera;
 pub use canvas::Canvas;
+pub use capabilities::{Capabilities, PresentModes};
 pub use color::Color;
+pub use coordinate_system::CoordinateSystem;
+pub use debug_draw::DebugDraw;
 pub use font::Font;
+#[cfg(feature = "system-fonts")]
+pub use font_style::{FontStyle, FontWeight};
+pub use format::Format;
+pub use frame_stats::FrameStats;
+pub use geometry::{Geometry, Vertex};
 pub use gpu::Gpu;
+pub use gradient::Gradient;
 pub use mesh::Mesh;
+pub use nine_slice::{Borders, NineSlice};
 pub use point::Point;
-pub use quad::{IntoQuad, Quad};
+pub use post_process::PostProcess;
+pub use quad::{Flip, IntoQuad, Quad};
+pub use recorder::Recorder;
 pub use rectangle::Rectangle;
+pub use sampler::{Filter, Sampler};
+pub use shader::Shader;
 pub use shape::Shape;
 pub use sprite::Sprite;
+pub use sprite_sheet::SpriteSheet;
+pub use stroke::{LineCap, LineJoin, Stroke};
 pub use target::Target;
-pub use text::{HorizontalAlignment, Text, VerticalAlignment};
+pub use text::{
+    FontFace, HorizontalAlignment, RichText, Span, Text, VerticalAlignment,
+};
 pub use texture_array::TextureArray;
 pub use transformation::Transformation;
 pub use vector::Vector;
-pub use window::{CursorIcon, Frame, Settings as WindowSettings, Window};
+pub use viewport::Viewport;
+pub use window::{
+    CursorIcon, CustomCursor, Frame, Fullscreen, Icon, Monitor,
+    SecondaryWindow, Settings as WindowSettings, VideoMode, Window,
+};
+pub use wrap_mode::WrapMode;
+
+#[cfg(feature = "raw")]
+pub use gpu::{texture::External as RawTexture, Raw as RawGpu};
+
+#[cfg(feature = "clipboard")]
+pub use window::Clipboard;
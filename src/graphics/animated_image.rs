@@ -0,0 +1,230 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use image::AnimationDecoder;
+
+use crate::graphics::{Gpu, Image, Point, Rectangle, Sprite};
+use crate::load::Task;
+use crate::Result;
+
+/// An animated image, like a GIF.
+///
+/// The frames of an [`AnimatedImage`] are stacked vertically in a single
+/// [`Image`]. Use [`frame_at`] to find the frame to show after some time, and
+/// draw it with [`sprite`] or with the [`quad_source`] of a [`Quad`].
+///
+/// Only GIF files are supported for now. APNG files need a newer version of
+/// the `image` crate.
+///
+/// Cloning an [`AnimatedImage`] is cheap, its [`Image`] is shared.
+///
+/// [`AnimatedImage`]: struct.AnimatedImage.html
+/// [`Image`]: struct.Image.html
+/// [`Quad`]: struct.Quad.html
+/// [`frame_at`]: #method.frame_at
+/// [`sprite`]: #method.sprite
+/// [`quad_source`]: #method.quad_source
+#[derive(Debug, Clone)]
+pub struct AnimatedImage {
+    image: Image,
+    durations: Vec<Duration>,
+    frame_width: u16,
+    frame_height: u16,
+}
+
+impl AnimatedImage {
+    // Most browsers use this delay for frames without one
+    const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+    /// Loads an [`AnimatedImage`] from the GIF file at the given path.
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`].
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn new<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<AnimatedImage> {
+        let bytes = gpu.read_asset(path)?;
+
+        AnimatedImage::from_bytes(gpu, &bytes)
+    }
+
+    /// Creates a [`Task`] that loads an [`AnimatedImage`] from the GIF file at
+    /// the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<AnimatedImage> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| AnimatedImage::new(gpu, &p))
+    }
+
+    /// Creates an [`AnimatedImage`] from the raw bytes of a GIF file.
+    ///
+    /// Fails if the frames do not fit in a texture of the [`Gpu`] when
+    /// stacked vertically.
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn from_bytes(gpu: &mut Gpu, bytes: &[u8]) -> Result<AnimatedImage> {
+        let frames = image::gif::Decoder::new(std::io::Cursor::new(bytes))?
+            .into_frames()
+            .collect_frames()?;
+
+        let (width, height) = match frames.first() {
+            Some(frame) => frame.buffer().dimensions(),
+            None => return Err(image::ImageError::NotEnoughData.into()),
+        };
+
+        let strip_height = height * frames.len() as u32;
+        let max_size = gpu.max_texture_size().min(u32::from(u16::MAX));
+
+        if width == 0 || width > max_size || strip_height > max_size {
+            return Err(image::ImageError::DimensionError.into());
+        }
+
+        let mut strip = image::RgbaImage::new(width, strip_height);
+        let mut durations = Vec::with_capacity(frames.len());
+
+        for (i, frame) in frames.iter().enumerate() {
+            if frame.buffer().dimensions() != (width, height) {
+                return Err(image::ImageError::DimensionError.into());
+            }
+
+            image::imageops::replace(
+                &mut strip,
+                frame.buffer(),
+                0,
+                height * i as u32,
+            );
+
+            let delay = u64::from(frame.delay().to_integer());
+
+            durations.push(if delay == 0 {
+                Self::DEFAULT_DELAY
+            } else {
+                Duration::from_millis(delay)
+            });
+        }
+
+        let image =
+            Image::from_image(gpu, &image::DynamicImage::ImageRgba8(strip))?;
+
+        Ok(AnimatedImage {
+            image,
+            durations,
+            frame_width: width as u16,
+            frame_height: height as u16,
+        })
+    }
+
+    /// Returns the [`Image`] containing all the frames of the
+    /// [`AnimatedImage`], stacked vertically.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the number of frames of the [`AnimatedImage`].
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn frames(&self) -> usize {
+        self.durations.len()
+    }
+
+    /// Returns the width of a frame, in pixels.
+    pub fn width(&self) -> u16 {
+        self.frame_width
+    }
+
+    /// Returns the height of a frame, in pixels.
+    pub fn height(&self) -> u16 {
+        self.frame_height
+    }
+
+    /// Returns how long the given frame is shown.
+    ///
+    /// Frames without a delay are shown for 100 ms, like in most browsers.
+    pub fn frame_duration(&self, frame: usize) -> Duration {
+        self.durations[frame]
+    }
+
+    /// Returns the duration of a full loop of the [`AnimatedImage`].
+    ///
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn duration(&self) -> Duration {
+        self.durations.iter().sum()
+    }
+
+    /// Returns the frame shown after the given time since the animation
+    /// started, looping forever.
+    pub fn frame_at(&self, elapsed: Duration) -> usize {
+        let duration = self.duration().as_micros();
+
+        if duration == 0 {
+            return 0;
+        }
+
+        let mut remaining = elapsed.as_micros() % duration;
+
+        for (frame, delay) in self.durations.iter().enumerate() {
+            if remaining < delay.as_micros() {
+                return frame;
+            }
+
+            remaining -= delay.as_micros();
+        }
+
+        self.durations.len() - 1
+    }
+
+    /// Returns the region of the [`Image`] that contains the given frame.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn source(&self, frame: usize) -> Rectangle<u16> {
+        Rectangle {
+            x: 0,
+            y: self.frame_height * frame as u16,
+            width: self.frame_width,
+            height: self.frame_height,
+        }
+    }
+
+    /// Returns the region of the [`Image`] that contains the given frame in
+    /// relative coordinates, ready to be used as the `source` of a [`Quad`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Quad`]: struct.Quad.html
+    pub fn quad_source(&self, frame: usize) -> Rectangle<f32> {
+        let frames = self.frames() as f32;
+
+        Rectangle {
+            x: 0.0,
+            y: frame as f32 / frames,
+            width: 1.0,
+            height: 1.0 / frames,
+        }
+    }
+
+    /// Returns a [`Sprite`] ready to be drawn for the given frame.
+    ///
+    /// The returned [`Sprite`] is positioned at the origin. Use it with the
+    /// [`Image`] of the [`AnimatedImage`].
+    ///
+    /// [`Sprite`]: struct.Sprite.html
+    /// [`Image`]: struct.Image.html
+    /// [`AnimatedImage`]: struct.AnimatedImage.html
+    pub fn sprite(&self, frame: usize) -> Sprite {
+        Sprite {
+            source: self.source(frame),
+            position: Point::new(0.0, 0.0),
+            ..Sprite::default()
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::graphics::sprite_sheet::{self, SpriteSheet};
+use crate::graphics::{Gpu, Image, Rectangle};
+use crate::load::Task;
+use crate::Result;
+
+/// A builder that packs many images into a single texture at load time.
+///
+/// The produced [`SpriteSheet`] maps the name of every image to its region in
+/// the packed [`Image`]. Drawing the sprites of a single [`Image`] allows them
+/// to share a [`Batch`], even when they come from separate files.
+///
+/// Images are packed into rows, from tallest to shortest. The texture is only
+/// as big as needed to fit every image, but never bigger than the maximum size
+/// given to [`AtlasBuilder::new`].
+///
+/// [`SpriteSheet`]: sprite_sheet/struct.SpriteSheet.html
+/// [`Image`]: struct.Image.html
+/// [`Batch`]: struct.Batch.html
+/// [`AtlasBuilder::new`]: struct.AtlasBuilder.html#method.new
+#[derive(Debug)]
+pub struct AtlasBuilder {
+    max_width: u16,
+    max_height: u16,
+    padding: u16,
+    entries: Vec<(String, Source)>,
+}
+
+#[derive(Debug)]
+enum Source {
+    Path(PathBuf),
+    Image(image::RgbaImage),
+}
+
+impl AtlasBuilder {
+    /// Creates a new [`AtlasBuilder`] of a texture with the given maximum
+    /// size.
+    ///
+    /// Keep the size within the [`Gpu::max_texture_size`].
+    ///
+    /// [`AtlasBuilder`]: struct.AtlasBuilder.html
+    /// [`Gpu::max_texture_size`]: struct.Gpu.html#method.max_texture_size
+    pub fn new(max_width: u16, max_height: u16) -> AtlasBuilder {
+        AtlasBuilder {
+            max_width,
+            max_height,
+            padding: 1,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Sets the amount of empty pixels between packed images.
+    ///
+    /// By default, images are separated by 1 pixel, which avoids bleeding
+    /// between neighboring sprites when they are scaled.
+    pub fn set_padding(&mut self, padding: u16) {
+        self.padding = padding;
+    }
+
+    /// Adds the image in the given path with the given name.
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`] when the atlas
+    /// is built. Adding an image with an existing name replaces it.
+    ///
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn add<N: Into<String>, P: Into<PathBuf>>(&mut self, name: N, path: P) {
+        self.insert(name.into(), Source::Path(path.into()));
+    }
+
+    /// Adds a [`DynamicImage`] of the [`image` crate] with the given name.
+    ///
+    /// Adding an image with an existing name replaces it.
+    ///
+    /// [`DynamicImage`]: https://docs.rs/image/0.21.1/image/enum.DynamicImage.html
+    /// [`image` crate]: https://docs.rs/image
+    pub fn add_image<N: Into<String>>(
+        &mut self,
+        name: N,
+        image: &image::DynamicImage,
+    ) {
+        self.insert(name.into(), Source::Image(image.to_rgba()));
+    }
+
+    fn insert(&mut self, name: String, source: Source) {
+        self.entries.retain(|(entry, _)| *entry != name);
+        self.entries.push((name, source));
+    }
+
+    /// Packs the added images and uploads them to the GPU as a single
+    /// [`Image`].
+    ///
+    /// Fails if an image cannot be read or if the images do not fit in the
+    /// maximum size of the atlas.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn build(self, gpu: &mut Gpu) -> Result<SpriteSheet> {
+        let mut images = Vec::with_capacity(self.entries.len());
+
+        for (name, source) in self.entries {
+            let image = match source {
+                Source::Path(path) => {
+                    image::load_from_memory(&gpu.read_asset(&path)?)?.to_rgba()
+                }
+                Source::Image(image) => image,
+            };
+
+            images.push((name, image));
+        }
+
+        // Packing taller images first keeps the rows compact
+        images.sort_by(|(_, a), (_, b)| b.height().cmp(&a.height()));
+
+        let Packing {
+            regions,
+            width,
+            height,
+        } = pack(
+            &images,
+            u32::from(self.max_width),
+            u32::from(self.max_height),
+            u32::from(self.padding),
+        )
+        .map_err(crate::Error::SpriteSheet)?;
+
+        let mut atlas = image::RgbaImage::new(width.max(1), height.max(1));
+
+        for (name, image) in &images {
+            let region = regions[name];
+
+            image::imageops::overlay(
+                &mut atlas,
+                image,
+                u32::from(region.x),
+                u32::from(region.y),
+            );
+        }
+
+        let image =
+            Image::from_image(gpu, &image::DynamicImage::ImageRgba8(atlas))?;
+
+        SpriteSheet::new(image, regions)
+    }
+
+    /// Creates a [`Task`] that builds the atlas.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    pub fn load(self) -> Task<SpriteSheet> {
+        Task::using_gpu(move |gpu| self.build(gpu))
+    }
+}
+
+#[derive(Debug)]
+struct Packing {
+    regions: HashMap<String, Rectangle<u16>>,
+    width: u32,
+    height: u32,
+}
+
+// Packs the images into rows, in the given order
+fn pack(
+    images: &[(String, image::RgbaImage)],
+    max_width: u32,
+    max_height: u32,
+    padding: u32,
+) -> std::result::Result<Packing, sprite_sheet::Error> {
+    let mut regions = HashMap::with_capacity(images.len());
+    let (mut x, mut y) = (0, 0);
+    let (mut width, mut height) = (0, 0);
+    let mut row_height = 0;
+
+    for (name, image) in images {
+        if x > 0 && x + image.width() > max_width {
+            x = 0;
+            y += row_height + padding;
+            row_height = 0;
+        }
+
+        if x + image.width() > max_width || y + image.height() > max_height {
+            return Err(sprite_sheet::Error::AtlasIsFull {
+                name: name.clone(),
+            });
+        }
+
+        let _ = regions.insert(
+            name.clone(),
+            Rectangle {
+                x: x as u16,
+                y: y as u16,
+                width: image.width() as u16,
+                height: image.height() as u16,
+            },
+        );
+
+        width = width.max(x + image.width());
+        height = height.max(y + image.height());
+        row_height = row_height.max(image.height());
+        x += image.width() + padding;
+    }
+
+    Ok(Packing {
+        regions,
+        width,
+        height,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn images(sizes: &[(&str, u32, u32)]) -> Vec<(String, image::RgbaImage)> {
+        sizes
+            .iter()
+            .map(|&(name, width, height)| {
+                (String::from(name), image::RgbaImage::new(width, height))
+            })
+            .collect()
+    }
+
+    fn region(x: u16, y: u16, width: u16, height: u16) -> Rectangle<u16> {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn pack_fills_rows_with_padding() {
+        let images = images(&[("a", 4, 4), ("b", 3, 3), ("c", 2, 2)]);
+
+        let Packing {
+            regions,
+            width,
+            height,
+        } = pack(&images, 8, 16, 1).expect("Pack images");
+
+        assert_eq!(regions["a"], region(0, 0, 4, 4));
+        assert_eq!(regions["b"], region(5, 0, 3, 3));
+        assert_eq!(regions["c"], region(0, 5, 2, 2));
+        assert_eq!((width, height), (8, 7));
+    }
+
+    #[test]
+    fn pack_without_padding() {
+        let images = images(&[("a", 2, 2), ("b", 2, 2), ("c", 2, 1)]);
+
+        let Packing {
+            regions,
+            width,
+            height,
+        } = pack(&images, 4, 4, 0).expect("Pack images");
+
+        assert_eq!(regions["b"], region(2, 0, 2, 2));
+        assert_eq!(regions["c"], region(0, 2, 2, 1));
+        assert_eq!((width, height), (4, 3));
+    }
+
+    #[test]
+    fn pack_fails_when_the_atlas_is_full() {
+        let images = images(&[("a", 4, 4), ("b", 4, 4)]);
+
+        match pack(&images, 8, 4, 1) {
+            Err(sprite_sheet::Error::AtlasIsFull { name }) => {
+                assert_eq!(name, "b")
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn pack_fails_when_an_image_is_too_wide() {
+        let images = images(&[("wide", 9, 1)]);
+
+        match pack(&images, 8, 8, 1) {
+            Err(sprite_sheet::Error::AtlasIsFull { name }) => {
+                assert_eq!(name, "wide")
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+    }
+}
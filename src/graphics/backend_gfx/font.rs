@@ -2,37 +2,216 @@ use gfx_device_gl as gl;
 use gfx_glyph::GlyphCruncher;
 
 use crate::graphics::gpu::{TargetView, Transformation};
-use crate::graphics::{HorizontalAlignment, Text, Vector, VerticalAlignment};
+use crate::graphics::{
+    FontFace, HorizontalAlignment, Point, Rectangle, RichText, Text, Vector,
+    VerticalAlignment,
+};
 
 pub struct Font {
     glyphs: gfx_glyph::GlyphBrush<'static, gl::Resources, gl::Factory>,
+    sections: Vec<gfx_glyph::OwnedVariedSection>,
+    fallbacks: Vec<gfx_glyph::FontId>,
+    #[cfg(feature = "shaping")]
+    faces: Vec<gfx_glyph::rusttype::SharedBytes<'static>>,
 }
 
 impl Font {
-    pub fn from_bytes(factory: &mut gl::Factory, bytes: &'static [u8]) -> Font {
+    pub fn from_bytes(
+        factory: &mut gl::Factory,
+        bytes: gfx_glyph::rusttype::SharedBytes<'static>,
+        cache_size: (u32, u32),
+    ) -> Font {
+        let font = load(bytes.clone());
+
         Font {
-            glyphs: gfx_glyph::GlyphBrushBuilder::using_font_bytes(bytes)
+            glyphs: gfx_glyph::GlyphBrushBuilder::using_font(font)
+                .initial_cache_size(cache_size)
                 .depth_test(gfx::preset::depth::PASS_TEST)
                 .texture_filter_method(gfx::texture::FilterMethod::Scale)
                 .build(factory.clone()),
+            sections: Vec::new(),
+            fallbacks: Vec::new(),
+            #[cfg(feature = "shaping")]
+            faces: vec![bytes],
         }
     }
 
+    pub fn add_face(
+        &mut self,
+        bytes: gfx_glyph::rusttype::SharedBytes<'static>,
+    ) -> FontFace {
+        FontFace(self.add_font(bytes).0)
+    }
+
+    pub fn add_fallback(
+        &mut self,
+        bytes: gfx_glyph::rusttype::SharedBytes<'static>,
+    ) {
+        let font_id = self.add_font(bytes);
+        self.fallbacks.push(font_id);
+    }
+
+    fn add_font(
+        &mut self,
+        bytes: gfx_glyph::rusttype::SharedBytes<'static>,
+    ) -> gfx_glyph::FontId {
+        #[cfg(feature = "shaping")]
+        self.faces.push(bytes.clone());
+
+        self.glyphs.add_font(load(bytes))
+    }
+
     pub fn add(&mut self, text: Text<'_>) {
-        let section: gfx_glyph::Section<'_> = text.into();
-        self.glyphs.queue(section);
+        self.add_rich(RichText::from(text));
     }
 
-    pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        let section: gfx_glyph::Section<'_> = text.into();
+    pub fn add_rich(&mut self, text: RichText<'_>) {
+        let section = self.fall_back(text.into());
+        self.sections.push(section.to_owned());
+    }
+
+    pub fn preload(&mut self, characters: &str, size: f32) {
+        self.glyphs.queue(gfx_glyph::Section {
+            text: characters,
+            scale: gfx_glyph::Scale::uniform(size),
+            color: [0.0, 0.0, 0.0, 0.0],
+            ..Default::default()
+        });
+    }
+
+    /// Queues all the added text, rasterizing its glyphs at the given scale
+    /// on each axis.
+    ///
+    /// The text must be drawn with a transformation that undoes the scale.
+    /// If `flip` is true, every section is mirrored vertically around its own
+    /// position, so it stays there when drawn with a transformation that
+    /// flips the Y axis.
+    pub fn queue(&mut self, scale: Vector, flip: bool) {
+        for mut section in self.sections.drain(..) {
+            if flip {
+                let offset = vertical_offset(&section.layout, section.bounds.1);
+
+                section.screen_position.1 =
+                    2.0 * offset - section.screen_position.1;
+            }
+
+            section.screen_position.0 *= scale.x;
+            section.screen_position.1 *= scale.y;
+            section.bounds.0 *= scale.x;
+            section.bounds.1 *= scale.y;
+
+            for text in section.text.iter_mut() {
+                text.scale = gfx_glyph::Scale {
+                    x: text.scale.x * scale.x,
+                    y: text.scale.y * scale.y,
+                };
+            }
+
+            #[cfg(feature = "shaping")]
+            self.glyphs.queue_custom_layout(
+                section.to_borrowed(),
+                &Shaping::new(&self.faces, &section.layout),
+            );
+
+            #[cfg(not(feature = "shaping"))]
+            self.glyphs.queue(section.to_borrowed());
+        }
+    }
+
+    pub fn measure(&mut self, text: &Text<'_>) -> Rectangle<f32> {
+        self.measure_rich(&RichText::from(text.clone()))
+    }
+
+    pub fn measure_rich(&mut self, text: &RichText<'_>) -> Rectangle<f32> {
+        let section = self.fall_back(text.clone().into());
+
+        #[cfg(feature = "shaping")]
+        let bounds = {
+            let shaping = Shaping::new(&self.faces, &section.layout);
+
+            self.glyphs.glyph_bounds_custom_layout(section, &shaping)
+        };
+
+        #[cfg(not(feature = "shaping"))]
         let bounds = self.glyphs.glyph_bounds(section);
 
         match bounds {
-            Some(bounds) => (bounds.width(), bounds.height()),
-            None => (0.0, 0.0),
+            Some(bounds) => Rectangle {
+                x: bounds.min.x,
+                y: bounds.min.y,
+                width: bounds.width(),
+                height: bounds.height(),
+            },
+            None => Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
         }
     }
 
+    /// Splits the text of the section in runs that use the first fallback
+    /// containing their glyphs, when their own font face does not.
+    fn fall_back<'a>(
+        &self,
+        section: gfx_glyph::VariedSection<'a>,
+    ) -> gfx_glyph::VariedSection<'a> {
+        if self.fallbacks.is_empty() {
+            return section;
+        }
+
+        let fonts = self.glyphs.fonts();
+
+        let font_for = |c: char, font_id: gfx_glyph::FontId| {
+            let has_glyph = |font_id: &gfx_glyph::FontId| {
+                fonts[font_id.0].glyph(c).id().0 != 0
+            };
+
+            if c.is_whitespace() || has_glyph(&font_id) {
+                font_id
+            } else {
+                self.fallbacks
+                    .iter()
+                    .cloned()
+                    .find(has_glyph)
+                    .unwrap_or(font_id)
+            }
+        };
+
+        let mut text = Vec::new();
+
+        for run in section.text.iter() {
+            let mut start = 0;
+            let mut current = run.font_id;
+
+            for (i, c) in run.text.char_indices() {
+                let font_id = font_for(c, run.font_id);
+
+                if font_id != current {
+                    if i > start {
+                        text.push(gfx_glyph::SectionText {
+                            text: &run.text[start..i],
+                            font_id: current,
+                            ..*run
+                        });
+                    }
+
+                    start = i;
+                    current = font_id;
+                }
+            }
+
+            text.push(gfx_glyph::SectionText {
+                text: &run.text[start..],
+                font_id: current,
+                ..*run
+            });
+        }
+
+        gfx_glyph::VariedSection { text, ..section }
+    }
+
     pub fn draw(
         &mut self,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
@@ -55,30 +234,37 @@ impl Font {
     }
 }
 
-impl<'a> From<Text<'a>> for gfx_glyph::Section<'a> {
-    fn from(text: Text<'a>) -> gfx_glyph::Section<'a> {
-        let x = match text.horizontal_alignment {
-            HorizontalAlignment::Left => text.position.x,
-            HorizontalAlignment::Center => {
-                text.position.x + text.bounds.0 / 2.0
-            }
-            HorizontalAlignment::Right => text.position.x + text.bounds.0,
-        };
+fn load(
+    bytes: gfx_glyph::rusttype::SharedBytes<'static>,
+) -> gfx_glyph::rusttype::Font<'static> {
+    gfx_glyph::rusttype::Font::from_bytes(bytes).expect("Load font")
+}
 
-        let y = match text.vertical_alignment {
-            VerticalAlignment::Top => text.position.y,
-            VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
-            VerticalAlignment::Bottom => text.position.y + text.bounds.1,
-        };
+impl<'a> From<RichText<'a>> for gfx_glyph::VariedSection<'a> {
+    fn from(text: RichText<'a>) -> gfx_glyph::VariedSection<'a> {
+        let screen_position = screen_position(
+            text.position,
+            text.bounds,
+            text.horizontal_alignment,
+            text.vertical_alignment,
+        );
 
-        gfx_glyph::Section {
-            text: &text.content,
-            screen_position: (x, y),
-            scale: gfx_glyph::Scale {
-                x: text.size,
-                y: text.size,
-            },
-            color: text.color.into_linear(),
+        gfx_glyph::VariedSection {
+            text: text
+                .spans
+                .iter()
+                .map(|span| gfx_glyph::SectionText {
+                    text: span.content,
+                    scale: gfx_glyph::Scale {
+                        x: span.size,
+                        y: span.size,
+                    },
+                    color: span.color.into_linear(),
+                    font_id: gfx_glyph::FontId(span.face.0),
+                    ..Default::default()
+                })
+                .collect(),
+            screen_position,
             bounds: text.bounds,
             layout: gfx_glyph::Layout::default()
                 .h_align(text.horizontal_alignment.into())
@@ -88,6 +274,45 @@ impl<'a> From<Text<'a>> for gfx_glyph::Section<'a> {
     }
 }
 
+fn screen_position(
+    position: Point,
+    bounds: (f32, f32),
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> (f32, f32) {
+    let x = match horizontal_alignment {
+        HorizontalAlignment::Left => position.x,
+        HorizontalAlignment::Center => position.x + bounds.0 / 2.0,
+        HorizontalAlignment::Right => position.x + bounds.0,
+    };
+
+    let y = match vertical_alignment {
+        VerticalAlignment::Top => position.y,
+        VerticalAlignment::Center => position.y + bounds.1 / 2.0,
+        VerticalAlignment::Bottom => position.y + bounds.1,
+    };
+
+    (x, y)
+}
+
+/// Returns the distance between the position of some text and the position
+/// of its section, given its vertical alignment inside the given height.
+fn vertical_offset(
+    layout: &gfx_glyph::Layout<gfx_glyph::BuiltInLineBreaker>,
+    height: f32,
+) -> f32 {
+    let v_align = match *layout {
+        gfx_glyph::Layout::SingleLine { v_align, .. }
+        | gfx_glyph::Layout::Wrap { v_align, .. } => v_align,
+    };
+
+    match v_align {
+        gfx_glyph::VerticalAlign::Top => 0.0,
+        gfx_glyph::VerticalAlign::Center => height / 2.0,
+        gfx_glyph::VerticalAlign::Bottom => height,
+    }
+}
+
 impl From<HorizontalAlignment> for gfx_glyph::HorizontalAlign {
     fn from(alignment: HorizontalAlignment) -> gfx_glyph::HorizontalAlign {
         match alignment {
@@ -107,3 +332,129 @@ impl From<VerticalAlignment> for gfx_glyph::VerticalAlign {
         }
     }
 }
+
+/// A `GlyphPositioner` that shapes text with `rustybuzz`.
+#[cfg(feature = "shaping")]
+#[derive(Debug, Clone, Copy)]
+struct Shaping<'a> {
+    faces: &'a [gfx_glyph::rusttype::SharedBytes<'static>],
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+}
+
+#[cfg(feature = "shaping")]
+impl<'a> Shaping<'a> {
+    fn new(
+        faces: &'a [gfx_glyph::rusttype::SharedBytes<'static>],
+        layout: &gfx_glyph::Layout<gfx_glyph::BuiltInLineBreaker>,
+    ) -> Shaping<'a> {
+        let (h_align, v_align) = match *layout {
+            gfx_glyph::Layout::SingleLine {
+                h_align, v_align, ..
+            }
+            | gfx_glyph::Layout::Wrap {
+                h_align, v_align, ..
+            } => (h_align, v_align),
+        };
+
+        Shaping {
+            faces,
+            horizontal_alignment: match h_align {
+                gfx_glyph::HorizontalAlign::Left => HorizontalAlignment::Left,
+                gfx_glyph::HorizontalAlign::Center => {
+                    HorizontalAlignment::Center
+                }
+                gfx_glyph::HorizontalAlign::Right => HorizontalAlignment::Right,
+            },
+            vertical_alignment: match v_align {
+                gfx_glyph::VerticalAlign::Top => VerticalAlignment::Top,
+                gfx_glyph::VerticalAlign::Center => VerticalAlignment::Center,
+                gfx_glyph::VerticalAlign::Bottom => VerticalAlignment::Bottom,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "shaping")]
+impl std::hash::Hash for Shaping<'_> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Font data never moves while the font is alive, so its address
+        // identifies it
+        for face in self.faces {
+            (face.as_ptr() as usize).hash(state);
+        }
+
+        self.horizontal_alignment.hash(state);
+        self.vertical_alignment.hash(state);
+    }
+}
+
+#[cfg(feature = "shaping")]
+impl gfx_glyph::GlyphPositioner for Shaping<'_> {
+    fn calculate_glyphs<'font, F: gfx_glyph::FontMap<'font>>(
+        &self,
+        fonts: &F,
+        geometry: &gfx_glyph::SectionGeometry,
+        sections: &[gfx_glyph::SectionText<'_>],
+    ) -> Vec<(
+        gfx_glyph::rusttype::PositionedGlyph<'font>,
+        [f32; 4],
+        gfx_glyph::FontId,
+    )> {
+        use crate::graphics::shaping;
+
+        let runs: Vec<shaping::Run<'_>> = sections
+            .iter()
+            .map(|section| {
+                let font = fonts.font(section.font_id);
+                let v_metrics = font.v_metrics(section.scale);
+
+                shaping::Run {
+                    text: section.text,
+                    face: &self.faces[section.font_id.0],
+                    scale: section.scale.y / f32::from(font.units_per_em()),
+                    ascent: v_metrics.ascent,
+                    line_height: v_metrics.ascent - v_metrics.descent
+                        + v_metrics.line_gap,
+                }
+            })
+            .collect();
+
+        shaping::layout(
+            &runs,
+            geometry.screen_position,
+            geometry.bounds,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        )
+        .into_iter()
+        .map(|glyph| {
+            let section = &sections[glyph.run];
+
+            let positioned = fonts
+                .font(section.font_id)
+                .glyph(gfx_glyph::rusttype::GlyphId(glyph.id.into()))
+                .scaled(section.scale)
+                .positioned(gfx_glyph::rusttype::point(
+                    glyph.position.x,
+                    glyph.position.y,
+                ));
+
+            (positioned, section.color, section.font_id)
+        })
+        .collect()
+    }
+
+    fn bounds_rect(
+        &self,
+        geometry: &gfx_glyph::SectionGeometry,
+    ) -> gfx_glyph::rusttype::Rect<f32> {
+        let (x, y) = geometry.screen_position;
+        let (width, height) = geometry.bounds;
+
+        gfx_glyph::rusttype::Rect {
+            min: gfx_glyph::rusttype::point(x - width, y - height),
+            max: gfx_glyph::rusttype::point(x + width, y + height),
+        }
+    }
+}
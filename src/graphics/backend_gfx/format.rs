@@ -1,3 +1,7 @@
+use gfx::format::{ChannelTyped, SurfaceTyped};
+
+use crate::graphics::Format;
+
 pub const COLOR: gfx::format::Format = gfx::format::Format(
     gfx::format::SurfaceType::R8_G8_B8_A8,
     gfx::format::ChannelType::Unorm,
@@ -11,3 +15,18 @@ pub const DEPTH: gfx::format::Format = gfx::format::Format(
 pub type View = <gfx::format::Srgba8 as gfx::format::Formatted>::View;
 pub type Surface = <gfx::format::Srgba8 as gfx::format::Formatted>::Surface;
 pub type Channel = <gfx::format::Srgba8 as gfx::format::Formatted>::Channel;
+
+/// Returns the surface and channel types of a texture with the given
+/// `Format`.
+pub fn texture(format: Format) -> gfx::format::Format {
+    match format {
+        Format::Rgba8 => gfx::format::Format(
+            Surface::get_surface_type(),
+            Channel::get_channel_type(),
+        ),
+        Format::Rgba16Float => gfx::format::Format(
+            gfx::format::SurfaceType::R16_G16_B16_A16,
+            gfx::format::ChannelType::Float,
+        ),
+    }
+}
@@ -1,5 +1,7 @@
 mod font;
 mod format;
+mod multisample;
+mod pipelines;
 mod quad;
 mod surface;
 pub mod texture;
@@ -7,17 +9,27 @@ mod triangle;
 mod types;
 
 pub use font::Font;
-pub use quad::Quad;
+pub use multisample::Multisample;
+pub use quad::{Quad, Shader};
 pub use surface::Surface;
 pub use texture::Texture;
 pub use triangle::Vertex;
-pub use types::TargetView;
+pub use types::{StencilView, TargetView};
+
+use std::path::Path;
+use std::time::SystemTime;
 
 use gfx::{self, Device};
 use gfx_device_gl as gl;
 
-use crate::graphics::{Color, Transformation};
-use crate::Result;
+use crate::graphics::frame_stats;
+use crate::graphics::target::Mask;
+use crate::graphics::{
+    BlendMode, Capabilities, Color, DebugDraw, Format, FrameStats,
+    PresentModes, Rectangle, Transformation,
+};
+use crate::load::{AssetSource, Filesystem};
+use crate::{Error, Result};
 
 /// A link between your game and a graphics processor.
 ///
@@ -36,38 +48,274 @@ pub struct Gpu {
     encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer>,
     triangle_pipeline: triangle::Pipeline,
     quad_pipeline: quad::Pipeline,
+    multisample_pipeline: multisample::Pipeline,
+    debug_draw: DebugDraw,
+    assets: Box<dyn AssetSource>,
+    stats: frame_stats::Counter,
+    shader_error: Option<String>,
+    _headless: Option<Headless>,
+}
+
+// Keeps the OpenGL context of a headless `Gpu` alive
+struct Headless {
+    _context: glutin::Context<glutin::PossiblyCurrent>,
+    _event_loop: winit::event_loop::EventLoop<()>,
+}
+
+/// The raw handles of a [`Gpu`].
+///
+/// Obtain them with [`Gpu::raw`] to integrate other libraries or to record
+/// your own passes. This type is only available with the `raw` feature.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Gpu::raw`]: struct.Gpu.html#method.raw
+#[cfg(feature = "raw")]
+#[allow(missing_debug_implementations)]
+pub struct Raw<'a> {
+    /// The `gfx` OpenGL device.
+    pub device: &'a mut gl::Device,
+
+    /// The `gfx` OpenGL factory, used to create resources.
+    pub factory: &'a mut gl::Factory,
+
+    /// The encoder recording the commands of the current frame.
+    ///
+    /// Commands recorded here run in order with the draws of Coffee.
+    pub encoder: &'a mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
         events_loop: &winit::event_loop::EventLoop<()>,
+        vsync: bool,
     ) -> Result<(Gpu, Surface)> {
-        let (surface, device, mut factory) =
-            Surface::new(builder, events_loop)?;
+        let (surface, device, factory) =
+            Surface::new(builder, events_loop, vsync)?;
+
+        let gpu = Gpu::new(
+            device,
+            factory,
+            surface.target(),
+            surface.stencil(),
+            None,
+        );
+
+        Ok((gpu, surface))
+    }
 
+    pub(super) fn create_secondary_surface(
+        &mut self,
+        _builder: winit::window::WindowBuilder,
+        _event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
+        _vsync: bool,
+    ) -> Result<Surface> {
+        // Every window has its own OpenGL context, and `gfx` resources cannot
+        // be shared between them
+        Err(Error::WindowCreation(String::from(
+            "Secondary windows are not supported by the OpenGL backend",
+        )))
+    }
+
+    /// Creates a [`Gpu`] that is not linked to any window.
+    ///
+    /// A headless [`Gpu`] can load resources and draw on a [`Canvas`], whose
+    /// pixels can be read with [`Canvas::read_pixels`]. Use it to render
+    /// offscreen, like in golden image tests running on CI.
+    ///
+    /// It fails if no OpenGL context can be created, like on a Linux machine
+    /// without an X11 or Wayland display.
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Canvas::read_pixels`]: struct.Canvas.html#method.read_pixels
+    pub fn headless() -> Result<Gpu> {
+        let event_loop = headless_event_loop()?;
+
+        let context = glutin::ContextBuilder::new()
+            .with_gl(glutin::GlRequest::Latest)
+            .with_gl_profile(glutin::GlProfile::Core)
+            .build_headless(&event_loop, winit::dpi::PhysicalSize::new(1, 1))
+            .map_err(|error| Error::GpuCreation(error.to_string()))?;
+
+        #[allow(unsafe_code)]
+        let context = unsafe { context.make_current() }
+            .map_err(|(_, error)| Error::GpuCreation(error.to_string()))?;
+
+        let (device, mut factory) = gl::create(|s| {
+            context.get_proc_address(s) as *const std::os::raw::c_void
+        });
+
+        // The pipelines need an initial target, which is replaced on every
+        // draw
+        let drawable =
+            texture::Drawable::new(&mut factory, 1, 1, Format::Rgba8);
+
+        Ok(Gpu::new(
+            device,
+            factory,
+            drawable.target(),
+            drawable.stencil(),
+            Some(Headless {
+                _context: context,
+                _event_loop: event_loop,
+            }),
+        ))
+    }
+
+    fn new(
+        device: gl::Device,
+        mut factory: gl::Factory,
+        target: &TargetView,
+        stencil: &StencilView,
+        headless: Option<Headless>,
+    ) -> Gpu {
         let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
             factory.create_command_buffer().into();
 
         let triangle_pipeline = triangle::Pipeline::new(
             &mut factory,
             &mut encoder,
-            surface.target(),
+            target,
+            stencil,
         );
 
         let quad_pipeline =
-            quad::Pipeline::new(&mut factory, &mut encoder, surface.target());
-
-        Ok((
-            Gpu {
-                device,
-                factory,
-                encoder,
-                triangle_pipeline,
-                quad_pipeline,
+            quad::Pipeline::new(&mut factory, &mut encoder, target, stencil);
+
+        let multisample_pipeline = multisample::Pipeline::new(&mut factory);
+
+        Gpu {
+            device,
+            factory,
+            encoder,
+            triangle_pipeline,
+            quad_pipeline,
+            multisample_pipeline,
+            debug_draw: DebugDraw::Off,
+            assets: Box::new(Filesystem::default()),
+            stats: frame_stats::Counter::new(),
+            shader_error: None,
+            _headless: headless,
+        }
+    }
+
+    /// Returns the [`Capabilities`] of the [`Gpu`].
+    ///
+    /// [`Capabilities`]: struct.Capabilities.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn capabilities(&self) -> Capabilities {
+        let capabilities = self.device.get_capabilities();
+
+        Capabilities {
+            max_texture_size: capabilities.max_texture_size as u32,
+            // OpenGL 3.0 guarantees at least 256 layers, but `gfx` does not
+            // expose the actual limit.
+            max_texture_array_layers: 256,
+            // OpenGL 3.0 guarantees at least 4 samples
+            max_samples: 4,
+            // `gfx_core` has no BCn surface types, so BCn textures cannot be
+            // created even when `GL_EXT_texture_compression_s3tc` is present
+            compressed_textures: false,
+            // Every window has its own OpenGL context, and `gfx` resources
+            // cannot be shared between them
+            secondary_windows: false,
+            // OpenGL presents with a swap interval, which is either synced
+            // with the vertical blank or not at all
+            present_modes: PresentModes {
+                fifo: true,
+                mailbox: false,
+                immediate: true,
             },
-            surface,
-        ))
+        }
+    }
+
+    /// Returns the name of the graphics adapter used by the [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn adapter_name(&self) -> &str {
+        self.device.get_info().platform_name.renderer
+    }
+
+    /// Returns the maximum width and height of a texture, in pixels.
+    pub fn max_texture_size(&self) -> u32 {
+        self.capabilities().max_texture_size
+    }
+
+    /// Returns the current [`DebugDraw`] mode of the [`Gpu`].
+    ///
+    /// [`DebugDraw`]: enum.DebugDraw.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn debug_draw(&self) -> DebugDraw {
+        self.debug_draw
+    }
+
+    /// Sets the [`DebugDraw`] mode of the [`Gpu`].
+    ///
+    /// Use it to inspect the geometry of your scene or to find areas that are
+    /// drawn too many times.
+    ///
+    /// [`DebugDraw`]: enum.DebugDraw.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn set_debug_draw(&mut self, mode: DebugDraw) {
+        self.debug_draw = mode;
+    }
+
+    /// Returns the [`FrameStats`] of the last presented frame.
+    ///
+    /// [`FrameStats`]: struct.FrameStats.html
+    pub fn last_frame_stats(&self) -> FrameStats {
+        self.stats.last_frame()
+    }
+
+    /// Sets the [`AssetSource`] used to read the assets loaded by the [`Gpu`].
+    ///
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn set_asset_source<S: AssetSource + 'static>(&mut self, source: S) {
+        self.assets = Box::new(source);
+    }
+
+    /// Reads the asset with the given logical path from the [`AssetSource`] of
+    /// the [`Gpu`].
+    ///
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn read_asset<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        self.assets.read(path.as_ref())
+    }
+
+    /// Returns the [`RawGpu`] handles of the graphics backend.
+    ///
+    /// This method is only available with the `raw` feature.
+    ///
+    /// # Safety
+    /// Coffee assumes it owns the state of the OpenGL context. Changing it
+    /// outside of the given encoder, or destroying resources used by Coffee,
+    /// leads to undefined behavior. Prefer recording commands in the given
+    /// encoder.
+    ///
+    /// [`RawGpu`]: struct.RawGpu.html
+    #[cfg(feature = "raw")]
+    #[allow(unsafe_code)]
+    pub unsafe fn raw(&mut self) -> Raw<'_> {
+        Raw {
+            device: &mut self.device,
+            factory: &mut self.factory,
+            encoder: &mut self.encoder,
+        }
+    }
+
+    pub(super) fn asset_modified(&self, path: &Path) -> Option<SystemTime> {
+        self.assets.modified(path)
+    }
+
+    pub(crate) fn shader_error(&self) -> Option<&str> {
+        self.shader_error.as_ref().map(String::as_str)
+    }
+
+    pub(super) fn set_shader_error(&mut self, error: Option<String>) {
+        self.shader_error = error;
     }
 
     pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
@@ -80,6 +328,15 @@ impl Gpu {
             .clear(&typed_render_target, color.into_linear())
     }
 
+    pub(super) fn clear_stencil(&mut self, stencil: &StencilView) {
+        let typed_stencil: gfx::handle::DepthStencilView<
+            gl::Resources,
+            gfx::format::DepthStencil,
+        > = gfx::memory::Typed::new(stencil.clone());
+
+        self.encoder.clear_stencil(&typed_stencil, 0);
+    }
+
     fn flush(&mut self) {
         self.encoder.flush(&mut self.device);
     }
@@ -102,12 +359,32 @@ impl Gpu {
         Texture::new_array(&mut self.factory, layers)
     }
 
+    #[cfg(feature = "raw")]
+    pub(super) fn wrap_texture(
+        &mut self,
+        external: texture::External,
+    ) -> Result<Texture> {
+        Texture::from_external(&mut self.factory, external)
+            .map_err(|error| image::ImageError::UnsupportedError(error).into())
+    }
+
+    pub(super) fn update_texture(
+        &mut self,
+        texture: &Texture,
+        layer: u16,
+        region: Rectangle<u16>,
+        rgba: &[u8],
+    ) {
+        texture.update(&mut self.encoder, layer, region, rgba);
+    }
+
     pub(super) fn create_drawable_texture(
         &mut self,
         width: u16,
         height: u16,
+        format: Format,
     ) -> texture::Drawable {
-        texture::Drawable::new(&mut self.factory, width, height)
+        texture::Drawable::new(&mut self.factory, width, height, format)
     }
 
     pub(super) fn read_drawable_texture_pixels(
@@ -119,24 +396,98 @@ impl Gpu {
         drawable.read_pixels(&mut self.device, &mut self.factory)
     }
 
-    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
-        Font::from_bytes(&mut self.factory, bytes)
+    pub(super) fn create_multisample(
+        &mut self,
+        width: u16,
+        height: u16,
+        samples: u8,
+    ) -> Multisample {
+        Multisample::new(&mut self.factory, width, height, samples)
+    }
+
+    pub(super) fn resolve(
+        &mut self,
+        multisample: &Multisample,
+        drawable: &texture::Drawable,
+    ) {
+        self.multisample_pipeline.resolve(
+            &mut self.encoder,
+            multisample,
+            drawable,
+        );
+    }
+
+    pub(super) fn create_shader(&mut self, fragment: &[u8]) -> Result<Shader> {
+        Shader::new(&mut self.factory, fragment).map_err(Error::Shader)
+    }
+
+    pub(super) fn upload_font(
+        &mut self,
+        bytes: gfx_glyph::rusttype::SharedBytes<'static>,
+        cache_size: (u32, u32),
+    ) -> Font {
+        Font::from_bytes(&mut self.factory, bytes, cache_size)
     }
 
     pub(super) fn draw_triangles(
         &mut self,
+        texture: Option<&Texture>,
         vertices: &[Vertex],
         indices: &[u32],
         view: &TargetView,
         transformation: &Transformation,
+        blend_mode: BlendMode,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
+        stencil: &StencilView,
+        _samples: u32,
+        _format: Format,
     ) {
+        self.stats.triangles(indices.len());
+
         self.triangle_pipeline.draw(
             &mut self.factory,
             &mut self.encoder,
+            texture,
             vertices,
             indices,
             transformation,
+            self.debug_draw,
+            blend_mode,
+            clip,
+            mask,
             view,
+            stencil,
+        );
+    }
+
+    pub(super) fn clear_triangles(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
+        stencil: &StencilView,
+        _samples: u32,
+        _format: Format,
+    ) {
+        self.stats.triangles(indices.len());
+
+        self.triangle_pipeline.draw(
+            &mut self.factory,
+            &mut self.encoder,
+            None,
+            vertices,
+            indices,
+            transformation,
+            DebugDraw::Off,
+            BlendMode::Replace,
+            clip,
+            mask,
+            view,
+            stencil,
         );
     }
 
@@ -146,14 +497,32 @@ impl Gpu {
         instances: &[Quad],
         view: &TargetView,
         transformation: &Transformation,
+        shader: Option<&Shader>,
+        blend_mode: BlendMode,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
+        stencil: &StencilView,
+        _samples: u32,
+        _format: Format,
     ) {
+        let max = quad::MAX_INSTANCES as usize;
+        let draw_calls = (instances.len() + max - 1) / max;
+
+        self.stats.quads(texture.id(), instances.len(), draw_calls);
+
         self.quad_pipeline.bind_texture(texture);
 
         self.quad_pipeline.draw_textured(
             &mut self.encoder,
             instances,
             transformation,
+            self.debug_draw,
+            blend_mode,
+            shader,
+            clip,
+            mask,
             view,
+            stencil,
         );
     }
 
@@ -163,6 +532,70 @@ impl Gpu {
         target: &TargetView,
         transformation: Transformation,
     ) {
+        self.stats.font();
+
         font.draw(&mut self.encoder, target, transformation);
     }
+
+    pub(super) fn draw_font_multisampled(
+        &mut self,
+        font: &mut Font,
+        multisample: &Multisample,
+        transformation: Transformation,
+    ) {
+        self.draw_font(font, multisample.target(), transformation);
+    }
+
+    pub(super) fn draw_font_hdr(
+        &mut self,
+        font: &mut Font,
+        target: &TargetView,
+        _stencil: &StencilView,
+        _format: Format,
+        _width: u32,
+        _height: u32,
+        transformation: Transformation,
+    ) {
+        self.draw_font(font, target, transformation);
+    }
+}
+
+// Tests do not run on the main thread, where an event loop is usually required
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+fn headless_event_loop() -> Result<winit::event_loop::EventLoop<()>> {
+    use winit::platform::unix::EventLoopExtUnix;
+
+    // `new_any_thread` panics when there is no display server, so X11 is
+    // only used directly when Wayland is not available
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        Ok(winit::event_loop::EventLoop::new_any_thread())
+    } else {
+        winit::event_loop::EventLoop::new_x11_any_thread()
+            .map_err(|error| Error::GpuCreation(error.to_string()))
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn headless_event_loop() -> Result<winit::event_loop::EventLoop<()>> {
+    use winit::platform::windows::EventLoopExtWindows;
+
+    Ok(winit::event_loop::EventLoop::new_any_thread())
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "windows",
+)))]
+fn headless_event_loop() -> Result<winit::event_loop::EventLoop<()>> {
+    Ok(winit::event_loop::EventLoop::new())
 }
@@ -0,0 +1,181 @@
+use gfx::format::{ChannelTyped, SurfaceTyped};
+use gfx::traits::FactoryExt;
+use gfx::{self, *};
+use gfx_core::factory::Factory;
+use gfx_device_gl as gl;
+
+use super::format::{self, Channel, Surface};
+use super::texture::Drawable;
+use super::types::{StencilView, TargetView};
+
+gfx_defines! {
+    pipeline pipe {
+        source: gfx::RawShaderResource = "t_Source",
+        samples: gfx::Global<i32> = "u_Samples",
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               None
+          ),
+    }
+}
+
+type SourceView = gfx::handle::RawShaderResourceView<gl::Resources>;
+
+/// The multisampled color and stencil buffers of a target.
+///
+/// They are resolved into a `Drawable` by averaging their samples in a
+/// fragment shader, as `gfx` does not expose multisample resolves.
+#[derive(Clone)]
+pub struct Multisample {
+    samples: u8,
+    target: TargetView,
+    stencil: StencilView,
+    source: SourceView,
+}
+
+impl Multisample {
+    pub fn new(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+        samples: u8,
+    ) -> Multisample {
+        let kind = gfx::texture::Kind::D2(
+            width,
+            height,
+            gfx::texture::AaMode::Multi(samples),
+        );
+
+        let channel_type = Channel::get_channel_type();
+
+        let color = factory
+            .create_texture_raw(
+                gfx::texture::Info {
+                    kind,
+                    levels: 1,
+                    format: Surface::get_surface_type(),
+                    bind: gfx::memory::Bind::SHADER_RESOURCE
+                        | gfx::memory::Bind::RENDER_TARGET,
+                    usage: gfx::memory::Usage::Data,
+                },
+                Some(channel_type),
+                None,
+            )
+            .expect("Multisampled texture creation");
+
+        let target = factory
+            .view_texture_as_render_target_raw(
+                &color,
+                gfx::texture::RenderDesc {
+                    channel: channel_type,
+                    level: 0,
+                    layer: None,
+                },
+            )
+            .expect("View multisampled texture as render target");
+
+        let source = factory
+            .view_texture_as_shader_resource_raw(
+                &color,
+                gfx::texture::ResourceDesc {
+                    channel: channel_type,
+                    layer: None,
+                    min: 0,
+                    max: 0,
+                    swizzle: gfx::format::Swizzle::new(),
+                },
+            )
+            .expect("View multisampled texture as a shader resource");
+
+        let depth_stencil = factory
+            .create_texture_raw(
+                gfx::texture::Info {
+                    kind,
+                    levels: 1,
+                    format: gfx::format::SurfaceType::D24_S8,
+                    bind: gfx::memory::Bind::DEPTH_STENCIL,
+                    usage: gfx::memory::Usage::Data,
+                },
+                Some(gfx::format::ChannelType::Unorm),
+                None,
+            )
+            .expect("Multisampled stencil buffer creation");
+
+        let stencil = factory
+            .view_texture_as_depth_stencil_raw(
+                &depth_stencil,
+                gfx::texture::DepthStencilDesc {
+                    level: 0,
+                    layer: None,
+                    flags: gfx::texture::DepthStencilFlags::empty(),
+                },
+            )
+            .expect("View multisampled stencil buffer");
+
+        Multisample {
+            samples,
+            target,
+            stencil,
+            source,
+        }
+    }
+
+    pub fn samples(&self) -> u32 {
+        u32::from(self.samples)
+    }
+
+    pub fn target(&self) -> &TargetView {
+        &self.target
+    }
+
+    pub fn stencil(&self) -> &StencilView {
+        &self.stencil
+    }
+}
+
+pub struct Pipeline {
+    slice: gfx::Slice<gl::Resources>,
+    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+}
+
+impl Pipeline {
+    pub fn new(factory: &mut gl::Factory) -> Pipeline {
+        let state = factory
+            .create_pipeline_simple(
+                include_bytes!("shader/resolve.vert"),
+                include_bytes!("shader/resolve.frag"),
+                pipe::new(),
+            )
+            .expect("Resolve pipeline creation");
+
+        // The vertices of a triangle covering the whole target are computed
+        // in the vertex shader
+        let slice = gfx::Slice {
+            start: 0,
+            end: 3,
+            base_vertex: 0,
+            instances: None,
+            buffer: gfx::IndexBuffer::Auto,
+        };
+
+        Pipeline { slice, state }
+    }
+
+    pub fn resolve(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        multisample: &Multisample,
+        drawable: &Drawable,
+    ) {
+        let data = pipe::Data {
+            source: multisample.source.clone(),
+            samples: multisample.samples() as i32,
+            out: drawable.target().clone(),
+        };
+
+        encoder.draw(&self.slice, &self.state, &data);
+    }
+}
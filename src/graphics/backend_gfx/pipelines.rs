@@ -0,0 +1,185 @@
+use gfx::state::{
+    Blend, BlendValue, ColorMask, Comparison, Equation, Factor, RasterMethod,
+    Stencil, StencilOp,
+};
+use gfx_device_gl as gl;
+
+use super::types::StencilView;
+use crate::graphics::target::Mask;
+use crate::graphics::{BlendMode, DebugDraw, Rectangle};
+
+type State<M> = gfx::pso::PipelineState<gl::Resources, M>;
+
+/// A pipeline state for every `Mask`, `BlendMode`, and `DebugDraw` mode.
+pub struct Pipelines<M> {
+    unmasked: Set<M>,
+    masked: Set<M>,
+    mask: State<M>,
+}
+
+impl<M> Pipelines<M> {
+    pub fn new<F, E>(mut create_state: F) -> Result<Pipelines<M>, E>
+    where
+        F: FnMut(
+            RasterMethod,
+            Blend,
+            ColorMask,
+            Stencil,
+        ) -> Result<State<M>, E>,
+    {
+        Ok(Pipelines {
+            unmasked: Set::new(&mut create_state, stencil(Mask::None))?,
+            masked: Set::new(&mut create_state, stencil(Mask::Test))?,
+            mask: create_state(
+                RasterMethod::Fill,
+                blend(BlendMode::Replace),
+                ColorMask::empty(),
+                stencil(Mask::Write),
+            )?,
+        })
+    }
+
+    pub fn get(
+        &self,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+        mask: Mask,
+    ) -> &State<M> {
+        match mask {
+            Mask::None => self.unmasked.get(debug_draw, blend_mode),
+            Mask::Write => &self.mask,
+            Mask::Test => self.masked.get(debug_draw, blend_mode),
+        }
+    }
+}
+
+struct Set<M> {
+    alpha: State<M>,
+    additive: State<M>,
+    multiply: State<M>,
+    premultiplied: State<M>,
+    replace: State<M>,
+    wireframe: State<M>,
+    overdraw: State<M>,
+}
+
+impl<M> Set<M> {
+    fn new<F, E>(create_state: &mut F, stencil: Stencil) -> Result<Set<M>, E>
+    where
+        F: FnMut(
+            RasterMethod,
+            Blend,
+            ColorMask,
+            Stencil,
+        ) -> Result<State<M>, E>,
+    {
+        let mut fill = |blend_mode| {
+            create_state(
+                RasterMethod::Fill,
+                blend(blend_mode),
+                ColorMask::all(),
+                stencil,
+            )
+        };
+
+        Ok(Set {
+            alpha: fill(BlendMode::Alpha)?,
+            additive: fill(BlendMode::Additive)?,
+            multiply: fill(BlendMode::Multiply)?,
+            premultiplied: fill(BlendMode::Premultiplied)?,
+            replace: fill(BlendMode::Replace)?,
+            wireframe: create_state(
+                RasterMethod::Line(1),
+                blend(BlendMode::Alpha),
+                ColorMask::all(),
+                stencil,
+            )?,
+            overdraw: create_state(
+                RasterMethod::Fill,
+                gfx::preset::blend::ADD,
+                ColorMask::all(),
+                stencil,
+            )?,
+        })
+    }
+
+    fn get(&self, debug_draw: DebugDraw, blend_mode: BlendMode) -> &State<M> {
+        match debug_draw {
+            DebugDraw::Off | DebugDraw::Bounds => match blend_mode {
+                BlendMode::Alpha => &self.alpha,
+                BlendMode::Additive => &self.additive,
+                BlendMode::Multiply => &self.multiply,
+                BlendMode::Premultiplied => &self.premultiplied,
+                BlendMode::Replace => &self.replace,
+            },
+            DebugDraw::Wireframe => &self.wireframe,
+            DebugDraw::Overdraw => &self.overdraw,
+        }
+    }
+}
+
+/// Returns the scissor rectangle of a draw operation.
+///
+/// Without a clip, the whole view is drawn.
+pub fn scissor(
+    clip: Option<Rectangle<u32>>,
+    view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+) -> gfx::Rect {
+    match clip {
+        Some(clip) => gfx::Rect {
+            x: clip.x as u16,
+            y: clip.y as u16,
+            w: clip.width as u16,
+            h: clip.height as u16,
+        },
+        None => {
+            let (w, h, _, _) = view.get_dimensions();
+
+            gfx::Rect { x: 0, y: 0, w, h }
+        }
+    }
+}
+
+/// Returns the stencil target of a draw operation.
+///
+/// Masks are written and tested with a reference value of `1`.
+pub fn stencil_target(
+    view: &StencilView,
+) -> (
+    gfx::handle::DepthStencilView<gl::Resources, gfx::format::DepthStencil>,
+    (u8, u8),
+) {
+    (gfx::memory::Typed::new(view.clone()), (1, 1))
+}
+
+fn stencil(mask: Mask) -> Stencil {
+    let keep = (StencilOp::Keep, StencilOp::Keep, StencilOp::Keep);
+
+    match mask {
+        Mask::None => Stencil::new(Comparison::Always, 0, keep),
+        Mask::Write => Stencil::new(
+            Comparison::Always,
+            0xff,
+            (StencilOp::Keep, StencilOp::Keep, StencilOp::Replace),
+        ),
+        Mask::Test => Stencil::new(Comparison::Equal, 0xff, keep),
+    }
+}
+
+fn blend(blend_mode: BlendMode) -> Blend {
+    match blend_mode {
+        BlendMode::Alpha => gfx::preset::blend::ALPHA,
+        BlendMode::Additive => Blend::new(
+            Equation::Add,
+            Factor::ZeroPlus(BlendValue::SourceAlpha),
+            Factor::One,
+        ),
+        BlendMode::Multiply => gfx::preset::blend::MULTIPLY,
+        BlendMode::Premultiplied => Blend::new(
+            Equation::Add,
+            Factor::One,
+            Factor::OneMinus(BlendValue::SourceAlpha),
+        ),
+        BlendMode::Replace => gfx::preset::blend::REPLACE,
+    }
+}
@@ -1,12 +1,20 @@
+use std::collections::HashMap;
+
 use gfx::traits::FactoryExt;
 use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
+use super::pipelines::{self, Pipelines};
 use super::texture::Texture;
-use crate::graphics::{self, Transformation};
-
-const MAX_INSTANCES: u32 = 100_000;
+use super::types::StencilView;
+use crate::graphics::target::Mask;
+use crate::graphics::{
+    self, BlendMode, DebugDraw, Filter, Point, Rectangle, Sampler,
+    Transformation, Vector, WrapMode,
+};
+
+pub const MAX_INSTANCES: u32 = 100_000;
 const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
 
 const QUAD_VERTS: [Vertex; 4] = [
@@ -32,19 +40,36 @@ gfx_defines! {
     vertex Quad {
         src: [f32; 4] = "a_Src",
         translation: [f32; 2] = "a_Translation",
-        scale: [f32; 2] = "a_Scale",
+        transform: [f32; 4] = "a_Transform",
         layer: u32 = "t_Layer",
+        color: [f32; 4] = "a_Color",
     }
 
     constant Globals {
         mvp: [[f32; 4]; 4] = "u_MVP",
     }
 
+    constant Parameters {
+        values: [f32; 4] = "u_Parameters",
+    }
+
     pipeline pipe {
         vertices: gfx::VertexBuffer<Vertex> = (),
         texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
         globals: gfx::ConstantBuffer<Globals> = "Globals",
+        parameters: gfx::ConstantBuffer<Parameters> = "Parameters",
         instances: gfx::InstanceBuffer<Quad> = (),
+        scissor: gfx::Scissor = (),
+        stencil: gfx::StencilTarget<gfx::format::DepthStencil> =
+            gfx::state::Stencil::new(
+                gfx::state::Comparison::Always,
+                0,
+                (
+                    gfx::state::StencilOp::Keep,
+                    gfx::state::StencilOp::Keep,
+                    gfx::state::StencilOp::Keep,
+                ),
+            ),
         out: gfx::RawRenderTarget =
           (
               "Target0",
@@ -60,6 +85,8 @@ pub struct Pipeline {
     data: pipe::Data<gl::Resources>,
     shader: Shader,
     globals: Globals,
+    parameters: Parameters,
+    samplers: Samplers,
 }
 
 impl Pipeline {
@@ -67,6 +94,7 @@ impl Pipeline {
         factory: &mut gl::Factory,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         target: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        stencil: &StencilView,
     ) -> Pipeline {
         // Create point buffer
         let instances = factory
@@ -81,10 +109,7 @@ impl Pipeline {
         let (quads, slice) = factory
             .create_vertex_buffer_with_slice(&QUAD_VERTS, &QUAD_INDICES[..]);
 
-        let sampler = factory.create_sampler(gfx::texture::SamplerInfo::new(
-            gfx::texture::FilterMethod::Scale,
-            gfx::texture::WrapMode::Clamp,
-        ));
+        let samplers = Samplers::new(factory);
 
         let texture = Texture::new(
             factory,
@@ -97,23 +122,20 @@ impl Pipeline {
 
         let data = pipe::Data {
             vertices: quads.clone(),
-            texture: (texture.view().clone(), sampler),
+            texture: (
+                texture.view().clone(),
+                samplers.get(texture.sampler()).clone(),
+            ),
             globals: factory.create_constant_buffer(1),
+            parameters: factory.create_constant_buffer(1),
             instances,
+            scissor: pipelines::scissor(None, target),
+            stencil: pipelines::stencil_target(stencil),
             out: target.clone(),
         };
 
-        let init = pipe::Init {
-            out: (
-                "Target0",
-                format::COLOR,
-                gfx::state::ColorMask::all(),
-                Some(gfx::preset::blend::ALPHA),
-            ),
-            ..pipe::new()
-        };
-
-        let shader = Shader::new(factory, init);
+        let shader = Shader::new(factory, include_bytes!("shader/quad.frag"))
+            .expect("Quad shader creation");
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -123,16 +145,27 @@ impl Pipeline {
             .update_buffer(&data.globals, &[globals], 0)
             .expect("Globals initialization");
 
+        let parameters = Parameters { values: [0.0; 4] };
+
+        encoder
+            .update_buffer(&data.parameters, &[parameters], 0)
+            .expect("Parameters initialization");
+
         Pipeline {
             slice,
             data,
             shader,
             globals,
+            parameters,
+            samplers,
         }
     }
 
     pub fn bind_texture(&mut self, texture: &Texture) {
-        self.data.texture.0 = texture.view().clone();
+        self.data.texture = (
+            texture.view().clone(),
+            self.samplers.get(texture.sampler()).clone(),
+        );
     }
 
     pub fn draw_textured(
@@ -140,7 +173,13 @@ impl Pipeline {
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         instances: &[Quad],
         transformation: &Transformation,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+        shader: Option<&Shader>,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
         view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        stencil: &StencilView,
     ) {
         let transformation_matrix: [[f32; 4]; 4] =
             transformation.clone().into();
@@ -153,8 +192,22 @@ impl Pipeline {
                 .expect("Globals upload");
         }
 
+        let shader = shader.unwrap_or(&self.shader);
+
+        if self.parameters.values != shader.parameters {
+            self.parameters.values = shader.parameters;
+
+            encoder
+                .update_buffer(&self.data.parameters, &[self.parameters], 0)
+                .expect("Parameters upload");
+        }
+
+        self.data.scissor = pipelines::scissor(clip, view);
+        self.data.stencil = pipelines::stencil_target(stencil);
         self.data.out = view.clone();
 
+        let state = shader.pipelines.get(debug_draw, blend_mode, mask);
+
         let mut i = 0;
         let total = instances.len();
 
@@ -167,58 +220,155 @@ impl Pipeline {
 
             self.slice.instances = Some((end as u32 - i as u32, 0));
 
-            encoder.draw(&self.slice, &self.shader.state, &self.data);
+            encoder.draw(&self.slice, state, &self.data);
 
             i += MAX_INSTANCES as usize;
         }
     }
 }
 
+pub struct Samplers {
+    samplers: HashMap<Sampler, gfx::handle::Sampler<gl::Resources>>,
+}
+
+impl Samplers {
+    pub fn new(factory: &mut gl::Factory) -> Samplers {
+        let samplers = Sampler::all()
+            .map(|sampler| {
+                let filter = match sampler.filter {
+                    Filter::Nearest => gfx::texture::FilterMethod::Scale,
+                    Filter::Linear => gfx::texture::FilterMethod::Bilinear,
+                };
+
+                let wrap_mode = match sampler.wrap_mode {
+                    WrapMode::Clamp => gfx::texture::WrapMode::Clamp,
+                    WrapMode::Repeat => gfx::texture::WrapMode::Tile,
+                    WrapMode::Mirror => gfx::texture::WrapMode::Mirror,
+                };
+
+                let handle = factory.create_sampler(
+                    gfx::texture::SamplerInfo::new(filter, wrap_mode),
+                );
+
+                (sampler, handle)
+            })
+            .collect();
+
+        Samplers { samplers }
+    }
+
+    pub fn get(
+        &self,
+        sampler: Sampler,
+    ) -> &gfx::handle::Sampler<gl::Resources> {
+        &self.samplers[&sampler]
+    }
+}
+
 pub struct Shader {
-    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    pipelines: Pipelines<pipe::Meta>,
+    parameters: [f32; 4],
 }
 
 impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
+    pub fn new(
+        factory: &mut gl::Factory,
+        fragment: &[u8],
+    ) -> Result<Shader, String> {
         let set = factory
-            .create_shader_set(
-                include_bytes!("shader/quad.vert"),
-                include_bytes!("shader/quad.frag"),
-            )
-            .expect("Shader set creation");
-
-        let rasterizer = gfx::state::Rasterizer {
-            front_face: gfx::state::FrontFace::CounterClockwise,
-            cull_face: gfx::state::CullFace::Nothing,
-            method: gfx::state::RasterMethod::Fill,
-            offset: None,
-            samples: None,
-        };
+            .create_shader_set(include_bytes!("shader/quad.vert"), fragment)
+            .map_err(|error| error.to_string())?;
+
+        let pipelines =
+            Pipelines::new(|method, blend, color_mask, stencil| {
+                let rasterizer = gfx::state::Rasterizer {
+                    front_face: gfx::state::FrontFace::CounterClockwise,
+                    cull_face: gfx::state::CullFace::Nothing,
+                    method,
+                    offset: None,
+                    // Multisampling only has an effect on multisampled targets
+                    samples: Some(gfx::state::MultiSample),
+                };
+
+                let init = pipe::Init {
+                    stencil,
+                    out: ("Target0", format::COLOR, color_mask, Some(blend)),
+                    ..pipe::new()
+                };
+
+                factory
+                    .create_pipeline_state(
+                        &set,
+                        Primitive::TriangleList,
+                        rasterizer,
+                        init,
+                    )
+                    .map_err(|error| error.to_string())
+            })?;
+
+        Ok(Shader {
+            pipelines,
+            parameters: [0.0; 4],
+        })
+    }
 
-        let state = factory
-            .create_pipeline_state(
-                &set,
-                Primitive::TriangleList,
-                rasterizer,
-                init,
-            )
-            .expect("Pipeline state creation");
+    pub fn parameters(&self) -> [f32; 4] {
+        self.parameters
+    }
+
+    pub fn set_parameters(&mut self, parameters: [f32; 4]) {
+        self.parameters = parameters;
+    }
+}
+
+impl Quad {
+    /// Returns the corners of the quad in the coordinates of its target.
+    pub fn corners(&self) -> [[f32; 2]; 4] {
+        let [a, b, c, d] = self.transform;
+        let [x, y] = self.translation;
+
+        [
+            [x, y],
+            [x + a, y + b],
+            [x + a + c, y + b + d],
+            [x + c, y + d],
+        ]
+    }
+
+    /// Flips the quad vertically, keeping the area it covers.
+    pub fn flip_vertically(&mut self) {
+        let [a, b, c, d] = self.transform;
+        let [x, y] = self.translation;
+
+        self.transform = [a, b, -c, -d];
+        self.translation = [x + c, y + d];
+    }
+
+    /// Applies the given transformation on top of the transform of the quad.
+    pub fn apply(&mut self, transformation: &Transformation) {
+        let [a, b, c, d] = self.transform;
+        let [x, y] = self.translation;
+
+        let u = transformation.transform_vector(Vector::new(a, b));
+        let v = transformation.transform_vector(Vector::new(c, d));
+        let translation = transformation.transform_point(Point::new(x, y));
 
-        Shader { state }
+        self.transform = [u.x, u.y, v.x, v.y];
+        self.translation = [translation.x, translation.y];
     }
 }
 
 impl From<graphics::Quad> for Quad {
     fn from(quad: graphics::Quad) -> Quad {
         let source = quad.source;
-        let position = quad.position;
-        let (width, height) = quad.size;
+        let (transform, translation) = quad.instance_transform();
 
         Quad {
             src: [source.x, source.y, source.width, source.height],
-            translation: [position.x, position.y],
-            scale: [width, height],
+            translation,
+            transform,
             layer: 0,
+            color: quad.color.into_linear(),
         }
     }
 }
@@ -3,9 +3,10 @@
 in vec2 a_Pos;
 
 in vec4 a_Src;
-in vec2 a_Scale;
+in vec4 a_Transform;
 in vec2 a_Translation;
 in uint t_Layer;
+in vec4 a_Color;
 
 layout (std140) uniform Globals {
     mat4 u_MVP;
@@ -13,14 +14,16 @@ layout (std140) uniform Globals {
 
 out vec2 v_Uv;
 flat out uint v_Layer;
+out vec4 v_Color;
 
 void main() {
     v_Uv = a_Pos * a_Src.zw + a_Src.xy;
     v_Layer = t_Layer;
+    v_Color = a_Color;
 
     mat4 instance_transform = mat4(
-        vec4(a_Scale.x, 0.0, 0.0, 0.0),
-        vec4(0.0, a_Scale.y, 0.0, 0.0),
+        vec4(a_Transform.xy, 0.0, 0.0),
+        vec4(a_Transform.zw, 0.0, 0.0),
         vec4(0.0, 0.0, 1.0, 0.0),
         vec4(a_Translation, 0.0, 1.0)
     );
@@ -1,5 +1,7 @@
 #version 150 core
 
+uniform sampler2DArray t_Texture;
+in vec2 v_Uv;
 in vec4 v_Color;
 
 out vec4 Target0;
@@ -9,5 +11,5 @@ layout (std140) uniform Globals {
 };
 
 void main() {
-    Target0 = v_Color;
+    Target0 = texture(t_Texture, vec3(v_Uv, 0)) * v_Color;
 }
@@ -1,15 +1,18 @@
 #version 150 core
 
 in vec2 a_Pos;
+in vec2 a_Uv;
 in vec4 a_Color;
 
 layout (std140) uniform Globals {
     mat4 u_MVP;
 };
 
+out vec2 v_Uv;
 out vec4 v_Color;
 
 void main() {
+    v_Uv = a_Uv;
     v_Color = a_Color;
 
     gl_Position = u_MVP * vec4(a_Pos, 0.0, 1.0);
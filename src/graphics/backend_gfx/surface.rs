@@ -1,17 +1,23 @@
 use gfx_device_gl as gl;
 
-use super::{format, Gpu, TargetView};
+use super::{format, Gpu, StencilView, TargetView};
 use crate::{Error, Result};
 
 pub struct Surface {
     context: glutin::WindowedContext<glutin::PossiblyCurrent>,
     target: TargetView,
+    stencil: StencilView,
 }
 
 impl Surface {
+    // OpenGL places the origin of the default framebuffer at its bottom-left
+    // corner
+    pub const FLIP_SCISSOR: bool = true;
+
     pub(super) fn new(
         builder: winit::window::WindowBuilder,
         event_loop: &winit::event_loop::EventLoop<()>,
+        vsync: bool,
     ) -> Result<(Self, gl::Device, gl::Factory)> {
         let gl_builder = glutin::ContextBuilder::new()
             .with_gl(glutin::GlRequest::Latest)
@@ -19,9 +25,9 @@ impl Surface {
             .with_multisampling(0)
             // 24 color bits, 8 alpha bits
             .with_pixel_format(24, 8)
-            .with_vsync(true);
+            .with_vsync(vsync);
 
-        let (context, device, factory, target, _depth) = init_raw(
+        let (context, device, factory, target, stencil) = init_raw(
             builder,
             gl_builder,
             &event_loop,
@@ -30,7 +36,15 @@ impl Surface {
         )
         .map_err(|error| Error::WindowCreation(error.to_string()))?;
 
-        Ok((Self { context, target }, device, factory))
+        Ok((
+            Self {
+                context,
+                target,
+                stencil,
+            },
+            device,
+            factory,
+        ))
     }
 
     pub fn window(&self) -> &winit::window::Window {
@@ -41,6 +55,14 @@ impl Surface {
         &self.target
     }
 
+    pub fn stencil(&self) -> &StencilView {
+        &self.stencil
+    }
+
+    pub fn views(&mut self) -> (&TargetView, &StencilView) {
+        (&self.target, &self.stencil)
+    }
+
     pub fn resize(
         &mut self,
         _gpu: &mut Gpu,
@@ -50,21 +72,32 @@ impl Surface {
 
         let dimensions = self.target.get_dimensions();
 
-        if let Some((target, _depth)) = update_views_raw(
+        if let Some((target, stencil)) = update_views_raw(
             &self.context,
             dimensions,
             format::COLOR,
             format::DEPTH,
         ) {
             self.target = target;
+            self.stencil = stencil;
         }
     }
 
+    pub fn set_vsync(&mut self, _gpu: &mut Gpu, _vsync: bool) {
+        // `glutin` only sets the swap interval when creating the context
+    }
+
     pub fn request_redraw(&mut self) {
         self.context.window().request_redraw();
     }
 
     pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
+        gpu.stats.start_present();
+        self.present(gpu);
+        gpu.stats.finish_frame();
+    }
+
+    pub fn present(&mut self, gpu: &mut Gpu) {
         gpu.flush();
         self.context.swap_buffers().expect("Buffer swap");
         gpu.cleanup();
@@ -1,15 +1,14 @@
 use image;
 
-use gfx::format::{ChannelTyped, SurfaceTyped};
 use gfx::memory::Typed;
 use gfx::traits::FactoryExt;
 use gfx_core::factory::Factory;
 use gfx_device_gl as gl;
 
-use super::format::{Channel, Surface};
-use super::types::{RawTexture, ShaderResource, TargetView};
+use super::format;
+use super::types::{RawTexture, ShaderResource, StencilView, TargetView};
 use crate::graphics::vector::Vector;
-use crate::graphics::Transformation;
+use crate::graphics::{Format, Rectangle, Sampler, Transformation};
 
 #[derive(Clone, Debug)]
 pub struct Texture {
@@ -18,6 +17,27 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    sampler: Sampler,
+}
+
+/// A texture created outside of Coffee.
+///
+/// Use it to draw the result of your own render passes as an [`Image`] with
+/// [`Image::from_raw_texture`].
+///
+/// This type is only available with the `raw` feature.
+///
+/// [`Image`]: struct.Image.html
+/// [`Image::from_raw_texture`]: struct.Image.html#method.from_raw_texture
+#[cfg(feature = "raw")]
+#[derive(Debug)]
+pub struct External {
+    /// The texture. It must be a 2D array texture with the `SHADER_RESOURCE`
+    /// bind flag.
+    pub texture: RawTexture,
+
+    /// The channel type used to sample the texture.
+    pub channel: gfx::format::ChannelType,
 }
 
 impl Texture {
@@ -34,8 +54,10 @@ impl Texture {
             width,
             height,
             Some(&[&rgba]),
+            format::texture(Format::Rgba8),
             gfx::memory::Bind::SHADER_RESOURCE
-                | gfx::memory::Bind::TRANSFER_SRC,
+                | gfx::memory::Bind::TRANSFER_SRC
+                | gfx::memory::Bind::TRANSFER_DST,
         );
 
         Texture {
@@ -44,6 +66,7 @@ impl Texture {
             width,
             height,
             layers: 1,
+            sampler: Sampler::default(),
         }
     }
 
@@ -65,8 +88,10 @@ impl Texture {
             width,
             height,
             Some(&raw_layers[..]),
+            format::texture(Format::Rgba8),
             gfx::memory::Bind::SHADER_RESOURCE
-                | gfx::memory::Bind::TRANSFER_SRC,
+                | gfx::memory::Bind::TRANSFER_SRC
+                | gfx::memory::Bind::TRANSFER_DST,
         );
 
         Texture {
@@ -75,9 +100,75 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            sampler: Sampler::default(),
         }
     }
 
+    #[cfg(feature = "raw")]
+    pub(super) fn from_external(
+        factory: &mut gl::Factory,
+        external: External,
+    ) -> Result<Texture, String> {
+        let (width, height, layers) = match external.texture.get_info().kind {
+            gfx::texture::Kind::D2Array(width, height, layers, _) => {
+                (width, height, layers)
+            }
+            kind => {
+                return Err(format!(
+                    "expected a 2D array texture, got {:?}",
+                    kind
+                ))
+            }
+        };
+
+        let descriptor = gfx::texture::ResourceDesc {
+            channel: external.channel,
+            layer: None,
+            min: 0,
+            max: external.texture.get_info().levels - 1,
+            swizzle: gfx::format::Swizzle::new(),
+        };
+
+        let view = factory
+            .view_texture_as_shader_resource_raw(&external.texture, descriptor)
+            .map_err(|error| error.to_string())?;
+
+        Ok(Texture {
+            raw: external.texture,
+            view: gfx::memory::Typed::new(view),
+            width,
+            height,
+            layers,
+            sampler: Sampler::default(),
+        })
+    }
+
+    pub(super) fn update(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        layer: u16,
+        region: Rectangle<u16>,
+        rgba: &[u8],
+    ) {
+        encoder
+            .update_texture_raw(
+                &self.raw,
+                None,
+                gfx::texture::RawImageInfo {
+                    xoffset: region.x,
+                    yoffset: region.y,
+                    zoffset: layer,
+                    width: region.width,
+                    height: region.height,
+                    depth: 1,
+                    format: <gfx::format::Srgba8 as gfx::format::Formatted>::get_format(),
+                    mipmap: 0,
+                },
+                rgba,
+            )
+            .expect("Update texture");
+    }
+
     pub(super) fn handle(&self) -> &RawTexture {
         &self.raw
     }
@@ -93,21 +184,42 @@ impl Texture {
     pub fn height(&self) -> u16 {
         self.height
     }
+
+    pub fn id(&self) -> usize {
+        &*self.raw as *const _ as usize
+    }
+
+    pub fn sampler(&self) -> Sampler {
+        self.sampler
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
 }
 
 #[derive(Clone)]
 pub struct Drawable {
     texture: Texture,
     target: TargetView,
+    stencil: StencilView,
 }
 
 impl Drawable {
-    pub fn new(factory: &mut gl::Factory, width: u16, height: u16) -> Drawable {
+    pub fn new(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+        format: Format,
+    ) -> Drawable {
+        let format = format::texture(format);
+
         let (raw, view) = create_texture_array(
             factory,
             width,
             height,
             None,
+            format,
             gfx::memory::Bind::SHADER_RESOURCE
                 | gfx::memory::Bind::RENDER_TARGET
                 | gfx::memory::Bind::TRANSFER_SRC,
@@ -119,10 +231,11 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            sampler: Sampler::default(),
         };
 
         let render_desc = gfx::texture::RenderDesc {
-            channel: Channel::get_channel_type(),
+            channel: format.1,
             level: 0,
             layer: Some(0),
         };
@@ -131,7 +244,19 @@ impl Drawable {
             .view_texture_as_render_target_raw(texture.handle(), render_desc)
             .expect("View texture as render target");
 
-        Drawable { texture, target }
+        let stencil = factory
+            .create_depth_stencil_view_only::<gfx::format::DepthStencil>(
+                width, height,
+            )
+            .expect("Stencil buffer creation")
+            .raw()
+            .clone();
+
+        Drawable {
+            texture,
+            target,
+            stencil,
+        }
     }
 
     pub fn texture(&self) -> &Texture {
@@ -142,6 +267,10 @@ impl Drawable {
         &self.target
     }
 
+    pub fn stencil(&self) -> &StencilView {
+        &self.stencil
+    }
+
     pub fn read_pixels(
         &self,
         device: &mut gl::Device,
@@ -200,6 +329,7 @@ fn create_texture_array(
     width: u16,
     height: u16,
     layers: Option<&[&[u8]]>,
+    format: gfx::format::Format,
     bind: gfx::memory::Bind,
 ) -> (RawTexture, ShaderResource) {
     let kind = gfx::texture::Kind::D2Array(
@@ -212,12 +342,12 @@ fn create_texture_array(
     let info = gfx::texture::Info {
         kind: kind,
         levels: 1,
-        format: Surface::get_surface_type(),
+        format: format.0,
         bind: bind,
         usage: gfx::memory::Usage::Data,
     };
 
-    let channel_type = Channel::get_channel_type();
+    let channel_type = format.1;
 
     let texture = factory
         .create_texture_raw(
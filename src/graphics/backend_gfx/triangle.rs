@@ -3,11 +3,17 @@ use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
-use crate::graphics::Transformation;
+use super::pipelines::{self, Pipelines};
+use super::quad::Samplers;
+use super::texture::Texture;
+use super::types::StencilView;
+use crate::graphics::target::Mask;
+use crate::graphics::{BlendMode, DebugDraw, Rectangle, Transformation};
 
 gfx_defines! {
     vertex Vertex {
         position: [f32; 2] = "a_Pos",
+        uv: [f32; 2] = "a_Uv",
         color: [f32; 4] = "a_Color",
     }
 
@@ -17,7 +23,19 @@ gfx_defines! {
 
     pipeline pipe {
         vertices: gfx::VertexBuffer<Vertex> = (),
+        texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
         globals: gfx::ConstantBuffer<Globals> = "Globals",
+        scissor: gfx::Scissor = (),
+        stencil: gfx::StencilTarget<gfx::format::DepthStencil> =
+            gfx::state::Stencil::new(
+                gfx::state::Comparison::Always,
+                0,
+                (
+                    gfx::state::StencilOp::Keep,
+                    gfx::state::StencilOp::Keep,
+                    gfx::state::StencilOp::Keep,
+                ),
+            ),
         out: gfx::RawRenderTarget =
           (
               "Target0",
@@ -33,6 +51,8 @@ pub struct Pipeline {
     indices: gfx::handle::Buffer<gl::Resources, u32>,
     shader: Shader,
     globals: Globals,
+    samplers: Samplers,
+    white: Texture,
 }
 
 impl Pipeline {
@@ -42,6 +62,7 @@ impl Pipeline {
         factory: &mut gl::Factory,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
         target: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        stencil: &StencilView,
     ) -> Pipeline {
         let vertices = factory
             .create_buffer(
@@ -61,23 +82,31 @@ impl Pipeline {
             )
             .expect("Index buffer creation");
 
+        let samplers = Samplers::new(factory);
+
+        // Triangles without a texture sample a single white pixel
+        let white = Texture::new(
+            factory,
+            &image::DynamicImage::ImageRgba8(image::ImageBuffer::from_pixel(
+                1,
+                1,
+                image::Rgba([255, 255, 255, 255]),
+            )),
+        );
+
         let data = pipe::Data {
             vertices,
+            texture: (
+                white.view().clone(),
+                samplers.get(white.sampler()).clone(),
+            ),
             globals: factory.create_constant_buffer(1),
+            scissor: pipelines::scissor(None, target),
+            stencil: pipelines::stencil_target(stencil),
             out: target.clone(),
         };
 
-        let init = pipe::Init {
-            out: (
-                "Target0",
-                format::COLOR,
-                gfx::state::ColorMask::all(),
-                Some(gfx::preset::blend::ALPHA),
-            ),
-            ..pipe::new()
-        };
-
-        let shader = Shader::new(factory, init);
+        let shader = Shader::new(factory);
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -92,6 +121,8 @@ impl Pipeline {
             indices,
             shader,
             globals,
+            samplers,
+            white,
         }
     }
 
@@ -99,10 +130,16 @@ impl Pipeline {
         &mut self,
         factory: &mut gl::Factory,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        texture: Option<&Texture>,
         vertices: &[Vertex],
         indices: &[u32],
         transformation: &Transformation,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
         view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        stencil: &StencilView,
     ) {
         let transformation_matrix: [[f32; 4]; 4] =
             transformation.clone().into();
@@ -115,6 +152,14 @@ impl Pipeline {
                 .expect("Globals upload");
         }
 
+        let texture = texture.unwrap_or(&self.white);
+
+        self.data.texture = (
+            texture.view().clone(),
+            self.samplers.get(texture.sampler()).clone(),
+        );
+        self.data.scissor = pipelines::scissor(clip, view);
+        self.data.stencil = pipelines::stencil_target(stencil);
         self.data.out = view.clone();
 
         if self.data.vertices.len() < vertices.len()
@@ -122,7 +167,7 @@ impl Pipeline {
         {
             let vertices = factory
                 .create_buffer(
-                    self.data.vertices.len(),
+                    vertices.len().max(self.data.vertices.len()),
                     gfx::buffer::Role::Vertex,
                     gfx::memory::Usage::Dynamic,
                     gfx::memory::Bind::SHADER_RESOURCE,
@@ -131,7 +176,7 @@ impl Pipeline {
 
             let indices = factory
                 .create_buffer(
-                    indices.len(),
+                    indices.len().max(self.indices.len()),
                     gfx::buffer::Role::Index,
                     gfx::memory::Usage::Dynamic,
                     gfx::memory::Bind::empty(),
@@ -158,16 +203,20 @@ impl Pipeline {
             buffer: gfx::IndexBuffer::Index32(self.indices.clone()),
         };
 
-        encoder.draw(&slice, &self.shader.state, &self.data);
+        encoder.draw(
+            &slice,
+            self.shader.pipelines.get(debug_draw, blend_mode, mask),
+            &self.data,
+        );
     }
 }
 
 pub struct Shader {
-    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    pipelines: Pipelines<pipe::Meta>,
 }
 
 impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
+    pub fn new(factory: &mut gl::Factory) -> Shader {
         let set = factory
             .create_shader_set(
                 include_bytes!("shader/triangle.vert"),
@@ -175,29 +224,41 @@ impl Shader {
             )
             .expect("Shader set creation");
 
-        let rasterizer = gfx::state::Rasterizer {
-            front_face: gfx::state::FrontFace::CounterClockwise,
-            cull_face: gfx::state::CullFace::Nothing,
-            method: gfx::state::RasterMethod::Fill,
-            offset: None,
-            samples: None,
-        };
+        let pipelines = Pipelines::new(|method, blend, color_mask, stencil| {
+            let rasterizer = gfx::state::Rasterizer {
+                front_face: gfx::state::FrontFace::CounterClockwise,
+                cull_face: gfx::state::CullFace::Nothing,
+                method,
+                offset: None,
+                // Multisampling only has an effect on multisampled targets
+                samples: Some(gfx::state::MultiSample),
+            };
 
-        let state = factory
-            .create_pipeline_state(
+            let init = pipe::Init {
+                stencil,
+                out: ("Target0", format::COLOR, color_mask, Some(blend)),
+                ..pipe::new()
+            };
+
+            factory.create_pipeline_state(
                 &set,
                 Primitive::TriangleList,
                 rasterizer,
                 init,
             )
-            .expect("Pipeline state creation");
+        })
+        .expect("Pipeline state creation");
 
-        Shader { state }
+        Shader { pipelines }
     }
 }
 
 impl Vertex {
-    pub fn new(position: [f32; 2], color: [f32; 4]) -> Vertex {
-        Vertex { position, color }
+    pub fn new(position: [f32; 2], uv: [f32; 2], color: [f32; 4]) -> Vertex {
+        Vertex {
+            position,
+            uv,
+            color,
+        }
     }
 }
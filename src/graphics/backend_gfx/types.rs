@@ -4,6 +4,8 @@ use super::format;
 
 pub type TargetView = gfx::handle::RawRenderTargetView<gl::Resources>;
 
+pub type StencilView = gfx::handle::RawDepthStencilView<gl::Resources>;
+
 pub type RawTexture = gfx::handle::RawTexture<gl::Resources>;
 
 pub type ShaderResource =
@@ -1,4 +1,6 @@
 mod font;
+mod multisample;
+mod pipelines;
 mod quad;
 mod surface;
 pub mod texture;
@@ -6,35 +8,121 @@ mod triangle;
 mod types;
 
 pub use font::Font;
-pub use quad::Quad;
+pub use multisample::Multisample;
+pub use quad::{Quad, Shader};
 pub use surface::Surface;
 pub use texture::Texture;
 pub use triangle::Vertex;
-pub use types::TargetView;
+pub use types::{StencilView, TargetView};
 
-use crate::graphics::{Color, Transformation};
+use std::path::Path;
+use std::time::SystemTime;
+
+use crate::graphics::frame_stats;
+use crate::graphics::target::Mask;
+use crate::graphics::{
+    self, BlendMode, Capabilities, Color, DebugDraw, Format, FrameStats,
+    PresentModes, Rectangle, Sampler, Transformation,
+};
+use crate::load::{AssetSource, Filesystem};
 use crate::{Error, Result};
 
+/// A link between your game and a graphics processor.
+///
+/// It is necessary to perform any kind of graphical operation, like loading
+/// resources and drawing.
+///
+/// A [`Gpu`] can be obtained from a [`Window`] or a [`Frame`].
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Window`]: struct.Window.html
+/// [`Frame`]: struct.Frame.html
 #[allow(missing_debug_implementations)]
-#[allow(missing_docs)]
 pub struct Gpu {
     device: wgpu::Device,
     queue: wgpu::Queue,
     quad_pipeline: quad::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     encoder: wgpu::CommandEncoder,
+    debug_draw: DebugDraw,
+    assets: Box<dyn AssetSource>,
+    stats: frame_stats::Counter,
+    shader_error: Option<String>,
+    font_scratch: Option<texture::Drawable>,
+    adapter_name: String,
+    backend: wgpu::Backend,
+}
+
+/// The raw handles of a [`Gpu`].
+///
+/// Obtain them with [`Gpu::raw`] to integrate other libraries or to record
+/// your own passes. This type is only available with the `raw` feature.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Gpu::raw`]: struct.Gpu.html#method.raw
+#[cfg(feature = "raw")]
+#[allow(missing_debug_implementations)]
+pub struct Raw<'a> {
+    /// The `wgpu` device.
+    pub device: &'a mut wgpu::Device,
+
+    /// The `wgpu` queue.
+    pub queue: &'a mut wgpu::Queue,
+
+    /// The encoder recording the commands of the current frame.
+    ///
+    /// Commands recorded here run in order with the draws of Coffee.
+    pub encoder: &'a mut wgpu::CommandEncoder,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
         event_loop: &winit::event_loop::EventLoop<()>,
+        vsync: bool,
     ) -> Result<(Gpu, Surface)> {
         let window = builder
             .build(event_loop)
             .map_err(|error| Error::WindowCreation(error.to_string()))?;
 
-        let (mut device, queue) = futures::executor::block_on(async {
+        let gpu = Gpu::new().expect("Request adapter");
+        let surface = Surface::new(window, &gpu.device, vsync);
+
+        Ok((gpu, surface))
+    }
+
+    pub(super) fn create_secondary_surface(
+        &mut self,
+        builder: winit::window::WindowBuilder,
+        event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
+        vsync: bool,
+    ) -> Result<Surface> {
+        let window = builder
+            .build(event_loop)
+            .map_err(|error| Error::WindowCreation(error.to_string()))?;
+
+        Ok(Surface::new(window, &self.device, vsync))
+    }
+
+    /// Creates a [`Gpu`] that is not linked to any window.
+    ///
+    /// A headless [`Gpu`] can load resources and draw on a [`Canvas`], whose
+    /// pixels can be read with [`Canvas::read_pixels`]. Use it to render
+    /// offscreen, like in golden image tests running on CI.
+    ///
+    /// It fails if no graphics adapter is available.
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Canvas::read_pixels`]: struct.Canvas.html#method.read_pixels
+    pub fn headless() -> Result<Gpu> {
+        Gpu::new().ok_or_else(|| {
+            Error::GpuCreation(String::from("No graphics adapter found"))
+        })
+    }
+
+    fn new() -> Option<Gpu> {
+        let (mut device, queue, info) = futures::executor::block_on(async {
             let adapter = wgpu::Adapter::request(
                 &wgpu::RequestAdapterOptions {
                     power_preference: wgpu::PowerPreference::HighPerformance,
@@ -42,8 +130,7 @@ impl Gpu {
                 },
                 wgpu::BackendBit::all(),
             )
-            .await
-            .expect("Request adapter");
+            .await?;
 
             let (device, queue) = adapter
                 .request_device(&wgpu::DeviceDescriptor {
@@ -54,29 +141,139 @@ impl Gpu {
                 })
                 .await;
 
-            (device, queue)
-        });
-
-        let surface = Surface::new(window, &device);
+            Some((device, queue, adapter.get_info()))
+        })?;
 
         let quad_pipeline = quad::Pipeline::new(&mut device);
-        let triangle_pipeline = triangle::Pipeline::new(&mut device);
+        let triangle_pipeline =
+            triangle::Pipeline::new(&mut device, &queue, &quad_pipeline);
 
         let encoder =
             device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
                 label: Some("coffee::backend encoder"),
             });
 
-        Ok((
-            Gpu {
-                device,
-                queue,
-                quad_pipeline,
-                triangle_pipeline,
-                encoder,
-            },
-            surface,
-        ))
+        Some(Gpu {
+            device,
+            queue,
+            quad_pipeline,
+            triangle_pipeline,
+            encoder,
+            debug_draw: DebugDraw::Off,
+            assets: Box::new(Filesystem::default()),
+            stats: frame_stats::Counter::new(),
+            shader_error: None,
+            font_scratch: None,
+            adapter_name: info.name,
+            backend: info.backend,
+        })
+    }
+
+    /// Returns the [`Capabilities`] of the [`Gpu`].
+    ///
+    /// [`Capabilities`]: struct.Capabilities.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn capabilities(&self) -> Capabilities {
+        // `wgpu` does not expose texture limits yet. These are the minimums
+        // guaranteed by every supported backend.
+        Capabilities {
+            max_texture_size: 8192,
+            max_texture_array_layers: 256,
+            max_samples: 4,
+            // `wgpu` 0.5 has no BCn texture formats nor a feature to request
+            // them
+            compressed_textures: false,
+            secondary_windows: true,
+            present_modes: present_modes(self.backend),
+        }
+    }
+
+    /// Returns the name of the graphics adapter used by the [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn adapter_name(&self) -> &str {
+        &self.adapter_name
+    }
+
+    /// Returns the maximum width and height of a texture, in pixels.
+    pub fn max_texture_size(&self) -> u32 {
+        self.capabilities().max_texture_size
+    }
+
+    /// Returns the current [`DebugDraw`] mode of the [`Gpu`].
+    ///
+    /// [`DebugDraw`]: enum.DebugDraw.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn debug_draw(&self) -> DebugDraw {
+        self.debug_draw
+    }
+
+    /// Sets the [`DebugDraw`] mode of the [`Gpu`].
+    ///
+    /// Use it to inspect the geometry of your scene or to find areas that are
+    /// drawn too many times.
+    ///
+    /// [`DebugDraw`]: enum.DebugDraw.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn set_debug_draw(&mut self, mode: DebugDraw) {
+        self.debug_draw = mode;
+    }
+
+    /// Returns the [`FrameStats`] of the last presented frame.
+    ///
+    /// [`FrameStats`]: struct.FrameStats.html
+    pub fn last_frame_stats(&self) -> FrameStats {
+        self.stats.last_frame()
+    }
+
+    /// Sets the [`AssetSource`] used to read the assets loaded by the [`Gpu`].
+    ///
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn set_asset_source<S: AssetSource + 'static>(&mut self, source: S) {
+        self.assets = Box::new(source);
+    }
+
+    /// Reads the asset with the given logical path from the [`AssetSource`] of
+    /// the [`Gpu`].
+    ///
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn read_asset<P: AsRef<Path>>(&self, path: P) -> Result<Vec<u8>> {
+        self.assets.read(path.as_ref())
+    }
+
+    /// Returns the [`RawGpu`] handles of the graphics backend.
+    ///
+    /// This method is only available with the `raw` feature.
+    ///
+    /// # Safety
+    /// Coffee assumes it owns the state of the device. Submitting work to the
+    /// queue directly breaks the ordering of the commands of the current
+    /// frame, and destroying resources used by Coffee leads to undefined
+    /// behavior. Prefer recording commands in the given encoder.
+    ///
+    /// [`RawGpu`]: struct.RawGpu.html
+    #[cfg(feature = "raw")]
+    #[allow(unsafe_code)]
+    pub unsafe fn raw(&mut self) -> Raw<'_> {
+        Raw {
+            device: &mut self.device,
+            queue: &mut self.queue,
+            encoder: &mut self.encoder,
+        }
+    }
+
+    pub(super) fn asset_modified(&self, path: &Path) -> Option<SystemTime> {
+        self.assets.modified(path)
+    }
+
+    pub(crate) fn shader_error(&self) -> Option<&str> {
+        self.shader_error.as_ref().map(String::as_str)
+    }
+
+    pub(super) fn set_shader_error(&mut self, error: Option<String>) {
+        self.shader_error = error;
     }
 
     pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
@@ -99,6 +296,23 @@ impl Gpu {
         });
     }
 
+    pub(super) fn clear_stencil(&mut self, stencil: &StencilView) {
+        let _ = self.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[],
+            depth_stencil_attachment: Some(
+                wgpu::RenderPassDepthStencilAttachmentDescriptor {
+                    attachment: stencil,
+                    depth_load_op: wgpu::LoadOp::Load,
+                    depth_store_op: wgpu::StoreOp::Store,
+                    clear_depth: 1.0,
+                    stencil_load_op: wgpu::LoadOp::Clear,
+                    stencil_store_op: wgpu::StoreOp::Store,
+                    clear_stencil: 0,
+                },
+            ),
+        });
+    }
+
     pub(super) fn upload_texture(
         &mut self,
         image: &image::DynamicImage,
@@ -118,10 +332,45 @@ impl Gpu {
         )
     }
 
+    #[cfg(feature = "raw")]
+    pub(super) fn wrap_texture(
+        &mut self,
+        external: texture::External,
+    ) -> Result<Texture> {
+        Ok(Texture::from_external(
+            &mut self.device,
+            &self.quad_pipeline,
+            external,
+        ))
+    }
+
+    pub(super) fn update_texture(
+        &mut self,
+        texture: &Texture,
+        layer: u16,
+        region: Rectangle<u16>,
+        rgba: &[u8],
+    ) {
+        let mut bgra = rgba.to_vec();
+
+        for pixel in bgra.chunks_mut(4) {
+            pixel.swap(0, 2);
+        }
+
+        texture.update(
+            &mut self.device,
+            &mut self.encoder,
+            layer,
+            region,
+            &bgra,
+        );
+    }
+
     pub(super) fn create_drawable_texture(
         &mut self,
         width: u16,
         height: u16,
+        format: Format,
     ) -> texture::Drawable {
         texture::Drawable::new(
             &mut self.device,
@@ -129,6 +378,7 @@ impl Gpu {
             &self.quad_pipeline,
             width,
             height,
+            format,
         )
     }
 
@@ -147,24 +397,110 @@ impl Gpu {
         drawable.read_pixels(&mut self.device, &self.queue, encoder)
     }
 
-    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
-        Font::from_bytes(&mut self.device, bytes)
+    pub(super) fn create_multisample(
+        &mut self,
+        width: u16,
+        height: u16,
+        samples: u8,
+    ) -> Multisample {
+        Multisample::new(
+            &mut self.device,
+            &self.queue,
+            &self.quad_pipeline,
+            width,
+            height,
+            samples,
+        )
+    }
+
+    pub(super) fn resolve(
+        &mut self,
+        multisample: &Multisample,
+        drawable: &texture::Drawable,
+    ) {
+        multisample.resolve(&mut self.encoder, drawable);
+    }
+
+    pub(super) fn create_shader(&mut self, fragment: &[u8]) -> Result<Shader> {
+        let shader = self
+            .quad_pipeline
+            .create_shader(&mut self.device, fragment)
+            .map_err(|error| Error::Shader(error.to_string()))?;
+
+        Ok(shader)
+    }
+
+    pub(super) fn upload_font(
+        &mut self,
+        bytes: wgpu_glyph::rusttype::SharedBytes<'static>,
+        cache_size: (u32, u32),
+    ) -> Font {
+        Font::from_bytes(&mut self.device, bytes, cache_size)
     }
 
     pub(super) fn draw_triangles(
         &mut self,
+        texture: Option<&Texture>,
         vertices: &[Vertex],
         indices: &[u32],
         view: &TargetView,
         transformation: &Transformation,
+        blend_mode: BlendMode,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
+        stencil: &StencilView,
+        samples: u32,
+        format: Format,
     ) {
+        self.stats.triangles(indices.len());
+
+        self.triangle_pipeline.draw(
+            &mut self.device,
+            &mut self.encoder,
+            texture,
+            vertices,
+            indices,
+            transformation,
+            self.debug_draw,
+            blend_mode,
+            clip,
+            mask,
+            view,
+            stencil,
+            samples,
+            format,
+        );
+    }
+
+    pub(super) fn clear_triangles(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &TargetView,
+        transformation: &Transformation,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
+        stencil: &StencilView,
+        samples: u32,
+        format: Format,
+    ) {
+        self.stats.triangles(indices.len());
+
         self.triangle_pipeline.draw(
             &mut self.device,
             &mut self.encoder,
+            None,
             vertices,
             indices,
             transformation,
+            DebugDraw::Off,
+            BlendMode::Replace,
+            clip,
+            mask,
             view,
+            stencil,
+            samples,
+            format,
         );
     }
 
@@ -174,14 +510,34 @@ impl Gpu {
         instances: &[Quad],
         view: &TargetView,
         transformation: &Transformation,
+        shader: Option<&Shader>,
+        blend_mode: BlendMode,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
+        stencil: &StencilView,
+        samples: u32,
+        format: Format,
     ) {
+        let draw_calls = (instances.len() + Quad::MAX - 1) / Quad::MAX;
+
+        self.stats.quads(texture.id(), instances.len(), draw_calls);
+
         self.quad_pipeline.draw_textured(
             &mut self.device,
             &mut self.encoder,
             texture.binding(),
+            texture.sampler(),
             instances,
             transformation,
+            self.debug_draw,
+            blend_mode,
+            shader,
+            clip,
+            mask,
             view,
+            stencil,
+            samples,
+            format,
         );
     }
 
@@ -191,6 +547,119 @@ impl Gpu {
         target: &TargetView,
         transformation: Transformation,
     ) {
+        self.stats.font();
+
         font.draw(&mut self.device, &mut self.encoder, target, transformation);
     }
+
+    pub(super) fn draw_font_multisampled(
+        &mut self,
+        font: &mut Font,
+        multisample: &Multisample,
+        transformation: Transformation,
+    ) {
+        let scratch = multisample.scratch();
+
+        self.clear(scratch.target(), Color::TRANSPARENT);
+        self.draw_font(font, scratch.target(), transformation);
+        self.draw_scratch(
+            scratch,
+            multisample.target(),
+            multisample.stencil(),
+            multisample.samples(),
+            Format::Rgba8,
+        );
+    }
+
+    pub(super) fn draw_font_hdr(
+        &mut self,
+        font: &mut Font,
+        target: &TargetView,
+        stencil: &StencilView,
+        format: Format,
+        width: u32,
+        height: u32,
+        transformation: Transformation,
+    ) {
+        let is_outdated = match &self.font_scratch {
+            Some(scratch) => {
+                u32::from(scratch.texture().width()) != width
+                    || u32::from(scratch.texture().height()) != height
+            }
+            None => true,
+        };
+
+        // `wgpu_glyph` can only draw on targets with the default format, so
+        // text is drawn on a scratch texture first
+        if is_outdated {
+            self.font_scratch = Some(self.create_drawable_texture(
+                width as u16,
+                height as u16,
+                Format::Rgba8,
+            ));
+        }
+
+        let scratch = self.font_scratch.clone().expect("Font scratch");
+
+        self.clear(scratch.target(), Color::TRANSPARENT);
+        self.draw_font(font, scratch.target(), transformation);
+        self.draw_scratch(&scratch, target, stencil, 1, format);
+    }
+
+    fn draw_scratch(
+        &mut self,
+        scratch: &texture::Drawable,
+        target: &TargetView,
+        stencil: &StencilView,
+        samples: u32,
+        format: Format,
+    ) {
+        let texture = scratch.texture();
+        let width = f32::from(texture.width());
+        let height = f32::from(texture.height());
+
+        // The scratch texture has premultiplied alpha, as text is blended on
+        // a transparent background
+        self.quad_pipeline.draw_textured(
+            &mut self.device,
+            &mut self.encoder,
+            texture.binding(),
+            Sampler::default(),
+            &[Quad::from(graphics::Quad {
+                size: (width, height),
+                ..graphics::Quad::default()
+            })],
+            &(texture::Drawable::render_transformation()
+                * Transformation::orthographic(width, height)),
+            DebugDraw::Off,
+            BlendMode::Premultiplied,
+            None,
+            None,
+            Mask::None,
+            target,
+            stencil,
+            samples,
+            format,
+        );
+    }
+}
+
+// `wgpu` does not expose the present modes of a surface yet, and it falls
+// back to `Fifo` when a mode is not supported. Therefore, we only report the
+// modes that the swap chain of the adapter backend always provides.
+fn present_modes(backend: wgpu::Backend) -> PresentModes {
+    match backend {
+        wgpu::Backend::Metal | wgpu::Backend::Dx11 | wgpu::Backend::Dx12 => {
+            PresentModes {
+                fifo: true,
+                mailbox: false,
+                immediate: true,
+            }
+        }
+        _ => PresentModes {
+            fifo: true,
+            mailbox: false,
+            immediate: false,
+        },
+    }
 }
@@ -0,0 +1,116 @@
+use std::rc::Rc;
+
+use super::pipelines;
+use super::quad;
+use super::texture::Drawable;
+use super::types::{StencilView, TargetView};
+use crate::graphics::Format;
+
+/// The multisampled color and stencil buffers of a target.
+///
+/// `wgpu_glyph` cannot draw on multisampled targets. Instead, text is drawn
+/// on a scratch texture with a single sample, which is then drawn on the
+/// multisampled target.
+#[derive(Clone)]
+pub struct Multisample {
+    samples: u32,
+    target: Rc<TargetView>,
+    stencil: Rc<StencilView>,
+    scratch: Drawable,
+}
+
+impl Multisample {
+    pub fn new(
+        device: &mut wgpu::Device,
+        queue: &wgpu::Queue,
+        pipeline: &quad::Pipeline,
+        width: u16,
+        height: u16,
+        samples: u8,
+    ) -> Multisample {
+        let extent = wgpu::Extent3d {
+            width: u32::from(width),
+            height: u32::from(height),
+            depth: 1,
+        };
+
+        let create_view = |label, format| {
+            let texture = device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: extent,
+                array_layer_count: 1,
+                mip_level_count: 1,
+                sample_count: u32::from(samples),
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+            });
+
+            texture.create_default_view()
+        };
+
+        let target = create_view(
+            "coffee::backend::multisample target",
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+        );
+
+        let stencil = create_view(
+            "coffee::backend::multisample stencil",
+            pipelines::STENCIL_FORMAT,
+        );
+
+        let scratch = Drawable::new(
+            device,
+            queue,
+            pipeline,
+            width,
+            height,
+            Format::Rgba8,
+        );
+
+        Multisample {
+            samples: u32::from(samples),
+            target: Rc::new(target),
+            stencil: Rc::new(stencil),
+            scratch,
+        }
+    }
+
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+
+    pub fn target(&self) -> &TargetView {
+        &self.target
+    }
+
+    pub fn stencil(&self) -> &StencilView {
+        &self.stencil
+    }
+
+    pub fn scratch(&self) -> &Drawable {
+        &self.scratch
+    }
+
+    pub fn resolve(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        drawable: &Drawable,
+    ) {
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: &self.target,
+                resolve_target: Some(drawable.target()),
+                load_op: wgpu::LoadOp::Load,
+                store_op: wgpu::StoreOp::Store,
+                clear_color: wgpu::Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+    }
+}
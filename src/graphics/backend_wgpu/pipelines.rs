@@ -0,0 +1,291 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::graphics::target::Mask;
+use crate::graphics::{BlendMode, DebugDraw, Format};
+
+/// The format of the stencil buffer of every target.
+pub const STENCIL_FORMAT: wgpu::TextureFormat =
+    wgpu::TextureFormat::Depth24PlusStencil8;
+
+/// A render pipeline for every `Mask`, `BlendMode`, and `DebugDraw` mode.
+///
+/// `wgpu` does not support line polygon modes without an extension, so the
+/// wireframe pipeline uses a line list topology instead. Its users need to
+/// provide line indices when drawing in wireframe mode.
+///
+/// Every pipeline uses a stencil buffer with the `STENCIL_FORMAT`. Masks are
+/// written and tested with a reference value of `1`.
+pub struct Pipelines {
+    unmasked: Set,
+    masked: Set,
+    mask: wgpu::RenderPipeline,
+}
+
+impl Pipelines {
+    pub fn new<F>(create_pipeline: F) -> Pipelines
+    where
+        F: Fn(
+            wgpu::PrimitiveTopology,
+            wgpu::BlendDescriptor,
+            wgpu::BlendDescriptor,
+            wgpu::ColorWrite,
+            wgpu::StencilStateFaceDescriptor,
+        ) -> wgpu::RenderPipeline,
+    {
+        let (color_blend, alpha_blend) = blend(BlendMode::Replace);
+
+        Pipelines {
+            unmasked: Set::new(&create_pipeline, stencil(Mask::None)),
+            masked: Set::new(&create_pipeline, stencil(Mask::Test)),
+            mask: create_pipeline(
+                wgpu::PrimitiveTopology::TriangleList,
+                color_blend,
+                alpha_blend,
+                wgpu::ColorWrite::empty(),
+                stencil(Mask::Write),
+            ),
+        }
+    }
+
+    pub fn get(
+        &self,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+        mask: Mask,
+    ) -> &wgpu::RenderPipeline {
+        match mask {
+            Mask::None => self.unmasked.get(debug_draw, blend_mode),
+            Mask::Write => &self.mask,
+            Mask::Test => self.masked.get(debug_draw, blend_mode),
+        }
+    }
+}
+
+/// The `Pipelines` of a shader for every sample count and `Format` it is
+/// drawn with.
+///
+/// Render pipelines only work with targets of a specific sample count and
+/// format. The pipelines of a combination are created the first time they are
+/// needed.
+pub struct Cache {
+    pipelines: RefCell<HashMap<(u32, Format), Rc<Pipelines>>>,
+}
+
+impl Cache {
+    pub fn new() -> Cache {
+        Cache {
+            pipelines: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn get<F>(
+        &self,
+        samples: u32,
+        format: Format,
+        create_pipelines: F,
+    ) -> Rc<Pipelines>
+    where
+        F: FnOnce() -> Pipelines,
+    {
+        self.pipelines
+            .borrow_mut()
+            .entry((samples, format))
+            .or_insert_with(|| Rc::new(create_pipelines()))
+            .clone()
+    }
+}
+
+struct Set {
+    alpha: wgpu::RenderPipeline,
+    additive: wgpu::RenderPipeline,
+    multiply: wgpu::RenderPipeline,
+    premultiplied: wgpu::RenderPipeline,
+    replace: wgpu::RenderPipeline,
+    wireframe: wgpu::RenderPipeline,
+    overdraw: wgpu::RenderPipeline,
+}
+
+impl Set {
+    fn new<F>(
+        create_pipeline: &F,
+        stencil: wgpu::StencilStateFaceDescriptor,
+    ) -> Set
+    where
+        F: Fn(
+            wgpu::PrimitiveTopology,
+            wgpu::BlendDescriptor,
+            wgpu::BlendDescriptor,
+            wgpu::ColorWrite,
+            wgpu::StencilStateFaceDescriptor,
+        ) -> wgpu::RenderPipeline,
+    {
+        let fill = |blend_mode| {
+            let (color_blend, alpha_blend) = blend(blend_mode);
+
+            create_pipeline(
+                wgpu::PrimitiveTopology::TriangleList,
+                color_blend,
+                alpha_blend,
+                wgpu::ColorWrite::ALL,
+                stencil.clone(),
+            )
+        };
+
+        let (color_blend, alpha_blend) = blend(BlendMode::Alpha);
+
+        let additive = wgpu::BlendDescriptor {
+            src_factor: wgpu::BlendFactor::One,
+            dst_factor: wgpu::BlendFactor::One,
+            operation: wgpu::BlendOperation::Add,
+        };
+
+        Set {
+            alpha: fill(BlendMode::Alpha),
+            additive: fill(BlendMode::Additive),
+            multiply: fill(BlendMode::Multiply),
+            premultiplied: fill(BlendMode::Premultiplied),
+            replace: fill(BlendMode::Replace),
+            wireframe: create_pipeline(
+                wgpu::PrimitiveTopology::LineList,
+                color_blend,
+                alpha_blend,
+                wgpu::ColorWrite::ALL,
+                stencil.clone(),
+            ),
+            overdraw: create_pipeline(
+                wgpu::PrimitiveTopology::TriangleList,
+                additive.clone(),
+                additive,
+                wgpu::ColorWrite::ALL,
+                stencil.clone(),
+            ),
+        }
+    }
+
+    fn get(
+        &self,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+    ) -> &wgpu::RenderPipeline {
+        match debug_draw {
+            DebugDraw::Off | DebugDraw::Bounds => match blend_mode {
+                BlendMode::Alpha => &self.alpha,
+                BlendMode::Additive => &self.additive,
+                BlendMode::Multiply => &self.multiply,
+                BlendMode::Premultiplied => &self.premultiplied,
+                BlendMode::Replace => &self.replace,
+            },
+            DebugDraw::Wireframe => &self.wireframe,
+            DebugDraw::Overdraw => &self.overdraw,
+        }
+    }
+}
+
+/// Returns the texture format of a target with the given `Format`.
+pub fn texture_format(format: Format) -> wgpu::TextureFormat {
+    match format {
+        Format::Rgba8 => wgpu::TextureFormat::Bgra8UnormSrgb,
+        Format::Rgba16Float => wgpu::TextureFormat::Rgba16Float,
+    }
+}
+
+/// Returns the depth-stencil state of a pipeline using the given stencil
+/// operations.
+pub fn depth_stencil_state(
+    stencil: wgpu::StencilStateFaceDescriptor,
+) -> wgpu::DepthStencilStateDescriptor {
+    wgpu::DepthStencilStateDescriptor {
+        format: STENCIL_FORMAT,
+        depth_write_enabled: false,
+        depth_compare: wgpu::CompareFunction::Always,
+        stencil_front: stencil.clone(),
+        stencil_back: stencil,
+        stencil_read_mask: !0,
+        stencil_write_mask: !0,
+    }
+}
+
+/// Returns the stencil attachment of a render pass drawing on a target.
+pub fn stencil_attachment(
+    stencil: &wgpu::TextureView,
+) -> wgpu::RenderPassDepthStencilAttachmentDescriptor<'_> {
+    wgpu::RenderPassDepthStencilAttachmentDescriptor {
+        attachment: stencil,
+        depth_load_op: wgpu::LoadOp::Load,
+        depth_store_op: wgpu::StoreOp::Store,
+        clear_depth: 1.0,
+        stencil_load_op: wgpu::LoadOp::Load,
+        stencil_store_op: wgpu::StoreOp::Store,
+        clear_stencil: 0,
+    }
+}
+
+fn stencil(mask: Mask) -> wgpu::StencilStateFaceDescriptor {
+    let (compare, pass_op) = match mask {
+        Mask::None => {
+            (wgpu::CompareFunction::Always, wgpu::StencilOperation::Keep)
+        }
+        Mask::Write => (
+            wgpu::CompareFunction::Always,
+            wgpu::StencilOperation::Replace,
+        ),
+        Mask::Test => {
+            (wgpu::CompareFunction::Equal, wgpu::StencilOperation::Keep)
+        }
+    };
+
+    wgpu::StencilStateFaceDescriptor {
+        compare,
+        fail_op: wgpu::StencilOperation::Keep,
+        depth_fail_op: wgpu::StencilOperation::Keep,
+        pass_op,
+    }
+}
+
+/// Returns the color and alpha blend descriptors of a `BlendMode`.
+fn blend(
+    blend_mode: BlendMode,
+) -> (wgpu::BlendDescriptor, wgpu::BlendDescriptor) {
+    let descriptor = |src_factor, dst_factor| wgpu::BlendDescriptor {
+        src_factor,
+        dst_factor,
+        operation: wgpu::BlendOperation::Add,
+    };
+
+    match blend_mode {
+        BlendMode::Alpha => (
+            descriptor(
+                wgpu::BlendFactor::SrcAlpha,
+                wgpu::BlendFactor::OneMinusSrcAlpha,
+            ),
+            descriptor(
+                wgpu::BlendFactor::One,
+                wgpu::BlendFactor::OneMinusSrcAlpha,
+            ),
+        ),
+        BlendMode::Additive => (
+            descriptor(wgpu::BlendFactor::SrcAlpha, wgpu::BlendFactor::One),
+            descriptor(wgpu::BlendFactor::One, wgpu::BlendFactor::One),
+        ),
+        BlendMode::Multiply => (
+            descriptor(wgpu::BlendFactor::DstColor, wgpu::BlendFactor::Zero),
+            descriptor(wgpu::BlendFactor::DstAlpha, wgpu::BlendFactor::Zero),
+        ),
+        BlendMode::Premultiplied => {
+            let premultiplied = descriptor(
+                wgpu::BlendFactor::One,
+                wgpu::BlendFactor::OneMinusSrcAlpha,
+            );
+
+            (premultiplied.clone(), premultiplied)
+        }
+        BlendMode::Replace => {
+            let replace =
+                descriptor(wgpu::BlendFactor::One, wgpu::BlendFactor::Zero);
+
+            (replace.clone(), replace)
+        }
+    }
+}
@@ -1,32 +1,33 @@
+use std::collections::HashMap;
 use std::mem;
 
-use crate::graphics::{self, Transformation};
+use super::pipelines::{self, Pipelines};
+use super::types::StencilView;
+use crate::graphics::target::Mask;
+use crate::graphics::{
+    self, BlendMode, DebugDraw, Filter, Format, Point, Rectangle, Sampler,
+    Transformation, Vector, WrapMode,
+};
 use zerocopy::AsBytes;
 
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: pipelines::Cache,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
     transform: wgpu::Buffer,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
+    line_indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    constants: Constants,
     texture_layout: wgpu::BindGroupLayout,
+    parameters: wgpu::Buffer,
+    parameters_binding: wgpu::BindGroup,
 }
 
 impl Pipeline {
     pub fn new(device: &mut wgpu::Device) -> Pipeline {
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Nearest,
-            min_filter: wgpu::FilterMode::Nearest,
-            mipmap_filter: wgpu::FilterMode::Nearest,
-            lod_min_clamp: -100.0,
-            lod_max_clamp: 100.0,
-            compare: wgpu::CompareFunction::Always,
-        });
-
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("coffee::backend::quad constants"),
@@ -51,24 +52,8 @@ impl Pipeline {
             wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
         );
 
-        let constant_bind_group =
-            device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("coffee::backend::quad constants"),
-                layout: &constant_layout,
-                bindings: &[
-                    wgpu::Binding {
-                        binding: 0,
-                        resource: wgpu::BindingResource::Buffer {
-                            buffer: &transform_buffer,
-                            range: 0..64,
-                        },
-                    },
-                    wgpu::Binding {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
-                    },
-                ],
-            });
+        let constants =
+            Constants::new(device, &constant_layout, &transform_buffer);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -84,9 +69,41 @@ impl Pipeline {
                 }],
             });
 
+        let parameters_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("coffee::backend::quad parameters"),
+                bindings: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::UniformBuffer { dynamic: false },
+                }],
+            });
+
+        let parameters = device.create_buffer_with_data(
+            [0.0f32; 4].as_bytes(),
+            wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+        );
+
+        let parameters_binding =
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("coffee::backend::quad parameters"),
+                layout: &parameters_layout,
+                bindings: &[wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::Buffer {
+                        buffer: &parameters,
+                        range: 0..16,
+                    },
+                }],
+            });
+
         let layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-                bind_group_layouts: &[&constant_layout, &texture_layout],
+                bind_group_layouts: &[
+                    &constant_layout,
+                    &texture_layout,
+                    &parameters_layout,
+                ],
             });
 
         let vs = include_bytes!("shader/quad.vert.spv");
@@ -101,84 +118,18 @@ impl Pipeline {
                 .expect("Read quad fragment shader as SPIR-V"),
         );
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint16,
-                    vertex_buffers: &[
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Vertex>() as u64,
-                            step_mode: wgpu::InputStepMode::Vertex,
-                            attributes: &[wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            }],
-                        },
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Quad>() as u64,
-                            step_mode: wgpu::InputStepMode::Instance,
-                            attributes: &[
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 1,
-                                    format: wgpu::VertexFormat::Float4,
-                                    offset: 0,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 2,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * 4,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 3,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * (4 + 2),
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 4,
-                                    format: wgpu::VertexFormat::Uint,
-                                    offset: 4 * (4 + 2 + 2),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let pipelines = pipelines::Cache::new();
+
+        let _ = pipelines.get(1, Format::Rgba8, || {
+            create_pipelines(
+                device,
+                &layout,
+                &vs_module,
+                &fs_module,
+                1,
+                Format::Rgba8,
+            )
+        });
 
         let vertices = device.create_buffer_with_data(
             QUAD_VERTS.as_bytes(),
@@ -190,6 +141,11 @@ impl Pipeline {
             wgpu::BufferUsage::INDEX,
         );
 
+        let line_indices = device.create_buffer_with_data(
+            QUAD_LINE_INDICES.as_bytes(),
+            wgpu::BufferUsage::INDEX,
+        );
+
         let instances = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::quad instances"),
             size: mem::size_of::<Quad>() as u64 * Quad::MAX as u64,
@@ -197,16 +153,26 @@ impl Pipeline {
         });
 
         Pipeline {
-            pipeline,
+            pipelines,
+            layout,
+            vs_module,
+            fs_module,
             transform: transform_buffer,
             vertices,
             indices,
+            line_indices,
             instances,
-            constants: constant_bind_group,
+            constants,
             texture_layout,
+            parameters,
+            parameters_binding,
         }
     }
 
+    pub fn texture_layout(&self) -> &wgpu::BindGroupLayout {
+        &self.texture_layout
+    }
+
     pub fn create_texture_binding(
         &self,
         device: &mut wgpu::Device,
@@ -224,14 +190,52 @@ impl Pipeline {
         TextureBinding(binding)
     }
 
+    pub fn create_shader(
+        &self,
+        device: &mut wgpu::Device,
+        fragment: &[u8],
+    ) -> std::io::Result<Shader> {
+        let fs_module = device.create_shader_module(&wgpu::read_spirv(
+            std::io::Cursor::new(fragment),
+        )?);
+
+        let pipelines = pipelines::Cache::new();
+
+        let _ = pipelines.get(1, Format::Rgba8, || {
+            create_pipelines(
+                device,
+                &self.layout,
+                &self.vs_module,
+                &fs_module,
+                1,
+                Format::Rgba8,
+            )
+        });
+
+        Ok(Shader {
+            fs_module,
+            pipelines,
+            parameters: [0.0; 4],
+        })
+    }
+
     pub fn draw_textured(
         &mut self,
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         texture: &TextureBinding,
+        sampler: Sampler,
         instances: &[Quad],
         transformation: &Transformation,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+        shader: Option<&Shader>,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
         target: &wgpu::TextureView,
+        stencil: &StencilView,
+        samples: u32,
+        format: Format,
     ) {
         let matrix: [f32; 16] = transformation.clone().into();
 
@@ -248,6 +252,44 @@ impl Pipeline {
             16 * 4,
         );
 
+        let (indices, index_count) = match debug_draw {
+            DebugDraw::Wireframe => {
+                (&self.line_indices, QUAD_LINE_INDICES.len() as u32)
+            }
+            _ => (&self.indices, QUAD_INDICES.len() as u32),
+        };
+
+        let (fs_module, cache, parameters) = match shader {
+            Some(shader) => {
+                (&shader.fs_module, &shader.pipelines, shader.parameters)
+            }
+            None => (&self.fs_module, &self.pipelines, [0.0; 4]),
+        };
+
+        let parameters_buffer = device.create_buffer_with_data(
+            parameters.as_bytes(),
+            wgpu::BufferUsage::COPY_SRC,
+        );
+
+        encoder.copy_buffer_to_buffer(
+            &parameters_buffer,
+            0,
+            &self.parameters,
+            0,
+            4 * 4,
+        );
+
+        let pipelines = cache.get(samples, format, || {
+            create_pipelines(
+                device,
+                &self.layout,
+                &self.vs_module,
+                fs_module,
+                samples,
+                format,
+            )
+        });
+
         let mut i = 0;
         let total = instances.len();
 
@@ -285,21 +327,32 @@ impl Pipeline {
                                 },
                             },
                         ],
-                        depth_stencil_attachment: None,
+                        depth_stencil_attachment: Some(
+                            pipelines::stencil_attachment(stencil),
+                        ),
                     });
 
-                render_pass.set_pipeline(&self.pipeline);
-                render_pass.set_bind_group(0, &self.constants, &[]);
+                render_pass
+                    .set_pipeline(pipelines.get(debug_draw, blend_mode, mask));
+                render_pass.set_stencil_reference(1);
+
+                if let Some(clip) = clip {
+                    render_pass.set_scissor_rect(
+                        clip.x,
+                        clip.y,
+                        clip.width,
+                        clip.height,
+                    );
+                }
+
+                render_pass.set_bind_group(0, self.constants.get(sampler), &[]);
                 render_pass.set_bind_group(1, &texture.0, &[]);
-                render_pass.set_index_buffer(&self.indices, 0, 0);
+                render_pass.set_bind_group(2, &self.parameters_binding, &[]);
+                render_pass.set_index_buffer(indices, 0, 0);
                 render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
                 render_pass.set_vertex_buffer(1, &self.instances, 0, 0);
 
-                render_pass.draw_indexed(
-                    0..QUAD_INDICES.len() as u32,
-                    0,
-                    0..amount as u32,
-                );
+                render_pass.draw_indexed(0..index_count, 0, 0..amount as u32);
             }
 
             i += Quad::MAX;
@@ -307,6 +360,162 @@ impl Pipeline {
     }
 }
 
+fn create_pipelines(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    samples: u32,
+    format: Format,
+) -> Pipelines {
+    let create_pipeline =
+        |primitive_topology, color_blend, alpha_blend, write_mask, stencil| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                layout,
+                vertex_stage: wgpu::ProgrammableStageDescriptor {
+                    module: vs_module,
+                    entry_point: "main",
+                },
+                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                    module: fs_module,
+                    entry_point: "main",
+                }),
+                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                    front_face: wgpu::FrontFace::Cw,
+                    cull_mode: wgpu::CullMode::None,
+                    depth_bias: 0,
+                    depth_bias_slope_scale: 0.0,
+                    depth_bias_clamp: 0.0,
+                }),
+                primitive_topology,
+                color_states: &[wgpu::ColorStateDescriptor {
+                    format: pipelines::texture_format(format),
+                    color_blend,
+                    alpha_blend,
+                    write_mask,
+                }],
+                depth_stencil_state: Some(pipelines::depth_stencil_state(
+                    stencil,
+                )),
+                vertex_state: wgpu::VertexStateDescriptor {
+                    index_format: wgpu::IndexFormat::Uint16,
+                    vertex_buffers: &[
+                        wgpu::VertexBufferDescriptor {
+                            stride: mem::size_of::<Vertex>() as u64,
+                            step_mode: wgpu::InputStepMode::Vertex,
+                            attributes: &[wgpu::VertexAttributeDescriptor {
+                                shader_location: 0,
+                                format: wgpu::VertexFormat::Float2,
+                                offset: 0,
+                            }],
+                        },
+                        wgpu::VertexBufferDescriptor {
+                            stride: mem::size_of::<Quad>() as u64,
+                            step_mode: wgpu::InputStepMode::Instance,
+                            attributes: &[
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 1,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 0,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 2,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * 4,
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 3,
+                                    format: wgpu::VertexFormat::Float2,
+                                    offset: 4 * (4 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 4,
+                                    format: wgpu::VertexFormat::Uint,
+                                    offset: 4 * (4 + 4 + 2 + 4),
+                                },
+                                wgpu::VertexAttributeDescriptor {
+                                    shader_location: 5,
+                                    format: wgpu::VertexFormat::Float4,
+                                    offset: 4 * (4 + 4 + 2),
+                                },
+                            ],
+                        },
+                    ],
+                },
+                sample_count: samples,
+                sample_mask: !0,
+                alpha_to_coverage_enabled: false,
+            })
+        };
+
+    Pipelines::new(&create_pipeline)
+}
+
+pub struct Constants {
+    bind_groups: HashMap<Sampler, wgpu::BindGroup>,
+}
+
+impl Constants {
+    pub fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        transform: &wgpu::Buffer,
+    ) -> Constants {
+        let create_bind_group = |sampler: Sampler| {
+            let filter = match sampler.filter {
+                Filter::Nearest => wgpu::FilterMode::Nearest,
+                Filter::Linear => wgpu::FilterMode::Linear,
+            };
+
+            let address_mode = match sampler.wrap_mode {
+                WrapMode::Clamp => wgpu::AddressMode::ClampToEdge,
+                WrapMode::Repeat => wgpu::AddressMode::Repeat,
+                WrapMode::Mirror => wgpu::AddressMode::MirrorRepeat,
+            };
+
+            let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: address_mode,
+                address_mode_v: address_mode,
+                address_mode_w: address_mode,
+                mag_filter: filter,
+                min_filter: filter,
+                mipmap_filter: wgpu::FilterMode::Nearest,
+                lod_min_clamp: -100.0,
+                lod_max_clamp: 100.0,
+                compare: wgpu::CompareFunction::Always,
+            });
+
+            device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("coffee::backend::quad constants"),
+                layout,
+                bindings: &[
+                    wgpu::Binding {
+                        binding: 0,
+                        resource: wgpu::BindingResource::Buffer {
+                            buffer: transform,
+                            range: 0..64,
+                        },
+                    },
+                    wgpu::Binding {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(&sampler),
+                    },
+                ],
+            })
+        };
+
+        Constants {
+            bind_groups: Sampler::all()
+                .map(|sampler| (sampler, create_bind_group(sampler)))
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, sampler: Sampler) -> &wgpu::BindGroup {
+        &self.bind_groups[&sampler]
+    }
+}
+
 #[derive(Clone, Copy, AsBytes)]
 #[repr(C)]
 pub struct Vertex {
@@ -314,6 +523,7 @@ pub struct Vertex {
 }
 
 const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
+const QUAD_LINE_INDICES: [u16; 12] = [0, 1, 1, 2, 2, 0, 0, 2, 2, 3, 3, 0];
 
 const QUAD_VERTS: [Vertex; 4] = [
     Vertex {
@@ -334,28 +544,80 @@ const QUAD_VERTS: [Vertex; 4] = [
 #[repr(C)]
 pub struct Quad {
     source: [f32; 4],
-    scale: [f32; 2],
+    transform: [f32; 4],
     translation: [f32; 2],
+    color: [f32; 4],
     pub layer: u32,
 }
 
 impl Quad {
-    const MAX: usize = 100_000;
+    pub const MAX: usize = 100_000;
+
+    /// Returns the corners of the quad in the coordinates of its target.
+    pub fn corners(&self) -> [[f32; 2]; 4] {
+        let [a, b, c, d] = self.transform;
+        let [x, y] = self.translation;
+
+        [
+            [x, y],
+            [x + a, y + b],
+            [x + a + c, y + b + d],
+            [x + c, y + d],
+        ]
+    }
+
+    /// Flips the quad vertically, keeping the area it covers.
+    pub fn flip_vertically(&mut self) {
+        let [a, b, c, d] = self.transform;
+        let [x, y] = self.translation;
+
+        self.transform = [a, b, -c, -d];
+        self.translation = [x + c, y + d];
+    }
+
+    /// Applies the given transformation on top of the transform of the quad.
+    pub fn apply(&mut self, transformation: &Transformation) {
+        let [a, b, c, d] = self.transform;
+        let [x, y] = self.translation;
+
+        let u = transformation.transform_vector(Vector::new(a, b));
+        let v = transformation.transform_vector(Vector::new(c, d));
+        let translation = transformation.transform_point(Point::new(x, y));
+
+        self.transform = [u.x, u.y, v.x, v.y];
+        self.translation = [translation.x, translation.y];
+    }
 }
 
 impl From<graphics::Quad> for Quad {
     fn from(quad: graphics::Quad) -> Quad {
         let source = quad.source;
-        let position = quad.position;
-        let (width, height) = quad.size;
+        let (transform, translation) = quad.instance_transform();
 
         Quad {
             source: [source.x, source.y, source.width, source.height],
-            translation: [position.x, position.y],
-            scale: [width, height],
+            transform,
+            translation,
+            color: quad.color.into_linear(),
             layer: 0,
         }
     }
 }
 
-pub struct TextureBinding(wgpu::BindGroup);
+pub struct TextureBinding(pub(super) wgpu::BindGroup);
+
+pub struct Shader {
+    fs_module: wgpu::ShaderModule,
+    pipelines: pipelines::Cache,
+    parameters: [f32; 4],
+}
+
+impl Shader {
+    pub fn parameters(&self) -> [f32; 4] {
+        self.parameters
+    }
+
+    pub fn set_parameters(&mut self, parameters: [f32; 4]) {
+        self.parameters = parameters;
+    }
+}
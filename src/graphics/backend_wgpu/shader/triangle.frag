@@ -1,9 +1,13 @@
 #version 450
 
-layout(location = 0) in vec4 v_Color;
+layout(location = 0) in vec2 v_Uv;
+layout(location = 1) in vec4 v_Color;
+
+layout(set = 0, binding = 1) uniform sampler u_Sampler;
+layout(set = 1, binding = 0) uniform texture2DArray u_Texture;
 
 layout(location = 0) out vec4 o_Target;
 
 void main() {
-    o_Target = v_Color;
+    o_Target = texture(sampler2DArray(u_Texture, u_Sampler), vec3(v_Uv, 0.0)) * v_Color;
 }
@@ -1,28 +1,39 @@
-use super::{Gpu, TargetView};
+use super::pipelines;
+use super::{Gpu, StencilView, TargetView};
 
 pub struct Surface {
     window: winit::window::Window,
     surface: wgpu::Surface,
     swap_chain: wgpu::SwapChain,
     extent: wgpu::Extent3d,
+    present_mode: wgpu::PresentMode,
+    stencil: StencilView,
     output: Option<wgpu::SwapChainOutput>,
 }
 
 impl Surface {
+    pub const FLIP_SCISSOR: bool = false;
+
     pub fn new(
         window: winit::window::Window,
         device: &wgpu::Device,
+        vsync: bool,
     ) -> Surface {
         let surface = wgpu::Surface::create(&window);
         let size = window.inner_size();
+        let present_mode = present_mode(vsync);
 
-        let (swap_chain, extent) = new_swap_chain(device, &surface, size);
+        let (swap_chain, extent) =
+            new_swap_chain(device, &surface, size, present_mode);
+        let stencil = new_stencil(device, extent);
 
         Surface {
             window,
             surface,
             swap_chain,
             extent,
+            present_mode,
+            stencil,
             output: None,
         }
     }
@@ -44,20 +55,47 @@ impl Surface {
         &self.output.as_ref().unwrap().view
     }
 
+    pub fn views(&mut self) -> (&TargetView, &StencilView) {
+        let _ = self.target();
+
+        (&self.output.as_ref().unwrap().view, &self.stencil)
+    }
+
     pub fn resize(
         &mut self,
         gpu: &mut Gpu,
         size: winit::dpi::PhysicalSize<u32>,
     ) {
         let (swap_chain, extent) =
-            new_swap_chain(&gpu.device, &self.surface, size);
+            new_swap_chain(&gpu.device, &self.surface, size, self.present_mode);
 
         self.swap_chain = swap_chain;
         self.extent = extent;
+        self.stencil = new_stencil(&gpu.device, extent);
+        self.output = None;
+    }
+
+    pub fn set_vsync(&mut self, gpu: &mut Gpu, vsync: bool) {
+        self.present_mode = present_mode(vsync);
+
+        let (swap_chain, _) = new_swap_chain(
+            &gpu.device,
+            &self.surface,
+            self.window.inner_size(),
+            self.present_mode,
+        );
+
+        self.swap_chain = swap_chain;
         self.output = None;
     }
 
     pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
+        gpu.stats.start_present();
+        self.present(gpu);
+        gpu.stats.finish_frame();
+    }
+
+    pub fn present(&mut self, gpu: &mut Gpu) {
         let new_encoder = gpu.device.create_command_encoder(
             &wgpu::CommandEncoderDescriptor {
                 label: Some("coffee::backend::surface blit"),
@@ -70,6 +108,7 @@ impl Surface {
 
         gpu.queue.submit(&[encoder.finish()]);
 
+        // Dropping the output presents it
         self.output = None;
     }
 
@@ -78,10 +117,19 @@ impl Surface {
     }
 }
 
+fn present_mode(vsync: bool) -> wgpu::PresentMode {
+    if vsync {
+        wgpu::PresentMode::Mailbox
+    } else {
+        wgpu::PresentMode::Immediate
+    }
+}
+
 fn new_swap_chain(
     device: &wgpu::Device,
     surface: &wgpu::Surface,
     size: winit::dpi::PhysicalSize<u32>,
+    present_mode: wgpu::PresentMode,
 ) -> (wgpu::SwapChain, wgpu::Extent3d) {
     let swap_chain = device.create_swap_chain(
         surface,
@@ -90,7 +138,7 @@ fn new_swap_chain(
             format: wgpu::TextureFormat::Bgra8UnormSrgb,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Mailbox,
+            present_mode,
         },
     );
 
@@ -102,3 +150,18 @@ fn new_swap_chain(
 
     (swap_chain, extent)
 }
+
+fn new_stencil(device: &wgpu::Device, extent: wgpu::Extent3d) -> StencilView {
+    let texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("coffee::backend::surface stencil"),
+        size: extent,
+        array_layer_count: 1,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: pipelines::STENCIL_FORMAT,
+        usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+    });
+
+    texture.create_default_view()
+}
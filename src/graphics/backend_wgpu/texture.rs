@@ -1,9 +1,10 @@
 use std::fmt;
 use std::rc::Rc;
 
-use super::types::TargetView;
+use super::pipelines;
+use super::types::{StencilView, TargetView};
 use crate::graphics::gpu::quad::{self, Pipeline};
-use crate::graphics::Transformation;
+use crate::graphics::{Format, Rectangle, Sampler, Transformation};
 
 #[derive(Clone)]
 pub struct Texture {
@@ -13,6 +14,7 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    sampler: Sampler,
 }
 
 impl fmt::Debug for Texture {
@@ -25,6 +27,34 @@ impl fmt::Debug for Texture {
     }
 }
 
+/// A texture created outside of Coffee.
+///
+/// Use it to draw the result of your own render or compute passes as an
+/// [`Image`] with [`Image::from_raw_texture`].
+///
+/// This type is only available with the `raw` feature.
+///
+/// [`Image`]: struct.Image.html
+/// [`Image::from_raw_texture`]: struct.Image.html#method.from_raw_texture
+#[cfg(feature = "raw")]
+#[allow(missing_debug_implementations)]
+pub struct External {
+    /// The texture. It must have the `SAMPLED` usage.
+    pub texture: wgpu::Texture,
+
+    /// The format of the texture.
+    pub format: wgpu::TextureFormat,
+
+    /// The width of the texture, in pixels.
+    pub width: u16,
+
+    /// The height of the texture, in pixels.
+    pub height: u16,
+
+    /// The number of array layers of the texture.
+    pub layers: u16,
+}
+
 impl Texture {
     pub(super) fn new(
         device: &mut wgpu::Device,
@@ -43,6 +73,7 @@ impl Texture {
             u32::from(width),
             u32::from(height),
             Some(&[&bgra.into_raw()[..]]),
+            wgpu::TextureFormat::Bgra8UnormSrgb,
             wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
         );
 
@@ -53,6 +84,7 @@ impl Texture {
             width,
             height,
             layers: 1,
+            sampler: Sampler::default(),
         }
     }
 
@@ -78,6 +110,7 @@ impl Texture {
             u32::from(width),
             u32::from(height),
             Some(&raw_layers[..]),
+            wgpu::TextureFormat::Bgra8UnormSrgb,
             wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
         );
 
@@ -88,9 +121,75 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            sampler: Sampler::default(),
         }
     }
 
+    #[cfg(feature = "raw")]
+    pub(super) fn from_external(
+        device: &mut wgpu::Device,
+        pipeline: &Pipeline,
+        external: External,
+    ) -> Texture {
+        let view = external.texture.create_view(&wgpu::TextureViewDescriptor {
+            format: external.format,
+            dimension: wgpu::TextureViewDimension::D2Array,
+            aspect: wgpu::TextureAspect::All,
+            base_mip_level: 0,
+            level_count: 1,
+            base_array_layer: 0,
+            array_layer_count: u32::from(external.layers),
+        });
+
+        let binding = pipeline.create_texture_binding(device, &view);
+
+        Texture {
+            raw: Rc::new(external.texture),
+            view: Rc::new(view),
+            binding: Rc::new(binding),
+            width: external.width,
+            height: external.height,
+            layers: external.layers,
+            sampler: Sampler::default(),
+        }
+    }
+
+    pub(super) fn update(
+        &self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        layer: u16,
+        region: Rectangle<u16>,
+        bgra: &[u8],
+    ) {
+        let buffer =
+            device.create_buffer_with_data(bgra, wgpu::BufferUsage::COPY_SRC);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &buffer,
+                offset: 0,
+                bytes_per_row: 4 * u32::from(region.width),
+                rows_per_image: u32::from(region.height),
+            },
+            wgpu::TextureCopyView {
+                texture: &self.raw,
+                array_layer: u32::from(layer),
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: u32::from(region.x),
+                    y: u32::from(region.y),
+                    z: 0,
+                },
+            },
+            wgpu::Extent3d {
+                width: u32::from(region.width),
+                height: u32::from(region.height),
+                depth: 1,
+            },
+        );
+    }
+
     pub(super) fn view(&self) -> &TargetView {
         &self.view
     }
@@ -106,11 +205,25 @@ impl Texture {
     pub fn height(&self) -> u16 {
         self.height
     }
+
+    pub fn id(&self) -> usize {
+        &*self.raw as *const wgpu::Texture as usize
+    }
+
+    pub fn sampler(&self) -> Sampler {
+        self.sampler
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
 }
 
 #[derive(Clone)]
 pub struct Drawable {
     texture: Texture,
+    stencil: Rc<StencilView>,
+    format: Format,
 }
 
 impl Drawable {
@@ -120,6 +233,7 @@ impl Drawable {
         pipeline: &Pipeline,
         width: u16,
         height: u16,
+        format: Format,
     ) -> Drawable {
         let (texture, view, binding) = create_texture_array(
             device,
@@ -128,6 +242,7 @@ impl Drawable {
             u32::from(width),
             u32::from(height),
             None,
+            pipelines::texture_format(format),
             wgpu::TextureUsage::OUTPUT_ATTACHMENT
                 | wgpu::TextureUsage::SAMPLED
                 | wgpu::TextureUsage::COPY_SRC,
@@ -140,9 +255,29 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            sampler: Sampler::default(),
         };
 
-        Drawable { texture }
+        let stencil = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("coffee::backend::drawable stencil"),
+            size: wgpu::Extent3d {
+                width: u32::from(width),
+                height: u32::from(height),
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: pipelines::STENCIL_FORMAT,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+        });
+
+        Drawable {
+            texture,
+            stencil: Rc::new(stencil.create_default_view()),
+            format,
+        }
     }
 
     pub fn texture(&self) -> &Texture {
@@ -153,6 +288,10 @@ impl Drawable {
         self.texture().view()
     }
 
+    pub fn stencil(&self) -> &StencilView {
+        &self.stencil
+    }
+
     pub fn read_pixels(
         &self,
         device: &mut wgpu::Device,
@@ -161,7 +300,14 @@ impl Drawable {
     ) -> image::DynamicImage {
         let texture = self.texture();
 
-        let buffer_size = 4 * texture.width() as u64 * texture.height() as u64;
+        let bytes_per_pixel = match self.format {
+            Format::Rgba8 => 4,
+            Format::Rgba16Float => 8,
+        };
+
+        let buffer_size = bytes_per_pixel as u64
+            * texture.width() as u64
+            * texture.height() as u64;
 
         let buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::texture pixels"),
@@ -181,7 +327,7 @@ impl Drawable {
             wgpu::BufferCopyView {
                 buffer: &buffer,
                 offset: 0,
-                bytes_per_row: 4 * u32::from(texture.width()),
+                bytes_per_row: bytes_per_pixel * u32::from(texture.width()),
                 rows_per_image: u32::from(texture.height()),
             },
             wgpu::Extent3d {
@@ -198,7 +344,12 @@ impl Drawable {
         let result = block_on(buffer.map_read(0, buffer_size));
 
         let bgra = match result {
-            Ok(mapping) => mapping.as_slice().to_vec(),
+            Ok(mapping) => match self.format {
+                Format::Rgba8 => mapping.as_slice().to_vec(),
+                Format::Rgba16Float => {
+                    rgba16_float_to_bgra8(mapping.as_slice())
+                }
+            },
             Err(_) => vec![],
         };
 
@@ -225,6 +376,7 @@ fn create_texture_array(
     width: u32,
     height: u32,
     layers: Option<&[&[u8]]>,
+    format: wgpu::TextureFormat,
     usage: wgpu::TextureUsage,
 ) -> (wgpu::Texture, wgpu::TextureView, quad::TextureBinding) {
     let extent = wgpu::Extent3d {
@@ -242,7 +394,7 @@ fn create_texture_array(
         mip_level_count: 1,
         sample_count: 1,
         dimension: wgpu::TextureDimension::D2,
-        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        format,
         usage,
     });
 
@@ -280,7 +432,7 @@ fn create_texture_array(
     }
 
     let view = texture.create_view(&wgpu::TextureViewDescriptor {
-        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        format,
         dimension: wgpu::TextureViewDimension::D2Array,
         aspect: wgpu::TextureAspect::All,
         base_mip_level: 0,
@@ -293,3 +445,56 @@ fn create_texture_array(
 
     (texture, view, binding)
 }
+
+/// Converts pixels with 4 half-precision floats each into BGRA bytes, clamping
+/// every channel to the `[0.0, 1.0]` range.
+///
+/// The float channels are linear, so they are encoded in sRGB to match the
+/// pixels of a `Format::Rgba8` target.
+fn rgba16_float_to_bgra8(pixels: &[u8]) -> Vec<u8> {
+    let channel = |bytes: &[u8]| {
+        let value = f16_to_f32(u16::from_le_bytes([bytes[0], bytes[1]]));
+
+        value.max(0.0).min(1.0)
+    };
+
+    let to_srgb = |linear: f32| {
+        let srgb = if linear <= 0.003_130_8 {
+            linear * 12.92
+        } else {
+            1.055 * linear.powf(1.0 / 2.4) - 0.055
+        };
+
+        (srgb * 255.0).round() as u8
+    };
+
+    pixels
+        .chunks(8)
+        .flat_map(|pixel| {
+            let r = channel(&pixel[0..2]);
+            let g = channel(&pixel[2..4]);
+            let b = channel(&pixel[4..6]);
+            let a = channel(&pixel[6..8]);
+
+            vec![
+                to_srgb(b),
+                to_srgb(g),
+                to_srgb(r),
+                (a * 255.0).round() as u8,
+            ]
+        })
+        .collect()
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((half >> 10) & 0x1f);
+    let mantissa = f32::from(half & 0x3ff);
+
+    match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => sign * std::f32::INFINITY,
+        0x1f => std::f32::NAN,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
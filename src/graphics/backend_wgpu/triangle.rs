@@ -1,12 +1,23 @@
 use std::mem;
 
-use crate::graphics::Transformation;
+use super::pipelines::{self, Pipelines};
+use super::quad::{self, Constants};
+use super::texture::Texture;
+use super::types::StencilView;
+use crate::graphics::target::Mask;
+use crate::graphics::{
+    BlendMode, DebugDraw, Format, Rectangle, Transformation,
+};
 use zerocopy::AsBytes;
 
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    pipelines: pipelines::Cache,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
     transform: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    constants: Constants,
+    white: Texture,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     buffer_size: u32,
@@ -15,15 +26,26 @@ pub struct Pipeline {
 impl Pipeline {
     const INITIAL_BUFFER_SIZE: u32 = 100_000;
 
-    pub fn new(device: &mut wgpu::Device) -> Pipeline {
-        let transform_layout =
+    pub fn new(
+        device: &mut wgpu::Device,
+        queue: &wgpu::Queue,
+        quad_pipeline: &quad::Pipeline,
+    ) -> Pipeline {
+        let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
-                label: Some("coffee::backend::triangle transform"),
-                bindings: &[wgpu::BindGroupLayoutEntry {
-                    binding: 0,
-                    visibility: wgpu::ShaderStage::VERTEX,
-                    ty: wgpu::BindingType::UniformBuffer { dynamic: false },
-                }],
+                label: Some("coffee::backend::triangle constants"),
+                bindings: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStage::VERTEX,
+                        ty: wgpu::BindingType::UniformBuffer { dynamic: false },
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler { comparison: false },
+                    },
+                ],
             });
 
         let matrix: [f32; 16] = Transformation::identity().into();
@@ -33,22 +55,29 @@ impl Pipeline {
             wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
         );
 
-        let constant_bind_group =
-            device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("coffee::backend::triangle constants"),
-                layout: &transform_layout,
-                bindings: &[wgpu::Binding {
-                    binding: 0,
-                    resource: wgpu::BindingResource::Buffer {
-                        buffer: &transform_buffer,
-                        range: 0..64,
-                    },
-                }],
-            });
+        let constants =
+            Constants::new(device, &constant_layout, &transform_buffer);
+
+        // Triangles without a texture sample a single white pixel. Textures
+        // are bound with the layout of the quad pipeline, so the bindings of
+        // every `Texture` can be reused.
+        let white = Texture::new(
+            device,
+            queue,
+            quad_pipeline,
+            &image::DynamicImage::ImageRgba8(image::ImageBuffer::from_pixel(
+                1,
+                1,
+                image::Rgba([255, 255, 255, 255]),
+            )),
+        );
 
         let layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-                bind_group_layouts: &[&transform_layout],
+                bind_group_layouts: &[
+                    &constant_layout,
+                    quad_pipeline.texture_layout(),
+                ],
             });
 
         let vs = include_bytes!("shader/triangle.vert.spv");
@@ -63,63 +92,18 @@ impl Pipeline {
                 .expect("Read triangle fragment shader as SPIR-V"),
         );
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Ccw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint32,
-                    vertex_buffers: &[wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Vertex>() as u64,
-                        step_mode: wgpu::InputStepMode::Vertex,
-                        attributes: &[
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 1,
-                                format: wgpu::VertexFormat::Float4,
-                                offset: 4 * 2,
-                            },
-                        ],
-                    }],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let pipelines = pipelines::Cache::new();
+
+        let _ = pipelines.get(1, Format::Rgba8, || {
+            create_pipelines(
+                device,
+                &layout,
+                &vs_module,
+                &fs_module,
+                1,
+                Format::Rgba8,
+            )
+        });
 
         let vertices = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::triangle vertices"),
@@ -136,9 +120,13 @@ impl Pipeline {
         });
 
         Pipeline {
-            pipeline,
+            pipelines,
+            layout,
+            vs_module,
+            fs_module,
             transform: transform_buffer,
-            constants: constant_bind_group,
+            constants,
+            white,
             vertices,
             indices,
             buffer_size: Self::INITIAL_BUFFER_SIZE,
@@ -149,15 +137,37 @@ impl Pipeline {
         &mut self,
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
+        texture: Option<&Texture>,
         vertices: &[Vertex],
         indices: &[u32],
         transformation: &Transformation,
+        debug_draw: DebugDraw,
+        blend_mode: BlendMode,
+        clip: Option<Rectangle<u32>>,
+        mask: Mask,
         target: &wgpu::TextureView,
+        stencil: &StencilView,
+        samples: u32,
+        format: Format,
     ) {
         if vertices.is_empty() || indices.is_empty() {
             return;
         }
 
+        let line_indices: Vec<u32>;
+
+        let indices = match debug_draw {
+            DebugDraw::Wireframe => {
+                line_indices = indices
+                    .chunks_exact(3)
+                    .flat_map(|t| vec![t[0], t[1], t[1], t[2], t[2], t[0]])
+                    .collect();
+
+                &line_indices[..]
+            }
+            _ => indices,
+        };
+
         let matrix: [f32; 16] = transformation.clone().into();
 
         let transform_buffer = device.create_buffer_with_data(
@@ -219,6 +229,19 @@ impl Pipeline {
             (mem::size_of::<u32>() * indices.len()) as u64,
         );
 
+        let texture = texture.unwrap_or(&self.white);
+
+        let pipelines = self.pipelines.get(samples, format, || {
+            create_pipelines(
+                device,
+                &self.layout,
+                &self.vs_module,
+                &self.fs_module,
+                samples,
+                format,
+            )
+        });
+
         {
             let mut render_pass =
                 encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
@@ -236,11 +259,30 @@ impl Pipeline {
                             },
                         },
                     ],
-                    depth_stencil_attachment: None,
+                    depth_stencil_attachment: Some(
+                        pipelines::stencil_attachment(stencil),
+                    ),
                 });
 
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass
+                .set_pipeline(pipelines.get(debug_draw, blend_mode, mask));
+            render_pass.set_stencil_reference(1);
+
+            if let Some(clip) = clip {
+                render_pass.set_scissor_rect(
+                    clip.x,
+                    clip.y,
+                    clip.width,
+                    clip.height,
+                );
+            }
+
+            render_pass.set_bind_group(
+                0,
+                self.constants.get(texture.sampler()),
+                &[],
+            );
+            render_pass.set_bind_group(1, &texture.binding().0, &[]);
             render_pass.set_index_buffer(&self.indices, 0, 0);
             render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
 
@@ -249,17 +291,89 @@ impl Pipeline {
     }
 }
 
+fn create_pipelines(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    samples: u32,
+    format: Format,
+) -> Pipelines {
+    let create_pipeline =
+        |primitive_topology, color_blend, alpha_blend, write_mask, stencil| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                layout,
+                vertex_stage: wgpu::ProgrammableStageDescriptor {
+                    module: vs_module,
+                    entry_point: "main",
+                },
+                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                    module: fs_module,
+                    entry_point: "main",
+                }),
+                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                    front_face: wgpu::FrontFace::Ccw,
+                    cull_mode: wgpu::CullMode::None,
+                    depth_bias: 0,
+                    depth_bias_slope_scale: 0.0,
+                    depth_bias_clamp: 0.0,
+                }),
+                primitive_topology,
+                color_states: &[wgpu::ColorStateDescriptor {
+                    format: pipelines::texture_format(format),
+                    color_blend,
+                    alpha_blend,
+                    write_mask,
+                }],
+                depth_stencil_state: Some(pipelines::depth_stencil_state(
+                    stencil,
+                )),
+                vertex_state: wgpu::VertexStateDescriptor {
+                    index_format: wgpu::IndexFormat::Uint32,
+                    vertex_buffers: &[wgpu::VertexBufferDescriptor {
+                        stride: mem::size_of::<Vertex>() as u64,
+                        step_mode: wgpu::InputStepMode::Vertex,
+                        attributes: &[
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 0,
+                                format: wgpu::VertexFormat::Float2,
+                                offset: 0,
+                            },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 1,
+                                format: wgpu::VertexFormat::Float2,
+                                offset: 4 * 2,
+                            },
+                            wgpu::VertexAttributeDescriptor {
+                                shader_location: 2,
+                                format: wgpu::VertexFormat::Float4,
+                                offset: 4 * (2 + 2),
+                            },
+                        ],
+                    }],
+                },
+                sample_count: samples,
+                sample_mask: !0,
+                alpha_to_coverage_enabled: false,
+            })
+        };
+
+    Pipelines::new(&create_pipeline)
+}
+
 #[derive(Debug, Clone, Copy, AsBytes)]
 #[repr(C)]
 pub struct Vertex {
     _position: [f32; 2],
+    _uv: [f32; 2],
     _color: [f32; 4],
 }
 
 impl Vertex {
-    pub fn new(position: [f32; 2], color: [f32; 4]) -> Vertex {
+    pub fn new(position: [f32; 2], uv: [f32; 2], color: [f32; 4]) -> Vertex {
         Vertex {
             _position: position,
+            _uv: uv,
             _color: color,
         }
     }
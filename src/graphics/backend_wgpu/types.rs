@@ -1 +1,3 @@
 pub type TargetView = wgpu::TextureView;
+
+pub type StencilView = wgpu::TextureView;
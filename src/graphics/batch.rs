@@ -1,7 +1,9 @@
+use std::cmp::Ordering;
+
 use rayon::prelude::*;
 
 use crate::graphics::gpu;
-use crate::graphics::{Image, IntoQuad, Target};
+use crate::graphics::{Image, IntoQuad, Target, Transformation};
 
 /// A collection of quads that will be drawn all at once using the same
 /// [`Image`].
@@ -10,6 +12,7 @@ use crate::graphics::{Image, IntoQuad, Target};
 pub struct Batch {
     image: Image,
     instances: Vec<gpu::Quad>,
+    depths: Vec<f32>,
     x_unit: f32,
     y_unit: f32,
 }
@@ -26,6 +29,7 @@ impl Batch {
         Self {
             image,
             instances: Vec::new(),
+            depths: Vec::new(),
             x_unit,
             y_unit,
         }
@@ -36,8 +40,34 @@ impl Batch {
     /// [`Batch`]: struct.Batch.html
     #[inline]
     pub fn add<Q: IntoQuad>(&mut self, quad: Q) {
-        let instance =
-            gpu::Quad::from(quad.into_quad(self.x_unit, self.y_unit));
+        let quad = quad.into_quad(self.x_unit, self.y_unit);
+
+        self.depths.push(quad.depth);
+        self.instances.push(gpu::Quad::from(quad));
+    }
+
+    /// Adds a quad to the [`Batch`] with the given [`Transformation`] applied
+    /// to it.
+    ///
+    /// The [`Transformation`] is combined with the position, size, and
+    /// rotation of the quad into a single instance transform, so it can
+    /// scale, rotate, or skew every quad differently while still drawing the
+    /// whole [`Batch`] at once.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Transformation`]: struct.Transformation.html
+    #[inline]
+    pub fn add_transformed<Q: IntoQuad>(
+        &mut self,
+        quad: Q,
+        transformation: Transformation,
+    ) {
+        let quad = quad.into_quad(self.x_unit, self.y_unit);
+
+        self.depths.push(quad.depth);
+
+        let mut instance = gpu::Quad::from(quad);
+        instance.apply(&transformation);
 
         self.instances.push(instance);
     }
@@ -50,6 +80,21 @@ impl Batch {
         target.draw_texture_quads(&self.image.texture, &self.instances[..]);
     }
 
+    /// Sorts the quads of the [`Batch`] by their `depth` and draws them on the
+    /// given [`Target`].
+    ///
+    /// Quads with a lower depth are drawn first. Quads with the same depth keep
+    /// the order in which they were added. The [`Batch`] stays sorted, so
+    /// drawing it again is cheap.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: struct.Target.html
+    pub fn draw_sorted(&mut self, target: &mut Target<'_>) {
+        sort_by_depth(&mut self.depths, &mut self.instances);
+
+        self.draw(target);
+    }
+
     /// Clears the [`Batch`] contents.
     ///
     /// This is useful to avoid creating a new batch every frame and
@@ -58,6 +103,7 @@ impl Batch {
     /// [`Batch`]: struct.Batch.html
     pub fn clear(&mut self) {
         self.instances.clear();
+        self.depths.clear();
     }
 }
 
@@ -76,9 +122,12 @@ impl<Q: IntoQuad> Extend<Q> for Batch {
         let x_unit = self.x_unit;
         let y_unit = self.y_unit;
 
-        self.instances.extend(
-            iter.map(|quad| gpu::Quad::from(quad.into_quad(x_unit, y_unit))),
-        );
+        for quad in iter {
+            let quad = quad.into_quad(x_unit, y_unit);
+
+            self.depths.push(quad.depth);
+            self.instances.push(gpu::Quad::from(quad));
+        }
     }
 }
 
@@ -98,9 +147,35 @@ impl<Q: IntoQuad + Send> ParallelExtend<Q> for Batch {
         let x_unit = self.x_unit;
         let y_unit = self.y_unit;
 
-        self.instances.par_extend(
-            par_iter
-                .map(|quad| gpu::Quad::from(quad.into_quad(x_unit, y_unit))),
-        );
+        let quads: Vec<(f32, gpu::Quad)> = par_iter
+            .map(|quad| {
+                let quad = quad.into_quad(x_unit, y_unit);
+
+                (quad.depth, gpu::Quad::from(quad))
+            })
+            .collect();
+
+        self.depths.extend(quads.iter().map(|(depth, _)| depth));
+        self.instances
+            .extend(quads.into_iter().map(|(_, instance)| instance));
+    }
+}
+
+pub(super) fn sort_by_depth(
+    depths: &mut Vec<f32>,
+    instances: &mut Vec<gpu::Quad>,
+) {
+    if depths.windows(2).all(|pair| pair[0] <= pair[1]) {
+        return;
+    }
+
+    let mut quads: Vec<(f32, gpu::Quad)> =
+        depths.drain(..).zip(instances.drain(..)).collect();
+
+    quads.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    for (depth, instance) in quads {
+        depths.push(depth);
+        instances.push(instance);
     }
 }
@@ -0,0 +1,37 @@
+/// The way new pixels are combined with the pixels of a [`Target`].
+///
+/// Use [`Target::with_blend_mode`] to change it.
+///
+/// [`Target`]: struct.Target.html
+/// [`Target::with_blend_mode`]: struct.Target.html#method.with_blend_mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Pixels are blended using their alpha channel.
+    ///
+    /// This is the default mode.
+    Alpha,
+
+    /// The colors of the pixels are added to the target, weighted by their
+    /// alpha channel.
+    ///
+    /// It is useful to draw glows, lights, and particle effects.
+    Additive,
+
+    /// The colors of the pixels are multiplied with the target.
+    ///
+    /// It is useful to draw shadows and to darken parts of the target.
+    Multiply,
+
+    /// Pixels are blended assuming their color is already multiplied by their
+    /// alpha channel.
+    Premultiplied,
+
+    /// Pixels replace the contents of the target, including its alpha channel.
+    Replace,
+}
+
+impl Default for BlendMode {
+    fn default() -> BlendMode {
+        BlendMode::Alpha
+    }
+}
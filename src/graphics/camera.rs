@@ -0,0 +1,150 @@
+use crate::graphics::{Point, Transformation, Vector};
+
+/// A 2D camera that looks at a point of your world.
+///
+/// A [`Camera`] produces a [`Transformation`] that you can use with
+/// [`Target::transform`] to draw your world as seen through it. It can also
+/// convert positions between screen space and world space, which is useful to
+/// pick objects with the mouse.
+///
+/// Keep the `viewport` updated with the size of the [`Target`] you are drawing
+/// on, so the [`Camera`] stays centered when the window is resized.
+///
+/// [`Camera`]: struct.Camera.html
+/// [`Transformation`]: struct.Transformation.html
+/// [`Target::transform`]: struct.Target.html#method.transform
+/// [`Target`]: struct.Target.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Camera {
+    /// The point of the world shown at the center of the viewport.
+    pub position: Point,
+
+    /// The zoom of the camera.
+    ///
+    /// A zoom of `2.0` makes everything look twice as big.
+    pub zoom: f32,
+
+    /// The rotation of the camera, in radians.
+    ///
+    /// Positive angles rotate the world counterclockwise on the screen.
+    pub rotation: f32,
+
+    /// The size of the viewport of the camera, in screen coordinates.
+    pub viewport: (f32, f32),
+}
+
+impl Camera {
+    /// Creates a new [`Camera`] with the given viewport size.
+    ///
+    /// It looks at the origin, without zoom or rotation.
+    ///
+    /// [`Camera`]: struct.Camera.html
+    pub fn new(width: f32, height: f32) -> Camera {
+        Camera {
+            position: Point::new(0.0, 0.0),
+            zoom: 1.0,
+            rotation: 0.0,
+            viewport: (width, height),
+        }
+    }
+
+    /// Returns the [`Transformation`] that turns world coordinates into screen
+    /// coordinates.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    pub fn transformation(&self) -> Transformation {
+        Transformation::translate(self.center())
+            * Transformation::scale(self.zoom)
+            * Transformation::rotate(-self.rotation)
+            * Transformation::translate(-self.position.coords)
+    }
+
+    /// Converts a [`Point`] in screen coordinates into world coordinates.
+    ///
+    /// [`Point`]: type.Point.html
+    pub fn screen_to_world(&self, point: Point) -> Point {
+        let transformation = Transformation::translate(self.position.coords)
+            * Transformation::rotate(self.rotation)
+            * Transformation::scale(1.0 / self.zoom)
+            * Transformation::translate(-self.center());
+
+        transformation.transform_point(point)
+    }
+
+    /// Converts a [`Point`] in world coordinates into screen coordinates.
+    ///
+    /// [`Point`]: type.Point.html
+    pub fn world_to_screen(&self, point: Point) -> Point {
+        self.transformation().transform_point(point)
+    }
+
+    fn center(&self) -> Vector {
+        Vector::new(self.viewport.0 / 2.0, self.viewport.1 / 2.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Point, b: Point) {
+        assert!((a - b).norm() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn position_is_at_the_center_of_the_viewport() {
+        let camera = Camera {
+            position: Point::new(100.0, 50.0),
+            ..Camera::new(800.0, 600.0)
+        };
+
+        assert_close(
+            camera.world_to_screen(Point::new(100.0, 50.0)),
+            Point::new(400.0, 300.0),
+        );
+    }
+
+    #[test]
+    fn zoom_scales_around_the_position() {
+        let camera = Camera {
+            position: Point::new(10.0, 10.0),
+            zoom: 2.0,
+            ..Camera::new(800.0, 600.0)
+        };
+
+        assert_close(
+            camera.world_to_screen(Point::new(11.0, 9.0)),
+            Point::new(402.0, 298.0),
+        );
+    }
+
+    #[test]
+    fn rotation_is_counterclockwise() {
+        let camera = Camera {
+            rotation: std::f32::consts::FRAC_PI_2,
+            ..Camera::new(800.0, 600.0)
+        };
+
+        assert_close(
+            camera.world_to_screen(Point::new(10.0, 0.0)),
+            Point::new(400.0, 290.0),
+        );
+    }
+
+    #[test]
+    fn screen_to_world_undoes_world_to_screen() {
+        let camera = Camera {
+            position: Point::new(-30.0, 12.0),
+            zoom: 1.5,
+            rotation: 0.4,
+            viewport: (640.0, 480.0),
+        };
+
+        let point = Point::new(5.0, -7.0);
+
+        assert_close(
+            camera.screen_to_world(camera.world_to_screen(point)),
+            point,
+        );
+    }
+}
@@ -1,5 +1,7 @@
+use std::path::Path;
+
 use crate::graphics::gpu::{self, texture, Gpu};
-use crate::graphics::{IntoQuad, Target};
+use crate::graphics::{Format, IntoQuad, Rectangle, Target};
 use crate::load::Task;
 use crate::Result;
 
@@ -11,6 +13,10 @@ use crate::Result;
 #[derive(Clone)]
 pub struct Canvas {
     drawable: texture::Drawable,
+    multisample: Option<gpu::Multisample>,
+    format: Format,
+    width: u16,
+    height: u16,
 }
 
 impl Canvas {
@@ -18,8 +24,60 @@ impl Canvas {
     ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn new(gpu: &mut Gpu, width: u16, height: u16) -> Result<Canvas> {
+        Self::new_with_format(gpu, width, height, Format::Rgba8)
+    }
+
+    /// Creates a new [`Canvas`] with the given size and pixel [`Format`].
+    ///
+    /// Use [`Format::Rgba16Float`] to draw colors exceeding `1.0`, like
+    /// accumulated lights. Then, draw the [`Canvas`] with a tonemapping
+    /// [`Shader`] to bring them back to a displayable range.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Format`]: enum.Format.html
+    /// [`Format::Rgba16Float`]: enum.Format.html#variant.Rgba16Float
+    /// [`Shader`]: struct.Shader.html
+    pub fn new_with_format(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+        format: Format,
+    ) -> Result<Canvas> {
+        Ok(Canvas {
+            drawable: gpu.create_drawable_texture(width, height, format),
+            multisample: None,
+            format,
+            width,
+            height,
+        })
+    }
+
+    /// Creates a new multisampled [`Canvas`] with the given size and number
+    /// of samples per pixel.
+    ///
+    /// Multisampling smooths the edges of quads and meshes drawn on the
+    /// [`Canvas`]. Most GPUs support 2, 4, and 8 samples. A [`Canvas`] with a
+    /// single sample is not multisampled.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn with_samples(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+        samples: u8,
+    ) -> Result<Canvas> {
+        let multisample = if samples > 1 {
+            Some(gpu.create_multisample(width, height, samples))
+        } else {
+            None
+        };
+
         Ok(Canvas {
-            drawable: gpu.create_drawable_texture(width, height),
+            drawable: gpu.create_drawable_texture(width, height, Format::Rgba8),
+            multisample,
+            format: Format::Rgba8,
+            width,
+            height,
         })
     }
 
@@ -35,14 +93,74 @@ impl Canvas {
     ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn width(&self) -> u16 {
-        self.drawable.texture().width()
+        self.width
     }
 
     /// Returns the height of the [`Canvas`].
     ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn height(&self) -> u16 {
-        self.drawable.texture().height()
+        self.height
+    }
+
+    /// Returns the pixel [`Format`] of the [`Canvas`].
+    ///
+    /// [`Format`]: enum.Format.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Resizes the [`Canvas`], keeping its number of samples per pixel and
+    /// its [`Format`].
+    ///
+    /// The GPU resources of the [`Canvas`] keep the biggest size it has had.
+    /// They are only recreated when the [`Canvas`] grows beyond it, so you can
+    /// call this every frame with the size of the window. Only the area of
+    /// the new size is drawn and sampled afterwards. The previous contents of
+    /// a resized [`Canvas`] are lost.
+    ///
+    /// Clones of the [`Canvas`] keep using the previous resources when it
+    /// grows.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Format`]: enum.Format.html
+    pub fn resize(&mut self, gpu: &mut Gpu, width: u16, height: u16) {
+        let (capacity_width, capacity_height) = self.capacity();
+
+        if width > capacity_width || height > capacity_height {
+            let capacity_width = width.max(capacity_width);
+            let capacity_height = height.max(capacity_height);
+            let samples = self.samples();
+
+            self.drawable = gpu.create_drawable_texture(
+                capacity_width,
+                capacity_height,
+                self.format,
+            );
+
+            self.multisample = if samples > 1 {
+                Some(gpu.create_multisample(
+                    capacity_width,
+                    capacity_height,
+                    samples,
+                ))
+            } else {
+                None
+            };
+        }
+
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Returns the number of samples per pixel of the [`Canvas`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn samples(&self) -> u8 {
+        self.multisample
+            .as_ref()
+            .map_or(1, |multisample| multisample.samples() as u8)
     }
 
     /// Views the [`Canvas`] as a [`Target`].
@@ -52,13 +170,22 @@ impl Canvas {
     pub fn as_target<'a>(&'a mut self, gpu: &'a mut Gpu) -> Target<'a> {
         let texture = self.drawable.texture();
 
+        let (view, stencil) = match &self.multisample {
+            Some(multisample) => (multisample.target(), multisample.stencil()),
+            None => (self.drawable.target(), self.drawable.stencil()),
+        };
+
         Target::with_transformation(
             gpu,
-            self.drawable.target(),
+            view,
+            stencil,
+            self.multisample.as_ref(),
+            self.format,
             f32::from(texture.width()),
             f32::from(texture.height()),
             texture::Drawable::render_transformation(),
         )
+        .with_visible_size(u32::from(self.width), u32::from(self.height))
     }
 
     /// Renders the [`Canvas`] on the given [`Target`].
@@ -66,22 +193,77 @@ impl Canvas {
     /// [`Canvas`]: struct.Canvas.html
     /// [`Target`]: struct.Target.html
     pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        if let Some(multisample) = &self.multisample {
+            target.resolve(multisample, &self.drawable);
+        }
+
+        let mut quad =
+            quad.into_quad(1.0 / self.width as f32, 1.0 / self.height as f32);
+
+        // Only the area in use of the texture is sampled
+        let (capacity_width, capacity_height) = self.capacity();
+        let x_ratio = f32::from(self.width) / f32::from(capacity_width);
+        let y_ratio = f32::from(self.height) / f32::from(capacity_height);
+
+        quad.source = Rectangle {
+            x: quad.source.x * x_ratio,
+            y: quad.source.y * y_ratio,
+            width: quad.source.width * x_ratio,
+            height: quad.source.height * y_ratio,
+        };
+
+        let instance = gpu::Quad::from(quad);
+
         target.draw_texture_quads(
             &self.drawable.texture(),
-            &[gpu::Quad::from(quad.into_quad(
-                1.0 / self.width() as f32,
-                1.0 / self.height() as f32,
-            ))],
+            std::slice::from_ref(&instance),
         );
+        target.draw_canvas_bounds(&instance);
     }
 
     /// Reads the pixels of the [`Canvas`].
     ///
+    /// The colors of a [`Canvas`] with a [`Format::Rgba16Float`] are clamped
+    /// to the `[0.0, 1.0]` range.
+    ///
     /// _Note:_ This is a very slow operation.
     ///
     /// [`Canvas`]: struct.Canvas.html
+    /// [`Format::Rgba16Float`]: enum.Format.html#variant.Rgba16Float
     pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
-        gpu.read_drawable_texture_pixels(&self.drawable)
+        if let Some(multisample) = &self.multisample {
+            gpu.resolve(multisample, &self.drawable);
+        }
+
+        let mut pixels = gpu.read_drawable_texture_pixels(&self.drawable);
+        let (capacity_width, capacity_height) = self.capacity();
+
+        if (self.width, self.height) == (capacity_width, capacity_height) {
+            pixels
+        } else {
+            pixels.crop(0, 0, u32::from(self.width), u32::from(self.height))
+        }
+    }
+
+    fn capacity(&self) -> (u16, u16) {
+        let texture = self.drawable.texture();
+
+        (texture.width(), texture.height())
+    }
+
+    /// Saves the pixels of the [`Canvas`] to an image file at the given path.
+    ///
+    /// The format of the file is deduced from the extension of the path.
+    ///
+    /// _Note:_ This reads the pixels of the [`Canvas`], which is a very slow
+    /// operation. See [`read_pixels`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`read_pixels`]: #method.read_pixels
+    pub fn save<P: AsRef<Path>>(&self, gpu: &mut Gpu, path: P) -> Result<()> {
+        self.read_pixels(gpu).save(path)?;
+
+        Ok(())
     }
 }
 
@@ -89,9 +271,11 @@ impl std::fmt::Debug for Canvas {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Canvas {{ width: {}, height: {} }}",
+            "Canvas {{ width: {}, height: {}, samples: {}, format: {:?} }}",
             self.width(),
-            self.height()
+            self.height(),
+            self.samples(),
+            self.format
         )
     }
 }
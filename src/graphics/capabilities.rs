@@ -0,0 +1,71 @@
+/// The capabilities of a [`Gpu`].
+///
+/// You can use them to adapt your resources to the limits of the hardware.
+/// For instance, you may want to split an atlas into multiple images when
+/// it exceeds [`max_texture_size`].
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`max_texture_size`]: #structfield.max_texture_size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The maximum width and height of a texture, in pixels.
+    ///
+    /// It applies to an [`Image`], a [`Canvas`], and every layer of a
+    /// [`TextureArray`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`TextureArray`]: texture_array/struct.TextureArray.html
+    pub max_texture_size: u32,
+
+    /// The maximum amount of layers of a [`TextureArray`].
+    ///
+    /// [`TextureArray`]: texture_array/struct.TextureArray.html
+    pub max_texture_array_layers: u32,
+
+    /// The maximum amount of samples per pixel of a multisampled [`Canvas`]
+    /// or window.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub max_samples: u8,
+
+    /// Whether BC-compressed textures can be uploaded to the GPU as they are.
+    ///
+    /// When `false`, compressed textures loaded by an [`Image`] are decoded
+    /// to RGBA first, which uses 4 to 8 times more memory.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub compressed_textures: bool,
+
+    /// Whether secondary windows can be opened with
+    /// [`Window::spawn_secondary`].
+    ///
+    /// [`Window::spawn_secondary`]: struct.Window.html#method.spawn_secondary
+    pub secondary_windows: bool,
+
+    /// The present modes supported when presenting frames to a window.
+    pub present_modes: PresentModes,
+}
+
+/// The present modes supported by a [`Gpu`].
+///
+/// They define how frames wait for the vertical blank of the display. When
+/// vsync is enabled, Coffee prefers `mailbox` and falls back to `fifo`.
+///
+/// Some platforms cannot be queried before a window exists. In that case,
+/// only the modes the backend guarantees are reported as supported.
+///
+/// [`Gpu`]: struct.Gpu.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentModes {
+    /// Frames are queued and presented on every vertical blank. It is always
+    /// supported.
+    pub fifo: bool,
+
+    /// Frames are presented on the vertical blank, but only the latest one
+    /// is kept. It avoids tearing with lower latency than `fifo`.
+    pub mailbox: bool,
+
+    /// Frames are presented right away, which may cause tearing.
+    pub immediate: bool,
+}
@@ -55,6 +55,46 @@ impl Color {
         a: 1.0,
     };
 
+    /// Yellow color.
+    pub const YELLOW: Self = Self {
+        r: 1.0,
+        g: 1.0,
+        b: 0.0,
+        a: 1.0,
+    };
+
+    /// Cyan color.
+    pub const CYAN: Self = Self {
+        r: 0.0,
+        g: 1.0,
+        b: 1.0,
+        a: 1.0,
+    };
+
+    /// Magenta color.
+    pub const MAGENTA: Self = Self {
+        r: 1.0,
+        g: 0.0,
+        b: 1.0,
+        a: 1.0,
+    };
+
+    /// Gray color.
+    pub const GRAY: Self = Self {
+        r: 0.5,
+        g: 0.5,
+        b: 0.5,
+        a: 1.0,
+    };
+
+    /// Fully transparent color.
+    pub const TRANSPARENT: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+
     /// Creates a new [`Color`] from components in the [0, 1.0] range.
     ///
     /// [`Color`]: struct.Color.html
@@ -96,6 +136,120 @@ impl Color {
         Color::from_rgb(r, g, b)
     }
 
+    /// Creates a new [`Color`] from its HSL components.
+    ///
+    /// The hue is given in degrees, and it wraps around `360.0`. Saturation
+    /// and lightness are in the [0, 1.0] range. The resulting [`Color`] is
+    /// opaque.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        debug_assert!(saturation >= 0.0, "Saturation is < 0.0");
+        debug_assert!(saturation <= 1.0, "Saturation is > 1.0");
+        debug_assert!(lightness >= 0.0, "Lightness is < 0.0");
+        debug_assert!(lightness <= 1.0, "Lightness is > 1.0");
+
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+        Color::from_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Creates a new [`Color`] from its HSV components.
+    ///
+    /// The hue is given in degrees, and it wraps around `360.0`. Saturation
+    /// and value are in the [0, 1.0] range. The resulting [`Color`] is opaque.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        debug_assert!(saturation >= 0.0, "Saturation is < 0.0");
+        debug_assert!(saturation <= 1.0, "Saturation is > 1.0");
+        debug_assert!(value >= 0.0, "Value is < 0.0");
+        debug_assert!(value <= 1.0, "Value is > 1.0");
+
+        let chroma = value * saturation;
+
+        Color::from_chroma(hue, chroma, value - chroma)
+    }
+
+    /// Parses a [`Color`] from its hexadecimal representation.
+    ///
+    /// Both `#rrggbb` and `#rrggbbaa` are supported, and the leading `#` is
+    /// optional. Returns `None` if the string is not a valid color.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn from_hex(hex: &str) -> Option<Color> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        let is_valid = (hex.len() == 6 || hex.len() == 8)
+            && hex.chars().all(|c| c.is_ascii_hexdigit());
+
+        if !is_valid {
+            return None;
+        }
+
+        let component = |i: usize| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .ok()
+                .map(|c| c as f32 / 255.0)
+        };
+
+        Some(Color {
+            r: component(0)?,
+            g: component(2)?,
+            b: component(4)?,
+            a: if hex.len() == 8 { component(6)? } else { 1.0 },
+        })
+    }
+
+    /// Linearly interpolates between this [`Color`] and another one.
+    ///
+    /// Every component is interpolated, including alpha. A `t` of `0.0`
+    /// returns this [`Color`] and a `t` of `1.0` returns `other`.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let t = t.max(0.0).min(1.0);
+
+        Color {
+            r: self.r + (other.r - self.r) * t,
+            g: self.g + (other.g - self.g) * t,
+            b: self.b + (other.b - self.b) * t,
+            a: self.a + (other.a - self.a) * t,
+        }
+    }
+
+    /// Returns a lighter version of this [`Color`], mixing it with white by
+    /// the given amount in the [0, 1.0] range.
+    ///
+    /// The alpha component is left untouched.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn lighten(self, amount: f32) -> Color {
+        self.lerp(Color::WHITE.with_alpha(self.a), amount)
+    }
+
+    /// Returns a darker version of this [`Color`], mixing it with black by
+    /// the given amount in the [0, 1.0] range.
+    ///
+    /// The alpha component is left untouched.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn darken(self, amount: f32) -> Color {
+        self.lerp(Color::BLACK.with_alpha(self.a), amount)
+    }
+
+    /// Returns the same [`Color`] with the given alpha component in the
+    /// [0, 1.0] range.
+    ///
+    /// The RGB components are left untouched.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn with_alpha(self, a: f32) -> Color {
+        debug_assert!(a >= 0.0, "Alpha component is < 0.0");
+        debug_assert!(a <= 1.0, "Alpha component is > 1.0");
+        Color { a, ..self }
+    }
+
     /// Returns the [`Color`] components in the [0, 255] range.
     ///
     /// [`Color`]: struct.Color.html
@@ -108,6 +262,29 @@ impl Color {
         ]
     }
 
+    // As described in:
+    // https://en.wikipedia.org/wiki/HSL_and_HSV#Color_conversion_formulae
+    fn from_chroma(hue: f32, chroma: f32, offset: f32) -> Color {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+
+        Color {
+            r: r + offset,
+            g: g + offset,
+            b: b + offset,
+            a: 1.0,
+        }
+    }
+
     pub(crate) fn into_linear(self) -> [f32; 4] {
         // As described in:
         // https://en.wikipedia.org/wiki/SRGB#The_reverse_transformation
@@ -147,3 +324,106 @@ impl From<Color> for [u8; 4] {
         color.to_rgba()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_hex_round_trips_through_rgba() {
+        assert_eq!(
+            Color::from_hex("#1a2b3c").map(|color| color.to_rgba()),
+            Some([0x1a, 0x2b, 0x3c, 0xff])
+        );
+        assert_eq!(
+            Color::from_hex("1A2B3C4D").map(|color| color.to_rgba()),
+            Some([0x1a, 0x2b, 0x3c, 0x4d])
+        );
+        assert_eq!(
+            Color::from_hex("#1a2b3c").map(|color| color.to_rgba()),
+            Some(Color::from_rgb_u32(0x1a2b3c).to_rgba())
+        );
+    }
+
+    #[test]
+    fn from_hex_rejects_invalid_colors() {
+        assert_eq!(Color::from_hex(""), None);
+        assert_eq!(Color::from_hex("#fff"), None);
+        assert_eq!(Color::from_hex("#1a2b3"), None);
+        assert_eq!(Color::from_hex("#1a2b3g"), None);
+        assert_eq!(Color::from_hex("#1a2b3c4d5e"), None);
+        assert_eq!(Color::from_hex("+1a2b3c"), None);
+    }
+
+    #[test]
+    fn from_hsl_matches_rgb() {
+        let cases = [
+            ((0.0, 1.0, 0.5), [255, 0, 0]),
+            ((120.0, 1.0, 0.5), [0, 255, 0]),
+            ((240.0, 1.0, 0.5), [0, 0, 255]),
+            ((60.0, 1.0, 0.25), [128, 128, 0]),
+            ((210.0, 0.5, 0.6), [102, 153, 204]),
+            ((0.0, 0.0, 1.0), [255, 255, 255]),
+            ((0.0, 0.0, 0.0), [0, 0, 0]),
+        ];
+
+        for &((hue, saturation, lightness), [r, g, b]) in cases.iter() {
+            assert_eq!(
+                Color::from_hsl(hue, saturation, lightness).to_rgba(),
+                [r, g, b, 255],
+                "hsl({}, {}, {})",
+                hue,
+                saturation,
+                lightness
+            );
+        }
+    }
+
+    #[test]
+    fn from_hsv_matches_rgb() {
+        let cases = [
+            ((0.0, 1.0, 1.0), [255, 0, 0]),
+            ((180.0, 1.0, 1.0), [0, 255, 255]),
+            ((300.0, 1.0, 1.0), [255, 0, 255]),
+            ((30.0, 1.0, 0.5), [128, 64, 0]),
+            ((210.0, 0.5, 0.8), [102, 153, 204]),
+            ((0.0, 0.0, 1.0), [255, 255, 255]),
+        ];
+
+        for &((hue, saturation, value), [r, g, b]) in cases.iter() {
+            assert_eq!(
+                Color::from_hsv(hue, saturation, value).to_rgba(),
+                [r, g, b, 255],
+                "hsv({}, {}, {})",
+                hue,
+                saturation,
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn hue_wraps_around() {
+        assert_eq!(
+            Color::from_hsl(480.0, 1.0, 0.5),
+            Color::from_hsl(120.0, 1.0, 0.5)
+        );
+        assert_eq!(
+            Color::from_hsv(-60.0, 1.0, 1.0),
+            Color::from_hsv(300.0, 1.0, 1.0)
+        );
+        assert_eq!(
+            Color::from_hsv(360.0, 1.0, 1.0).to_rgba(),
+            [255, 0, 0, 255]
+        );
+    }
+
+    #[test]
+    fn lighten_and_darken_keep_alpha() {
+        let color = Color::from_rgb(100, 100, 100).with_alpha(0.5);
+
+        assert_eq!(color.lighten(1.0), Color::WHITE.with_alpha(0.5));
+        assert_eq!(color.darken(1.0), Color::BLACK.with_alpha(0.5));
+        assert_eq!(color.lighten(0.0), color);
+    }
+}
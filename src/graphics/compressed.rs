@@ -0,0 +1,430 @@
+use image::{ImageError, ImageResult, RgbaImage};
+
+const KTX2_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+const DDS_MAGIC: &[u8; 4] = b"DDS ";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Bc1,
+    Bc2,
+    Bc3,
+}
+
+impl Format {
+    fn block_size(self) -> usize {
+        match self {
+            Format::Bc1 => 8,
+            Format::Bc2 | Format::Bc3 => 16,
+        }
+    }
+}
+
+/// Decodes the given bytes into RGBA pixels, if they are a KTX2 or DDS file.
+///
+/// Returns `None` when the bytes are in any other format.
+pub(super) fn decode(bytes: &[u8]) -> Option<ImageResult<RgbaImage>> {
+    if bytes.starts_with(&KTX2_IDENTIFIER) {
+        Some(decode_ktx2(bytes))
+    } else if bytes.starts_with(DDS_MAGIC) {
+        Some(decode_dds(bytes))
+    } else {
+        None
+    }
+}
+
+fn decode_ktx2(bytes: &[u8]) -> ImageResult<RgbaImage> {
+    let vk_format = read_u32(bytes, 12)?;
+    let width = read_u32(bytes, 20)?;
+    let height = read_u32(bytes, 24)?.max(1);
+    let supercompression = read_u32(bytes, 44)?;
+
+    if supercompression != 0 {
+        return Err(ImageError::UnsupportedError(String::from(
+            "Supercompressed KTX2 textures are not supported",
+        )));
+    }
+
+    // The level index follows the 48 byte header and the 32 byte index of
+    // the data format descriptor, the key/value data, and the supercompression
+    // global data. The first level is the base one.
+    let offset = read_u64(bytes, 80)? as usize;
+    let length = read_u64(bytes, 88)? as usize;
+
+    let data = bytes
+        .get(offset..offset.saturating_add(length))
+        .ok_or(ImageError::NotEnoughData)?;
+
+    let format = match vk_format {
+        // VK_FORMAT_R8G8B8A8_UNORM and VK_FORMAT_R8G8B8A8_SRGB
+        37 | 43 => {
+            let size = (width as usize)
+                .checked_mul(height as usize)
+                .and_then(|pixels| pixels.checked_mul(4))
+                .ok_or(ImageError::DimensionError)?;
+
+            return RgbaImage::from_raw(
+                width,
+                height,
+                data.get(..size).ok_or(ImageError::NotEnoughData)?.to_vec(),
+            )
+            .ok_or(ImageError::NotEnoughData);
+        }
+        131..=134 => Format::Bc1,
+        135 | 136 => Format::Bc2,
+        137 | 138 => Format::Bc3,
+        _ => {
+            return Err(ImageError::UnsupportedError(format!(
+                "Unsupported KTX2 format: {}",
+                vk_format
+            )))
+        }
+    };
+
+    decode_blocks(format, width, height, data)
+}
+
+fn decode_dds(bytes: &[u8]) -> ImageResult<RgbaImage> {
+    let height = read_u32(bytes, 12)?;
+    let width = read_u32(bytes, 16)?;
+    let four_cc = bytes.get(84..88).ok_or(ImageError::NotEnoughData)?;
+
+    let (format, offset) = match four_cc {
+        b"DXT1" => (Format::Bc1, 128),
+        b"DXT2" | b"DXT3" => (Format::Bc2, 128),
+        b"DXT4" | b"DXT5" => (Format::Bc3, 128),
+        b"DX10" => {
+            let format = match read_u32(bytes, 128)? {
+                70..=72 => Format::Bc1,
+                73..=75 => Format::Bc2,
+                76..=78 => Format::Bc3,
+                dxgi_format => {
+                    return Err(ImageError::UnsupportedError(format!(
+                        "Unsupported DXGI format: {}",
+                        dxgi_format
+                    )))
+                }
+            };
+
+            (format, 148)
+        }
+        _ => {
+            return Err(ImageError::UnsupportedError(String::from(
+                "Only BC1, BC2, and BC3 DDS textures are supported",
+            )))
+        }
+    };
+
+    decode_blocks(format, width, height, &bytes[offset.min(bytes.len())..])
+}
+
+fn decode_blocks(
+    format: Format,
+    width: u32,
+    height: u32,
+    data: &[u8],
+) -> ImageResult<RgbaImage> {
+    if width == 0 || height == 0 {
+        return Err(ImageError::DimensionError);
+    }
+
+    let columns = ((width - 1) / 4 + 1) as usize;
+    let rows = ((height - 1) / 4 + 1) as usize;
+
+    let size = columns
+        .checked_mul(rows)
+        .and_then(|blocks| blocks.checked_mul(format.block_size()))
+        .ok_or(ImageError::DimensionError)?;
+
+    if data.len() < size {
+        return Err(ImageError::NotEnoughData);
+    }
+
+    let mut image = RgbaImage::new(width, height);
+
+    for (i, block) in data
+        .chunks_exact(format.block_size())
+        .take(columns * rows)
+        .enumerate()
+    {
+        let pixels = match format {
+            Format::Bc1 => decode_color(block, true),
+            Format::Bc2 => {
+                let mut pixels = decode_color(&block[8..], false);
+                let alpha = u64_at(block, 0);
+
+                for (j, pixel) in pixels.iter_mut().enumerate() {
+                    pixel[3] = ((alpha >> (4 * j)) & 0xF) as u8 * 17;
+                }
+
+                pixels
+            }
+            Format::Bc3 => {
+                let mut pixels = decode_color(&block[8..], false);
+                let alpha = decode_alpha(&block[..8]);
+
+                for (pixel, alpha) in pixels.iter_mut().zip(alpha.iter()) {
+                    pixel[3] = *alpha;
+                }
+
+                pixels
+            }
+        };
+
+        let x = (i % columns) as u32 * 4;
+        let y = (i / columns) as u32 * 4;
+
+        for (j, pixel) in pixels.iter().enumerate() {
+            let (px, py) = (x + j as u32 % 4, y + j as u32 / 4);
+
+            if px < width && py < height {
+                image.put_pixel(px, py, image::Rgba(*pixel));
+            }
+        }
+    }
+
+    Ok(image)
+}
+
+fn decode_color(block: &[u8], allow_alpha: bool) -> [[u8; 4]; 16] {
+    let c0 = u16::from(block[0]) | u16::from(block[1]) << 8;
+    let c1 = u16::from(block[2]) | u16::from(block[3]) << 8;
+    let indices = u32_at(block, 4);
+
+    let a = rgb565(c0);
+    let b = rgb565(c1);
+
+    let mix = |wa: u16, wb: u16| {
+        let channel = |i: usize| {
+            ((u16::from(a[i]) * wa + u16::from(b[i]) * wb) / (wa + wb)) as u8
+        };
+
+        [channel(0), channel(1), channel(2), 255]
+    };
+
+    // BC1 uses 3 colors and transparency when the endpoints are reversed
+    let palette = if c0 > c1 || !allow_alpha {
+        [a, b, mix(2, 1), mix(1, 2)]
+    } else {
+        [a, b, mix(1, 1), [0, 0, 0, 0]]
+    };
+
+    let mut pixels = [[0; 4]; 16];
+
+    for (i, pixel) in pixels.iter_mut().enumerate() {
+        *pixel = palette[((indices >> (2 * i)) & 0b11) as usize];
+    }
+
+    pixels
+}
+
+fn decode_alpha(block: &[u8]) -> [u8; 16] {
+    let a0 = u16::from(block[0]);
+    let a1 = u16::from(block[1]);
+    let indices = u64_at(block, 0) >> 16;
+
+    let mut palette = [0u8; 8];
+    palette[0] = a0 as u8;
+    palette[1] = a1 as u8;
+
+    if a0 > a1 {
+        for (i, value) in palette.iter_mut().enumerate().skip(2) {
+            let i = i as u16 - 1;
+            *value = (((7 - i) * a0 + i * a1) / 7) as u8;
+        }
+    } else {
+        for (i, value) in palette.iter_mut().enumerate().skip(2).take(4) {
+            let i = i as u16 - 1;
+            *value = (((5 - i) * a0 + i * a1) / 5) as u8;
+        }
+
+        palette[6] = 0;
+        palette[7] = 255;
+    }
+
+    let mut alpha = [0; 16];
+
+    for (i, value) in alpha.iter_mut().enumerate() {
+        *value = palette[((indices >> (3 * i)) & 0b111) as usize];
+    }
+
+    alpha
+}
+
+fn rgb565(color: u16) -> [u8; 4] {
+    let r = ((color >> 11) & 0x1F) as u8;
+    let g = ((color >> 5) & 0x3F) as u8;
+    let b = (color & 0x1F) as u8;
+
+    [r << 3 | r >> 2, g << 2 | g >> 4, b << 3 | b >> 2, 255]
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> ImageResult<u32> {
+    if bytes.len() < offset + 4 {
+        return Err(ImageError::NotEnoughData);
+    }
+
+    Ok(u32_at(bytes, offset))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> ImageResult<u64> {
+    if bytes.len() < offset + 8 {
+        return Err(ImageError::NotEnoughData);
+    }
+
+    Ok(u64_at(bytes, offset))
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    (0..4).fold(0, |value, i| {
+        value | u32::from(bytes[offset + i]) << (8 * i)
+    })
+}
+
+fn u64_at(bytes: &[u8], offset: usize) -> u64 {
+    (0..8).fold(0, |value, i| {
+        value | u64::from(bytes[offset + i]) << (8 * i)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    fn dds(width: u32, height: u32, four_cc: &[u8; 4], data: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0; 128];
+
+        bytes[..4].copy_from_slice(DDS_MAGIC);
+        bytes[12..16].copy_from_slice(&height.to_le_bytes());
+        bytes[16..20].copy_from_slice(&width.to_le_bytes());
+        bytes[84..88].copy_from_slice(four_cc);
+        bytes.extend_from_slice(data);
+
+        bytes
+    }
+
+    fn ktx2(vk_format: u32, width: u32, height: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![0; 96];
+
+        bytes[..12].copy_from_slice(&KTX2_IDENTIFIER);
+        bytes[12..16].copy_from_slice(&vk_format.to_le_bytes());
+        bytes[20..24].copy_from_slice(&width.to_le_bytes());
+        bytes[24..28].copy_from_slice(&height.to_le_bytes());
+        bytes[80..88].copy_from_slice(&96u64.to_le_bytes());
+        bytes[88..96].copy_from_slice(&(data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(data);
+
+        bytes
+    }
+
+    fn pixels(image: &RgbaImage) -> Vec<[u8; 4]> {
+        image.pixels().map(|pixel| pixel.data).collect()
+    }
+
+    #[test]
+    fn decodes_opaque_bc1_block() {
+        // Red and blue endpoints, the first 4 pixels use every index
+        let block = [0x00, 0xF8, 0x1F, 0x00, 0xE4, 0x00, 0x00, 0x00];
+
+        let image = decode(&dds(4, 4, b"DXT1", &block))
+            .expect("Recognize DDS")
+            .expect("Decode BC1");
+
+        let pixels = pixels(&image);
+
+        assert_eq!(pixels[0], RED);
+        assert_eq!(pixels[1], BLUE);
+        assert_eq!(pixels[2], [170, 0, 85, 255]);
+        assert_eq!(pixels[3], [85, 0, 170, 255]);
+        assert!(pixels[4..].iter().all(|pixel| *pixel == RED));
+    }
+
+    #[test]
+    fn decodes_transparent_bc1_block() {
+        // Reversed endpoints use 3 colors and transparency
+        let block = [0x1F, 0x00, 0x00, 0xF8, 0xE4, 0x00, 0x00, 0x00];
+
+        let image = decode(&dds(4, 4, b"DXT1", &block))
+            .expect("Recognize DDS")
+            .expect("Decode BC1");
+
+        let pixels = pixels(&image);
+
+        assert_eq!(pixels[0], BLUE);
+        assert_eq!(pixels[1], RED);
+        assert_eq!(pixels[2], [127, 0, 127, 255]);
+        assert_eq!(pixels[3], [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn decodes_bc3_block() {
+        // Alpha endpoints 255 and 0, the first pixel uses index 1 and the
+        // second one index 2. The color is white.
+        let block = [
+            0xFF, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        // VK_FORMAT_BC3_UNORM_BLOCK
+        let image = decode(&ktx2(137, 4, 4, &block))
+            .expect("Recognize KTX2")
+            .expect("Decode BC3");
+
+        let pixels = pixels(&image);
+
+        assert_eq!(pixels[0], [255, 255, 255, 0]);
+        assert_eq!(pixels[1], [255, 255, 255, 218]);
+        assert!(pixels[2..].iter().all(|pixel| *pixel == [255; 4]));
+    }
+
+    #[test]
+    fn crops_partial_blocks() {
+        let block = [0x00, 0xF8, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        let image = decode(&dds(3, 2, b"DXT1", &block))
+            .expect("Recognize DDS")
+            .expect("Decode BC1");
+
+        assert_eq!(image.dimensions(), (3, 2));
+        assert!(pixels(&image).iter().all(|pixel| *pixel == RED));
+    }
+
+    #[test]
+    fn ignores_other_formats() {
+        assert!(decode(b"\x89PNG\r\n\x1a\n").is_none());
+    }
+
+    #[test]
+    fn rejects_truncated_headers() {
+        assert!(decode(DDS_MAGIC).expect("Recognize DDS").is_err());
+        assert!(decode(&KTX2_IDENTIFIER).expect("Recognize KTX2").is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let block = [0x00, 0xF8, 0x1F, 0x00];
+
+        assert!(decode(&dds(4, 4, b"DXT1", &block))
+            .expect("Recognize DDS")
+            .is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_dimensions() {
+        let block = [0x00, 0xF8, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00];
+
+        assert!(decode(&dds(u32::MAX, u32::MAX, b"DXT5", &block))
+            .expect("Recognize DDS")
+            .is_err());
+
+        // VK_FORMAT_R8G8B8A8_UNORM
+        assert!(decode(&ktx2(37, u32::MAX, u32::MAX, &block))
+            .expect("Recognize KTX2")
+            .is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use crate::graphics::Transformation;
+
+/// The coordinate convention used to draw on a [`Target`].
+///
+/// Use [`Target::with_coordinates`] to change it.
+///
+/// [`Target`]: struct.Target.html
+/// [`Target::with_coordinates`]: struct.Target.html#method.with_coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoordinateSystem {
+    /// The origin is at the top-left corner and the Y axis points down.
+    ///
+    /// This is the default coordinate system.
+    Screen,
+
+    /// The origin is at the center and the Y axis points up, like in most
+    /// physics engines and math libraries.
+    ///
+    /// Positive rotations are counter-clockwise. The `position` of a [`Quad`]
+    /// or a [`Sprite`] is its bottom-left corner, and images are drawn
+    /// upright.
+    ///
+    /// Text is drawn upright too, at the same `position` it would have in
+    /// [`Screen`] coordinates. Its lines still flow from its `position`
+    /// towards the bottom of the screen.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    /// [`Sprite`]: struct.Sprite.html
+    /// [`Screen`]: #variant.Screen
+    Cartesian,
+}
+
+impl CoordinateSystem {
+    /// Returns the [`Transformation`] that turns coordinates of this
+    /// [`CoordinateSystem`] into [`Screen`] coordinates, for an area of the
+    /// given size.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    /// [`CoordinateSystem`]: enum.CoordinateSystem.html
+    /// [`Screen`]: #variant.Screen
+    pub fn transformation(self, width: f32, height: f32) -> Transformation {
+        match self {
+            CoordinateSystem::Screen => Transformation::identity(),
+            CoordinateSystem::Cartesian => {
+                Transformation::cartesian(width, height)
+            }
+        }
+    }
+}
+
+impl Default for CoordinateSystem {
+    fn default() -> CoordinateSystem {
+        CoordinateSystem::Screen
+    }
+}
@@ -0,0 +1,37 @@
+/// A diagnostic draw mode of a [`Gpu`].
+///
+/// It affects quads and triangles, but not text.
+///
+/// You can change the [`DebugDraw`] mode of a [`Gpu`] with
+/// [`Gpu::set_debug_draw`].
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`DebugDraw`]: enum.DebugDraw.html
+/// [`Gpu::set_debug_draw`]: struct.Gpu.html#method.set_debug_draw
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugDraw {
+    /// Everything is drawn normally.
+    Off,
+
+    /// Only the edges of the drawn triangles are rendered.
+    Wireframe,
+
+    /// Colors are blended additively, making areas that are drawn multiple
+    /// times stand out.
+    Overdraw,
+
+    /// Everything is drawn normally. Then, the outline of every quad is drawn
+    /// on top in green, the bounds of every batch of quads in yellow, and the
+    /// extents of every drawn [`Canvas`] in magenta.
+    ///
+    /// Use it to find misplaced sprites and wrong sprite sources.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    Bounds,
+}
+
+impl Default for DebugDraw {
+    fn default() -> DebugDraw {
+        DebugDraw::Off
+    }
+}
@@ -1,21 +1,62 @@
+use std::path::{Path, PathBuf};
+
 use crate::graphics::gpu;
-use crate::graphics::{Gpu, Target, Text};
+use crate::graphics::{
+    Canvas, FontFace, Gpu, Rectangle, RichText, Target, Text,
+};
 use crate::load::Task;
 use crate::Result;
 
+#[cfg(feature = "system-fonts")]
+use crate::graphics::{FontStyle, FontWeight};
+#[cfg(feature = "system-fonts")]
+use crate::Error;
+
 /// A collection of text with the same font.
+///
+/// Text added to a [`Font`] is queued until [`Font::draw`] is called. Then,
+/// all the queued text is rendered at once using a shared glyph cache,
+/// usually with a single draw call. Therefore, you should add all the text
+/// that uses the same [`Font`] before drawing it.
+///
+/// The glyph cache grows automatically when a frame uses too many different
+/// glyphs.
+///
+/// [`Font`]: struct.Font.html
+/// [`Font::draw`]: struct.Font.html#method.draw
 #[allow(missing_debug_implementations)]
-pub struct Font(gpu::Font);
+pub struct Font {
+    raw: gpu::Font,
+    rasterize_at_scale: bool,
+}
 
 impl Font {
     pub(crate) const DEFAULT: &'static [u8] =
         include_bytes!("../../resources/font/Inconsolata-Regular.ttf");
 
+    const DEFAULT_CACHE_SIZE: (u32, u32) = (256, 256);
+
     /// Loads a [`Font`] from raw data.
     ///
     /// [`Font`]: struct.Font.html
     pub fn from_bytes(gpu: &mut Gpu, bytes: &'static [u8]) -> Result<Font> {
-        Ok(Font(gpu.upload_font(bytes)))
+        Font::from_bytes_with_cache_size(gpu, bytes, Self::DEFAULT_CACHE_SIZE)
+    }
+
+    /// Loads a [`Font`] from raw data with a glyph cache texture of the given
+    /// initial size.
+    ///
+    /// The glyph cache starts at `256x256` by default. Use a bigger size if
+    /// you draw lots of different glyphs or big text, to avoid growing the
+    /// cache during your game.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub fn from_bytes_with_cache_size(
+        gpu: &mut Gpu,
+        bytes: &'static [u8],
+        cache_size: (u32, u32),
+    ) -> Result<Font> {
+        Ok(Font::from_raw(gpu.upload_font(bytes.into(), cache_size)))
     }
 
     /// Creates a [`Task`] that loads a [`Font`] from raw data.
@@ -26,19 +67,231 @@ impl Font {
         Task::using_gpu(move |gpu| Font::from_bytes(gpu, bytes))
     }
 
+    /// Loads a [`Font`] from the given path.
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`]. Its contents
+    /// are kept in memory until the [`Font`] is dropped.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Font> {
+        let bytes = gpu.read_asset(path)?;
+
+        let font = gpu.upload_font(bytes.into(), Self::DEFAULT_CACHE_SIZE);
+
+        Ok(Font::from_raw(font))
+    }
+
+    /// Creates a [`Task`] that loads a [`Font`] from the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Font> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| Font::new(gpu, &p))
+    }
+
+    /// Loads a [`Font`] from the given path, together with a list of fallback
+    /// fonts.
+    ///
+    /// Characters missing in the [`Font`] are drawn with the first fallback
+    /// that contains them, instead of as empty boxes. This is useful to draw
+    /// text in multiple scripts, like CJK characters or symbols.
+    ///
+    /// See [`Font::new`] and [`Font::add_fallback`].
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`Font::new`]: #method.new
+    /// [`Font::add_fallback`]: #method.add_fallback
+    pub fn new_with_fallback<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+        fallbacks: &[P],
+    ) -> Result<Font> {
+        let mut font = Font::new(gpu, path)?;
+
+        for fallback in fallbacks {
+            let bytes = gpu.read_asset(fallback)?;
+
+            font.raw.add_fallback(bytes.into());
+        }
+
+        Ok(font)
+    }
+
+    /// Creates a [`Task`] that loads a [`Font`] from the given path, together
+    /// with a list of fallback fonts.
+    ///
+    /// See [`Font::new_with_fallback`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    /// [`Font::new_with_fallback`]: #method.new_with_fallback
+    pub fn load_with_fallback<P: Into<PathBuf>>(
+        path: P,
+        fallbacks: Vec<P>,
+    ) -> Task<Font> {
+        let p = path.into();
+        let fallbacks: Vec<PathBuf> =
+            fallbacks.into_iter().map(Into::into).collect();
+
+        Task::using_gpu(move |gpu| Font::new_with_fallback(gpu, &p, &fallbacks))
+    }
+
+    /// Loads a [`Font`] installed in the system by its family name, like
+    /// `"Noto Sans"`.
+    ///
+    /// The font of the family that best matches the given [`FontWeight`] and
+    /// [`FontStyle`] is chosen. Its contents are kept in memory until the
+    /// [`Font`] is dropped, like with [`Font::new`].
+    ///
+    /// This method is only available with the `system-fonts` feature.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`FontWeight`]: enum.FontWeight.html
+    /// [`FontStyle`]: enum.FontStyle.html
+    /// [`Font::new`]: #method.new
+    #[cfg(feature = "system-fonts")]
+    pub fn from_system(
+        gpu: &mut Gpu,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+    ) -> Result<Font> {
+        use font_kit::family_name::FamilyName;
+        use font_kit::properties::Properties;
+        use font_kit::source::SystemSource;
+
+        let handle = SystemSource::new()
+            .select_best_match(
+                &[FamilyName::Title(family.to_owned())],
+                Properties::new().weight(weight.into()).style(style.into()),
+            )
+            .map_err(|error| Error::SystemFont(error.to_string()))?;
+
+        let font = handle
+            .load()
+            .map_err(|error| Error::SystemFont(error.to_string()))?;
+
+        let bytes = font.copy_font_data().ok_or_else(|| {
+            Error::SystemFont(format!("Font data of {} is unavailable", family))
+        })?;
+
+        let font =
+            gpu.upload_font(bytes.to_vec().into(), Self::DEFAULT_CACHE_SIZE);
+
+        Ok(Font::from_raw(font))
+    }
+
+    /// Creates a [`Task`] that loads a [`Font`] installed in the system by its
+    /// family name.
+    ///
+    /// See [`Font::from_system`].
+    ///
+    /// This method is only available with the `system-fonts` feature.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Font`]: struct.Font.html
+    /// [`Font::from_system`]: #method.from_system
+    #[cfg(feature = "system-fonts")]
+    pub fn load_from_system(
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+    ) -> Task<Font> {
+        let family = family.to_owned();
+
+        Task::using_gpu(move |gpu| {
+            Font::from_system(gpu, &family, weight, style)
+        })
+    }
+
+    /// Adds a new face to this [`Font`] from raw data, like its bold or italic
+    /// variant.
+    ///
+    /// Use the returned [`FontFace`] in the [`Span`]s of some [`RichText`] to
+    /// draw them with the new face.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`FontFace`]: struct.FontFace.html
+    /// [`Span`]: struct.Span.html
+    /// [`RichText`]: struct.RichText.html
+    pub fn add_face(&mut self, bytes: &'static [u8]) -> FontFace {
+        self.raw.add_face(bytes.into())
+    }
+
+    /// Adds a fallback font to this [`Font`] from raw data.
+    ///
+    /// Characters missing in the [`FontFace`] of some text are drawn with the
+    /// first fallback that contains them, in the order they were added.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`FontFace`]: struct.FontFace.html
+    pub fn add_fallback(&mut self, bytes: &'static [u8]) {
+        self.raw.add_fallback(bytes.into())
+    }
+
     /// Adds [`Text`] to this [`Font`].
     ///
     /// [`Text`]: struct.Text.html
     /// [`Font`]: struct.Font.html
     pub fn add(&mut self, text: Text<'_>) {
-        self.0.add(text)
+        self.raw.add(text)
     }
 
-    /// Computes the layout bounds of the given [`Text`].
+    /// Adds [`RichText`] to this [`Font`].
+    ///
+    /// [`RichText`]: struct.RichText.html
+    /// [`Font`]: struct.Font.html
+    pub fn add_rich(&mut self, text: RichText<'_>) {
+        self.raw.add_rich(text)
+    }
+
+    /// Rasterizes the given characters at the given size and uploads them to
+    /// the glyph cache of this [`Font`].
+    ///
+    /// The first time a glyph is drawn at a size, it needs to be rasterized,
+    /// which can cause a stutter. Preload the characters of your text while
+    /// loading your game to avoid it.
+    ///
+    /// Text added to this [`Font`] and not drawn yet is kept.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub fn preload(
+        &mut self,
+        gpu: &mut Gpu,
+        characters: &str,
+        size: f32,
+    ) -> Result<()> {
+        let mut canvas = Canvas::new(gpu, 1, 1)?;
+
+        self.raw.preload(characters, size);
+        canvas.as_target(gpu).preload_font(&mut self.raw);
+
+        Ok(())
+    }
+
+    /// Computes the layout bounds of the given [`Text`] without drawing it.
+    ///
+    /// The returned [`Rectangle`] is the area the [`Text`] would cover if it
+    /// was added to this [`Font`], taking its position and alignment into
+    /// account. It is empty when the [`Text`] has no visible glyphs.
     ///
     /// [`Text`]: struct.Text.html
-    pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        self.0.measure(text)
+    /// [`Rectangle`]: struct.Rectangle.html
+    /// [`Font`]: struct.Font.html
+    pub fn measure(&mut self, text: &Text<'_>) -> Rectangle<f32> {
+        self.raw.measure(text)
+    }
+
+    /// Computes the layout bounds of the given [`RichText`] without drawing
+    /// it.
+    ///
+    /// [`RichText`]: struct.RichText.html
+    pub fn measure_rich(&mut self, text: &RichText<'_>) -> Rectangle<f32> {
+        self.raw.measure_rich(text)
     }
 
     /// Renders and flushes all the text added to this [`Font`].
@@ -46,6 +299,31 @@ impl Font {
     /// [`Font`]: struct.Font.html
     #[inline]
     pub fn draw(&mut self, target: &mut Target<'_>) {
-        target.draw_font(&mut self.0)
+        target.draw_font(&mut self.raw, self.rasterize_at_scale)
+    }
+
+    /// Sets whether the glyphs of this [`Font`] are rasterized at the scale
+    /// of the [`Target`] they are drawn on.
+    ///
+    /// By default, glyphs are rasterized at their size in the coordinates of
+    /// the [`Target`] and then scaled by its [`Transformation`], which makes
+    /// text blurry when zoomed in. When enabled, the glyphs are rasterized at
+    /// the size they cover on the [`Target`] instead, so text stays crisp.
+    /// However, every distinct scale fills the glyph cache with new glyphs,
+    /// so avoid enabling it for text drawn under a continuously animated
+    /// zoom.
+    ///
+    /// [`Font`]: struct.Font.html
+    /// [`Target`]: struct.Target.html
+    /// [`Transformation`]: struct.Transformation.html
+    pub fn set_rasterize_at_scale(&mut self, enabled: bool) {
+        self.rasterize_at_scale = enabled;
+    }
+
+    fn from_raw(raw: gpu::Font) -> Font {
+        Font {
+            raw,
+            rasterize_at_scale: false,
+        }
     }
 }
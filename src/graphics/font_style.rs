@@ -0,0 +1,85 @@
+/// The weight of a system font.
+///
+/// It is used to choose a font of a family with [`Font::from_system`].
+///
+/// [`Font::from_system`]: struct.Font.html#method.from_system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontWeight {
+    /// Thin weight (100)
+    Thin,
+
+    /// Light weight (300)
+    Light,
+
+    /// Normal weight (400)
+    Normal,
+
+    /// Medium weight (500)
+    Medium,
+
+    /// Semibold weight (600)
+    Semibold,
+
+    /// Bold weight (700)
+    Bold,
+
+    /// Black weight (900)
+    Black,
+}
+
+impl Default for FontWeight {
+    fn default() -> FontWeight {
+        FontWeight::Normal
+    }
+}
+
+impl From<FontWeight> for font_kit::properties::Weight {
+    fn from(weight: FontWeight) -> font_kit::properties::Weight {
+        use font_kit::properties::Weight;
+
+        match weight {
+            FontWeight::Thin => Weight::THIN,
+            FontWeight::Light => Weight::LIGHT,
+            FontWeight::Normal => Weight::NORMAL,
+            FontWeight::Medium => Weight::MEDIUM,
+            FontWeight::Semibold => Weight::SEMIBOLD,
+            FontWeight::Bold => Weight::BOLD,
+            FontWeight::Black => Weight::BLACK,
+        }
+    }
+}
+
+/// The style of a system font.
+///
+/// It is used to choose a font of a family with [`Font::from_system`].
+///
+/// [`Font::from_system`]: struct.Font.html#method.from_system
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontStyle {
+    /// Upright glyphs
+    Normal,
+
+    /// Cursive glyphs designed to be slanted
+    Italic,
+
+    /// Upright glyphs that are slanted
+    Oblique,
+}
+
+impl Default for FontStyle {
+    fn default() -> FontStyle {
+        FontStyle::Normal
+    }
+}
+
+impl From<FontStyle> for font_kit::properties::Style {
+    fn from(style: FontStyle) -> font_kit::properties::Style {
+        use font_kit::properties::Style;
+
+        match style {
+            FontStyle::Normal => Style::Normal,
+            FontStyle::Italic => Style::Italic,
+            FontStyle::Oblique => Style::Oblique,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/// The pixel format of a [`Canvas`].
+///
+/// Use [`Canvas::new_with_format`] to choose it.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`Canvas::new_with_format`]: struct.Canvas.html#method.new_with_format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// 8 bits per channel. Colors are clamped to the `[0.0, 1.0]` range.
+    ///
+    /// This is the default format.
+    Rgba8,
+
+    /// A 16-bit floating-point number per channel.
+    ///
+    /// Colors can exceed `1.0`, which is useful to accumulate lighting or to
+    /// extract the bright parts of a scene before a final tonemapping pass.
+    Rgba16Float,
+}
+
+impl Default for Format {
+    fn default() -> Format {
+        Format::Rgba8
+    }
+}
@@ -0,0 +1,114 @@
+use std::time::{Duration, Instant};
+
+/// The amount of work submitted to a [`Gpu`] during a frame.
+///
+/// You can obtain the statistics of the last presented frame with
+/// [`Gpu::last_frame_stats`]. Use them to check whether your [`Batch`] usage
+/// is collapsing draw calls or fragmenting them.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Gpu::last_frame_stats`]: struct.Gpu.html#method.last_frame_stats
+/// [`Batch`]: struct.Batch.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameStats {
+    /// The number of draw calls issued.
+    pub draw_calls: u32,
+
+    /// The number of textured quads drawn.
+    pub quads: u32,
+
+    /// The number of colored triangles drawn.
+    pub triangles: u32,
+
+    /// The number of times the active pipeline changed.
+    ///
+    /// Quads, triangles, and text are drawn with different pipelines.
+    pub pipeline_switches: u32,
+
+    /// The number of times a different texture was bound to draw quads.
+    pub texture_switches: u32,
+
+    /// The CPU time spent submitting and presenting the frame.
+    ///
+    /// This is not the time the GPU spent drawing the frame. The graphics
+    /// backends do not support timestamp queries, so GPU time is unavailable.
+    /// However, the CPU waits for the GPU to catch up while presenting.
+    /// Therefore, when this time is a big part of the frame time, and vsync is
+    /// disabled, the frame is likely GPU-bound.
+    pub present_cpu_time: Duration,
+}
+
+/// Accumulates the [`FrameStats`] of the current frame.
+///
+/// [`FrameStats`]: struct.FrameStats.html
+#[derive(Debug, Default)]
+pub(crate) struct Counter {
+    current: FrameStats,
+    last: FrameStats,
+    pipeline: Option<Pipeline>,
+    texture: Option<usize>,
+    present_start: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pipeline {
+    Quad,
+    Triangle,
+    Font,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter::default()
+    }
+
+    pub fn last_frame(&self) -> FrameStats {
+        self.last
+    }
+
+    pub fn triangles(&mut self, indices: usize) {
+        if indices > 0 {
+            self.draw(Pipeline::Triangle, 1);
+            self.current.triangles += (indices / 3) as u32;
+        }
+    }
+
+    pub fn quads(&mut self, texture: usize, quads: usize, draw_calls: usize) {
+        if quads > 0 {
+            self.draw(Pipeline::Quad, draw_calls);
+            self.current.quads += quads as u32;
+
+            if self.texture != Some(texture) {
+                self.current.texture_switches += 1;
+                self.texture = Some(texture);
+            }
+        }
+    }
+
+    pub fn font(&mut self) {
+        self.draw(Pipeline::Font, 1);
+    }
+
+    pub fn start_present(&mut self) {
+        self.present_start = Some(Instant::now());
+    }
+
+    pub fn finish_frame(&mut self) {
+        if let Some(start) = self.present_start.take() {
+            self.current.present_cpu_time = start.elapsed();
+        }
+
+        self.last = std::mem::replace(&mut self.current, FrameStats::default());
+        self.pipeline = None;
+        self.texture = None;
+    }
+
+    fn draw(&mut self, pipeline: Pipeline, draw_calls: usize) {
+        self.current.draw_calls += draw_calls as u32;
+
+        if self.pipeline != Some(pipeline) {
+            self.current.pipeline_switches += 1;
+            self.pipeline = Some(pipeline);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use crate::graphics::{gpu, Color, Gradient, Image, Point, Target};
+
+/// A buffer of colored and textured triangles that can be drawn in a single
+/// draw call.
+///
+/// Unlike a [`Mesh`], a [`Geometry`] does not tessellate shapes. Instead, it
+/// gives you full control over the topology of what you draw. You can use it
+/// to build effects that do not fit quads, like trails or custom gradients.
+///
+/// Every [`Vertex`] has a position in the coordinates of the [`Target`],
+/// texture coordinates, and a [`Color`]. Both texture coordinates and colors
+/// are interpolated across the surface of each triangle. When drawn with an
+/// [`Image`], the color of each pixel is the sampled texel multiplied by the
+/// interpolated [`Color`].
+///
+/// [`Mesh`]: struct.Mesh.html
+/// [`Geometry`]: struct.Geometry.html
+/// [`Vertex`]: struct.Vertex.html
+/// [`Target`]: struct.Target.html
+/// [`Color`]: struct.Color.html
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone)]
+pub struct Geometry {
+    vertices: Vec<gpu::Vertex>,
+    indices: Vec<u32>,
+}
+
+/// A vertex of a [`Geometry`].
+///
+/// [`Geometry`]: struct.Geometry.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Vertex {
+    /// The position of the vertex.
+    pub position: Point,
+
+    /// The texture coordinates of the vertex.
+    ///
+    /// They are normalized, `(0.0, 0.0)` being the top-left corner of the
+    /// [`Image`] and `(1.0, 1.0)` its bottom-right corner. Coordinates outside
+    /// of this range follow the [`WrapMode`] of the [`Image`]. They are
+    /// ignored when the [`Geometry`] is drawn without an [`Image`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`WrapMode`]: enum.WrapMode.html
+    /// [`Geometry`]: struct.Geometry.html
+    pub uv: Point,
+
+    /// The color of the vertex.
+    pub color: Color,
+}
+
+impl Geometry {
+    /// Creates a new empty [`Geometry`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn new() -> Geometry {
+        Geometry {
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    /// Returns true if the [`Geometry`] is empty.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Adds a triangle to the [`Geometry`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn push_triangle(&mut self, a: Vertex, b: Vertex, c: Vertex) {
+        let first = self.vertices.len() as u32;
+
+        self.vertices.extend([a, b, c].iter().map(|vertex| {
+            gpu::Vertex::new(
+                [vertex.position.x, vertex.position.y],
+                [vertex.uv.x, vertex.uv.y],
+                vertex.color.into_linear(),
+            )
+        }));
+
+        self.indices.extend(&[first, first + 1, first + 2]);
+    }
+
+    /// Adds the triangles of a [`Gradient`] to the [`Geometry`].
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn push_gradient(&mut self, gradient: Gradient) {
+        gradient.tessellate(self);
+    }
+
+    /// Removes all the triangles of the [`Geometry`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+
+    /// Draws the [`Geometry`] on the given [`Target`].
+    ///
+    /// Only the colors of the vertices are used.
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Target`]: struct.Target.html
+    pub fn draw(&self, target: &mut Target<'_>) {
+        target.draw_triangles(None, &self.vertices, &self.indices);
+    }
+
+    /// Draws the [`Geometry`] on the given [`Target`], texturing its
+    /// triangles with the given [`Image`].
+    ///
+    /// [`Geometry`]: struct.Geometry.html
+    /// [`Target`]: struct.Target.html
+    /// [`Image`]: struct.Image.html
+    pub fn draw_textured(&self, image: &Image, target: &mut Target<'_>) {
+        target.draw_triangles(
+            Some(&image.texture),
+            &self.vertices,
+            &self.indices,
+        );
+    }
+}
+
+impl Default for Geometry {
+    fn default() -> Geometry {
+        Geometry::new()
+    }
+}
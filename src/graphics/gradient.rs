@@ -0,0 +1,143 @@
+use std::f32::consts::PI;
+
+use crate::graphics::{Color, Geometry, Point, Rectangle, Target, Vertex};
+
+/// A smooth transition between two colors.
+///
+/// A [`Gradient`] is made of a few colored triangles, so it is cheap to draw.
+/// Use it to draw skies, vignettes, or health bars without baking textures.
+///
+/// You can draw it directly with [`Gradient::draw`] or add many of them to a
+/// [`Geometry`] with [`Geometry::push_gradient`].
+///
+/// [`Gradient`]: enum.Gradient.html
+/// [`Gradient::draw`]: enum.Gradient.html#method.draw
+/// [`Geometry`]: struct.Geometry.html
+/// [`Geometry::push_gradient`]: struct.Geometry.html#method.push_gradient
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gradient {
+    /// A rectangle that goes from one color on its left edge to another one
+    /// on its right edge
+    Horizontal {
+        /// The bounds of the rectangle
+        bounds: Rectangle<f32>,
+
+        /// The color of the left edge
+        left: Color,
+
+        /// The color of the right edge
+        right: Color,
+    },
+
+    /// A rectangle that goes from one color on its top edge to another one on
+    /// its bottom edge
+    Vertical {
+        /// The bounds of the rectangle
+        bounds: Rectangle<f32>,
+
+        /// The color of the top edge
+        top: Color,
+
+        /// The color of the bottom edge
+        bottom: Color,
+    },
+
+    /// A circle that goes from one color at its center to another one on its
+    /// edge
+    Radial {
+        /// The center of the circle
+        center: Point,
+
+        /// The radius of the circle
+        radius: f32,
+
+        /// The color of the center
+        inner: Color,
+
+        /// The color of the edge
+        outer: Color,
+    },
+}
+
+impl Gradient {
+    /// Draws the [`Gradient`] on the given [`Target`].
+    ///
+    /// [`Gradient`]: enum.Gradient.html
+    /// [`Target`]: struct.Target.html
+    pub fn draw(&self, target: &mut Target<'_>) {
+        let mut geometry = Geometry::new();
+        geometry.push_gradient(*self);
+        geometry.draw(target);
+    }
+
+    pub(crate) fn tessellate(self, geometry: &mut Geometry) {
+        match self {
+            Gradient::Horizontal {
+                bounds,
+                left,
+                right,
+            } => push_rectangle(geometry, bounds, [left, right, right, left]),
+            Gradient::Vertical {
+                bounds,
+                top,
+                bottom,
+            } => push_rectangle(geometry, bounds, [top, top, bottom, bottom]),
+            Gradient::Radial {
+                center,
+                radius,
+                inner,
+                outer,
+            } => {
+                // Roughly one segment every 4 units of circumference
+                let segments = (PI * radius / 2.0).ceil() as usize;
+                let segments = segments.max(16).min(256);
+
+                let point = |i: usize| {
+                    let angle = 2.0 * PI * i as f32 / segments as f32;
+
+                    Vertex {
+                        position: Point::new(
+                            center.x + radius * angle.cos(),
+                            center.y + radius * angle.sin(),
+                        ),
+                        uv: Point::new(0.0, 0.0),
+                        color: outer,
+                    }
+                };
+
+                let center = Vertex {
+                    position: center,
+                    uv: Point::new(0.0, 0.0),
+                    color: inner,
+                };
+
+                for i in 0..segments {
+                    geometry.push_triangle(center, point(i), point(i + 1));
+                }
+            }
+        }
+    }
+}
+
+fn push_rectangle(
+    geometry: &mut Geometry,
+    bounds: Rectangle<f32>,
+    [top_left, top_right, bottom_right, bottom_left]: [Color; 4],
+) {
+    let vertex = |x, y, color| Vertex {
+        position: Point::new(x, y),
+        uv: Point::new(0.0, 0.0),
+        color,
+    };
+
+    let right = bounds.x + bounds.width;
+    let bottom = bounds.y + bounds.height;
+
+    let top_left = vertex(bounds.x, bounds.y, top_left);
+    let top_right = vertex(right, bounds.y, top_right);
+    let bottom_right = vertex(right, bottom, bottom_right);
+    let bottom_left = vertex(bounds.x, bottom, bottom_left);
+
+    geometry.push_triangle(top_left, top_right, bottom_right);
+    geometry.push_triangle(top_left, bottom_right, bottom_left);
+}
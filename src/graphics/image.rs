@@ -1,10 +1,16 @@
-use std::fs::File;
-use std::io::Read;
 use std::path::{Path, PathBuf};
 
+use image::Pixel;
+
+use crate::graphics::compressed;
 use crate::graphics::gpu::{self, Texture};
-use crate::graphics::{Color, Gpu, IntoQuad, Target};
+use crate::graphics::{
+    Color, Gpu, IntoQuad, NineSlice, Point, Quad, Rectangle, Sampler, Sprite,
+    Target, WrapMode,
+};
 use crate::load::Task;
+#[cfg(feature = "svg")]
+use crate::Error;
 use crate::Result;
 
 /// A loaded image.
@@ -23,26 +29,217 @@ pub struct Image {
 impl Image {
     /// Loads an [`Image`] from the given path.
     ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`].
+    ///
+    /// Besides the formats of the [`image` crate], BC1, BC2, and BC3 textures
+    /// in KTX2 and DDS files are supported. They are decoded to RGBA while
+    /// loading when the [`Gpu`] does not support [`compressed_textures`].
+    ///
     /// [`Image`]: struct.Image.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    /// [`image` crate]: https://docs.rs/image
+    /// [`compressed_textures`]: struct.Capabilities.html#structfield.compressed_textures
     pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Image> {
-        let image = {
-            let mut buf = Vec::new();
-            let mut reader = File::open(path)?;
-            let _ = reader.read_to_end(&mut buf)?;
-            image::load_from_memory(&buf)?
-        };
+        let image = read_image(gpu, path)?;
 
         Image::from_image(gpu, &image)
     }
 
+    /// Loads an [`Image`] from the given path using the given [`Sampler`].
+    ///
+    /// See [`Image::new`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Image::new`]: #method.new
+    pub fn new_with_sampler<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+        sampler: Sampler,
+    ) -> Result<Image> {
+        let mut image = Image::new(gpu, path)?;
+        image.set_sampler(sampler);
+
+        Ok(image)
+    }
+
+    /// Loads an [`Image`] from the given path, making every pixel that matches
+    /// the given color key fully transparent.
+    ///
+    /// A pixel matches the color key when each one of its RGB components
+    /// differs, at most, `tolerance` from the key in the [0, 255] range. Use
+    /// a `tolerance` of `0` to only match the exact color.
+    ///
+    /// This is useful to load old sprite sheets that use a background color
+    /// instead of an alpha channel.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn new_with_colorkey<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+        key: Color,
+        tolerance: u8,
+    ) -> Result<Image> {
+        let mut rgba = read_image(gpu, path)?.to_rgba();
+        let [r, g, b, _] = key.to_rgba();
+
+        let matches = |channel: u8, key: u8| {
+            channel.max(key) - channel.min(key) <= tolerance
+        };
+
+        for pixel in rgba.pixels_mut() {
+            let channels = pixel.channels_mut();
+
+            if matches(channels[0], r)
+                && matches(channels[1], g)
+                && matches(channels[2], b)
+            {
+                channels[3] = 0;
+            }
+        }
+
+        Image::from_image(gpu, &image::DynamicImage::ImageRgba8(rgba))
+    }
+
     /// Creates a [`Task`] that loads an [`Image`] from the given path.
     ///
+    /// The image is decoded in parallel with other images when using
+    /// [`Task::join_parallel`].
+    ///
     /// [`Task`]: ../load/struct.Task.html
     /// [`Image`]: struct.Image.html
+    /// [`Task::join_parallel`]: ../load/struct.Task.html#method.join_parallel
     pub fn load<P: Into<PathBuf>>(path: P) -> Task<Image> {
         let p = path.into();
 
-        Task::using_gpu(move |gpu| Image::new(gpu, &p))
+        Task::using_gpu_in_stages(
+            move |gpu| gpu.read_asset(&p),
+            |bytes| decode_image(&bytes),
+            |gpu, image| Image::from_image(gpu, &image),
+        )
+    }
+
+    /// Creates a [`Task`] that loads an [`Image`] from the given path using
+    /// the given [`Sampler`].
+    ///
+    /// Like with [`Image::load`], the image is decoded in parallel with other
+    /// images when using [`Task::join_parallel`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: struct.Image.html
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Image::load`]: #method.load
+    /// [`Task::join_parallel`]: ../load/struct.Task.html#method.join_parallel
+    pub fn load_with_sampler<P: Into<PathBuf>>(
+        path: P,
+        sampler: Sampler,
+    ) -> Task<Image> {
+        let p = path.into();
+
+        Task::using_gpu_in_stages(
+            move |gpu| gpu.read_asset(&p),
+            |bytes| decode_image(&bytes),
+            move |gpu, image| {
+                let mut image = Image::from_image(gpu, &image)?;
+                image.set_sampler(sampler);
+
+                Ok(image)
+            },
+        )
+    }
+
+    /// Rasterizes the SVG file in the given path into an [`Image`].
+    ///
+    /// The `scale` multiplies the size of the SVG document. Use it to render
+    /// crisp vector art at any resolution.
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`].
+    ///
+    /// This method is only available with the `svg` feature.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    #[cfg(feature = "svg")]
+    pub fn from_svg<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+        scale: f32,
+    ) -> Result<Image> {
+        let svg = gpu.read_asset(path)?;
+
+        let tree = usvg::Tree::from_data(&svg, &usvg::Options::default())
+            .map_err(|error| Error::Svg(error.to_string()))?;
+
+        let size = tree.svg_node().size;
+        let width = (size.width() * f64::from(scale)).ceil() as u32;
+        let height = (size.height() * f64::from(scale)).ceil() as u32;
+
+        let invalid_size =
+            || Error::Svg(format!("Invalid size: {}x{}", width, height));
+
+        let mut pixmap =
+            tiny_skia::Pixmap::new(width, height).ok_or_else(invalid_size)?;
+
+        resvg::render(&tree, usvg::FitTo::Zoom(scale), pixmap.as_mut())
+            .ok_or_else(invalid_size)?;
+
+        // Rendered pixels have premultiplied alpha
+        let mut rgba = pixmap.take();
+
+        for pixel in rgba.chunks_mut(4) {
+            let alpha = u16::from(pixel[3]);
+
+            if alpha > 0 && alpha < 255 {
+                for channel in &mut pixel[..3] {
+                    *channel =
+                        ((u16::from(*channel) * 255 + alpha / 2) / alpha) as u8;
+                }
+            }
+        }
+
+        let image = image::RgbaImage::from_raw(width, height, rgba)
+            .ok_or_else(invalid_size)?;
+
+        Image::from_image(gpu, &image::DynamicImage::ImageRgba8(image))
+    }
+
+    /// Creates a [`Task`] that rasterizes the SVG file in the given path into
+    /// an [`Image`].
+    ///
+    /// See [`Image::from_svg`].
+    ///
+    /// This method is only available with the `svg` feature.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: struct.Image.html
+    /// [`Image::from_svg`]: struct.Image.html#method.from_svg
+    #[cfg(feature = "svg")]
+    pub fn load_svg<P: Into<PathBuf>>(path: P, scale: f32) -> Task<Image> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| Image::from_svg(gpu, &path, scale))
+    }
+
+    /// Creates a [`Task`] that loads an [`Image`] from the given path, making
+    /// every pixel that matches the given color key fully transparent.
+    ///
+    /// See [`Image::new_with_colorkey`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: struct.Image.html
+    /// [`Image::new_with_colorkey`]: #method.new_with_colorkey
+    pub fn load_with_colorkey<P: Into<PathBuf>>(
+        path: P,
+        key: Color,
+        tolerance: u8,
+    ) -> Task<Image> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| {
+            Image::new_with_colorkey(gpu, &p, key, tolerance)
+        })
     }
 
     /// Creates an [`Image`] from a [`DynamicImage`] of the [`image` crate].
@@ -59,6 +256,86 @@ impl Image {
         Ok(Image { texture })
     }
 
+    /// Creates an [`Image`] of the given size from raw RGBA pixels.
+    ///
+    /// The pixels are given row by row, from top to bottom, using 4 bytes per
+    /// pixel. Fails if the amount of pixels does not match the size.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn from_raw(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+        rgba: &[u8],
+    ) -> Result<Image> {
+        if rgba.len() != usize::from(width) * usize::from(height) * 4 {
+            return Err(image::ImageError::DimensionError.into());
+        }
+
+        let image = image::RgbaImage::from_raw(
+            u32::from(width),
+            u32::from(height),
+            rgba.to_vec(),
+        )
+        .ok_or(image::ImageError::DimensionError)?;
+
+        Self::from_image(gpu, &image::DynamicImage::ImageRgba8(image))
+    }
+
+    /// Creates an [`Image`] from a texture created outside of Coffee.
+    ///
+    /// Use it to draw the output of another library or of your own passes,
+    /// recorded using [`Gpu::raw`]. The texture is not copied.
+    ///
+    /// This method is only available with the `raw` feature.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`Gpu::raw`]: struct.Gpu.html#method.raw
+    #[cfg(feature = "raw")]
+    pub fn from_raw_texture(
+        gpu: &mut Gpu,
+        texture: crate::graphics::RawTexture,
+    ) -> Result<Image> {
+        let texture = gpu.wrap_texture(texture)?;
+
+        Ok(Image { texture })
+    }
+
+    /// Replaces the pixels of a region of the [`Image`] with raw RGBA pixels.
+    ///
+    /// The pixels are given row by row, from top to bottom, using 4 bytes per
+    /// pixel. Fails if the region exceeds the bounds of the [`Image`] or if the
+    /// amount of pixels does not match the size of the region.
+    ///
+    /// This is much cheaper than creating a new [`Image`]. Use it to update
+    /// procedurally generated textures. The change is visible in every clone of
+    /// the [`Image`].
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn update_region(
+        &mut self,
+        gpu: &mut Gpu,
+        region: Rectangle<u16>,
+        rgba: &[u8],
+    ) -> Result<()> {
+        let right = u32::from(region.x) + u32::from(region.width);
+        let bottom = u32::from(region.y) + u32::from(region.height);
+        let pixels = usize::from(region.width) * usize::from(region.height);
+
+        if right > u32::from(self.width())
+            || bottom > u32::from(self.height())
+            || rgba.len() != pixels * 4
+        {
+            return Err(image::ImageError::DimensionError.into());
+        }
+
+        if pixels > 0 {
+            gpu.update_texture(&self.texture, 0, region, rgba);
+        }
+
+        Ok(())
+    }
+
     /// Creates an [`Image`] representing a color palette.
     ///
     /// Each [`Color`] will be a pixel of the image, arranged horizontally.
@@ -96,6 +373,58 @@ impl Image {
         self.texture.height()
     }
 
+    /// Returns the [`WrapMode`] of the [`Image`].
+    ///
+    /// [`WrapMode`]: enum.WrapMode.html
+    /// [`Image`]: struct.Image.html
+    pub fn wrap_mode(&self) -> WrapMode {
+        self.texture.sampler().wrap_mode
+    }
+
+    /// Sets the [`WrapMode`] of the [`Image`].
+    ///
+    /// By default, an [`Image`] uses [`WrapMode::Clamp`]. Use
+    /// [`WrapMode::Repeat`] together with a [`Quad`] exceeding the
+    /// `[0.0, 1.0]` range to tile the [`Image`] in a single draw call.
+    ///
+    /// The OpenGL backend targets OpenGL 3.2, which supports repeating
+    /// textures of any size. Therefore, power-of-two dimensions are not
+    /// required.
+    ///
+    /// The [`WrapMode`] is not shared between clones of the same [`Image`].
+    ///
+    /// [`WrapMode`]: enum.WrapMode.html
+    /// [`WrapMode::Clamp`]: enum.WrapMode.html#variant.Clamp
+    /// [`WrapMode::Repeat`]: enum.WrapMode.html#variant.Repeat
+    /// [`Quad`]: struct.Quad.html
+    /// [`Image`]: struct.Image.html
+    pub fn set_wrap_mode(&mut self, wrap_mode: WrapMode) {
+        self.set_sampler(Sampler {
+            wrap_mode,
+            ..self.sampler()
+        });
+    }
+
+    /// Returns the [`Sampler`] of the [`Image`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Image`]: struct.Image.html
+    pub fn sampler(&self) -> Sampler {
+        self.texture.sampler()
+    }
+
+    /// Sets the [`Sampler`] of the [`Image`].
+    ///
+    /// Like the [`WrapMode`], the [`Sampler`] is not shared between clones of
+    /// the same [`Image`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`WrapMode`]: enum.WrapMode.html
+    /// [`Image`]: struct.Image.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.texture.set_sampler(sampler);
+    }
+
     /// Draws the [`Image`] on the given [`Target`].
     ///
     /// [`Image`]: struct.Image.html
@@ -110,6 +439,57 @@ impl Image {
             ))],
         );
     }
+
+    /// Draws a portion of the [`Image`] stretched to fill the given
+    /// destination rectangle.
+    ///
+    /// The `source` is given in absolute coordinates of the [`Image`]. It is
+    /// mapped onto `destination` regardless of their aspect ratios.
+    ///
+    /// [`Image`]: struct.Image.html
+    #[inline]
+    pub fn draw_rect(
+        &self,
+        source: Rectangle<u16>,
+        destination: Rectangle<f32>,
+        target: &mut Target<'_>,
+    ) {
+        let x_unit = 1.0 / self.width() as f32;
+        let y_unit = 1.0 / self.height() as f32;
+
+        let quad = Quad {
+            position: Point::new(destination.x, destination.y),
+            size: (destination.width, destination.height),
+            ..Sprite {
+                source,
+                ..Sprite::default()
+            }
+            .into_quad(x_unit, y_unit)
+        };
+
+        self.draw(quad, target);
+    }
+
+    /// Draws a [`NineSlice`] of the [`Image`] on the given [`Target`].
+    ///
+    /// All the slices are drawn at once.
+    ///
+    /// [`NineSlice`]: struct.NineSlice.html
+    /// [`Image`]: struct.Image.html
+    /// [`Target`]: struct.Target.html
+    pub fn draw_nine_slice(
+        &self,
+        nine_slice: NineSlice,
+        target: &mut Target<'_>,
+    ) {
+        let quads: Vec<gpu::Quad> = nine_slice
+            .into_quads(1.0 / self.width() as f32, 1.0 / self.height() as f32)
+            .into_iter()
+            .map(gpu::Quad::from)
+            .collect();
+
+        target.draw_texture_quads(&self.texture, &quads);
+    }
 }
 
 impl std::fmt::Debug for Image {
@@ -122,3 +502,20 @@ impl std::fmt::Debug for Image {
         )
     }
 }
+
+fn read_image<P: AsRef<Path>>(
+    gpu: &Gpu,
+    path: P,
+) -> Result<image::DynamicImage> {
+    let buf = gpu.read_asset(path)?;
+
+    decode_image(&buf)
+}
+
+fn decode_image(buf: &[u8]) -> Result<image::DynamicImage> {
+    if let Some(image) = compressed::decode(buf) {
+        return Ok(image::DynamicImage::ImageRgba8(image?));
+    }
+
+    Ok(image::load_from_memory(buf)?)
+}
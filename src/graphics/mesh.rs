@@ -1,4 +1,6 @@
-use crate::graphics::{gpu, Color, Rectangle, Shape, Target};
+use std::f32::consts::PI;
+
+use crate::graphics::{gpu, Color, Point, Rectangle, Shape, Stroke, Target};
 
 use lyon_tessellation as lyon;
 
@@ -60,6 +62,8 @@ impl Mesh {
     /// [`Mesh`]: struct.Mesh.html
     #[inline]
     pub fn fill(&mut self, shape: Shape, color: Color) {
+        let shape = simplify(shape);
+
         let mut builder = lyon::BuffersBuilder::new(
             &mut self.buffers,
             WithColor(color.into_linear()),
@@ -79,6 +83,20 @@ impl Mesh {
                 )
                 .expect("Fill rectangle");
             }
+            Shape::RoundedRectangle { bounds, radius } => {
+                let _ = lyon::basic_shapes::fill_rounded_rectangle(
+                    &lyon::math::rect(
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                    ),
+                    &border_radii(bounds, radius),
+                    &Self::fill_options(self.tolerance),
+                    &mut builder,
+                )
+                .expect("Fill rounded rectangle");
+            }
             Shape::Circle { center, radius } => {
                 let _ = lyon::basic_shapes::fill_circle(
                     lyon::math::point(center.x, center.y),
@@ -103,7 +121,7 @@ impl Mesh {
                 )
                 .expect("Fill ellipse");
             }
-            Shape::Polyline { points } => {
+            Shape::Polyline { points } | Shape::Polygon { points } => {
                 let _ = lyon::basic_shapes::fill_polyline(
                     points
                         .iter()
@@ -114,6 +132,9 @@ impl Mesh {
                 )
                 .expect("Fill polyline");
             }
+            Shape::RegularPolygon { .. } | Shape::TriangleFan { .. } => {
+                unreachable!("Shape is simplified into a polygon")
+            }
         }
     }
 
@@ -123,6 +144,25 @@ impl Mesh {
     /// [`Mesh`]: struct.Mesh.html
     #[inline]
     pub fn stroke(&mut self, shape: Shape, color: Color, width: f32) {
+        self.stroke_with(
+            shape,
+            color,
+            Stroke {
+                width,
+                ..Stroke::default()
+            },
+        );
+    }
+
+    /// Adds the stroke of a [`Shape`] to the [`Mesh`] using the given
+    /// [`Stroke`] style.
+    ///
+    /// [`Shape`]: enum.Shape.html
+    /// [`Mesh`]: struct.Mesh.html
+    /// [`Stroke`]: struct.Stroke.html
+    pub fn stroke_with(&mut self, shape: Shape, color: Color, stroke: Stroke) {
+        let shape = simplify(shape);
+
         let mut builder = lyon::BuffersBuilder::new(
             &mut self.buffers,
             WithColor(color.into_linear()),
@@ -137,16 +177,30 @@ impl Mesh {
             }) => {
                 let _ = lyon::basic_shapes::stroke_rectangle(
                     &lyon::math::rect(x, y, rect_width, height),
-                    &Self::stroke_options(self.tolerance, width),
+                    &Self::stroke_options(self.tolerance, stroke),
                     &mut builder,
                 )
                 .expect("Stroke rectangle");
             }
+            Shape::RoundedRectangle { bounds, radius } => {
+                let _ = lyon::basic_shapes::stroke_rounded_rectangle(
+                    &lyon::math::rect(
+                        bounds.x,
+                        bounds.y,
+                        bounds.width,
+                        bounds.height,
+                    ),
+                    &border_radii(bounds, radius),
+                    &Self::stroke_options(self.tolerance, stroke),
+                    &mut builder,
+                )
+                .expect("Stroke rounded rectangle");
+            }
             Shape::Circle { center, radius } => {
                 let _ = lyon::basic_shapes::stroke_circle(
                     lyon::math::point(center.x, center.y),
                     radius,
-                    &Self::stroke_options(self.tolerance, width),
+                    &Self::stroke_options(self.tolerance, stroke),
                     &mut builder,
                 )
                 .expect("Stroke circle");
@@ -161,7 +215,7 @@ impl Mesh {
                     lyon::math::point(center.x, center.y),
                     lyon::math::vector(horizontal_radius, vertical_radius),
                     lyon::math::Angle::radians(rotation),
-                    &Self::stroke_options(self.tolerance, width),
+                    &Self::stroke_options(self.tolerance, stroke),
                     &mut builder,
                 )
                 .expect("Stroke ellipse");
@@ -172,11 +226,25 @@ impl Mesh {
                         .iter()
                         .map(|point| lyon::math::point(point.x, point.y)),
                     false,
-                    &Self::stroke_options(self.tolerance, width),
+                    &Self::stroke_options(self.tolerance, stroke),
                     &mut builder,
                 )
                 .expect("Stroke polyline");
             }
+            Shape::Polygon { points } => {
+                let _ = lyon::basic_shapes::stroke_polyline(
+                    points
+                        .iter()
+                        .map(|point| lyon::math::point(point.x, point.y)),
+                    true,
+                    &Self::stroke_options(self.tolerance, stroke),
+                    &mut builder,
+                )
+                .expect("Stroke polygon");
+            }
+            Shape::RegularPolygon { .. } | Shape::TriangleFan { .. } => {
+                unreachable!("Shape is simplified into a polygon")
+            }
         }
     }
 
@@ -185,7 +253,11 @@ impl Mesh {
     /// [`Mesh`]: struct.Mesh.html
     /// [`Target`]: struct.Target.html
     pub fn draw(&self, target: &mut Target<'_>) {
-        target.draw_triangles(&self.buffers.vertices, &self.buffers.indices);
+        target.draw_triangles(
+            None,
+            &self.buffers.vertices,
+            &self.buffers.indices,
+        );
     }
 
     fn fill_options(tolerance: f32) -> lyon::FillOptions {
@@ -194,23 +266,79 @@ impl Mesh {
             .with_normals(false)
     }
 
-    fn stroke_options(tolerance: f32, width: f32) -> lyon::StrokeOptions {
+    fn stroke_options(tolerance: f32, stroke: Stroke) -> lyon::StrokeOptions {
         lyon::StrokeOptions::DEFAULT
             .with_tolerance(tolerance)
-            .with_line_width(width)
+            .with_line_width(stroke.width)
+            .with_line_join(stroke.join.into())
+            .with_line_cap(stroke.cap.into())
+    }
+}
+
+fn simplify(shape: Shape) -> Shape {
+    match shape {
+        Shape::RoundedRectangle { bounds, radius } if radius <= 0.0 => {
+            Shape::Rectangle(bounds)
+        }
+        Shape::RegularPolygon {
+            center,
+            radius,
+            sides,
+            rotation,
+        } => {
+            let sides = sides.max(3);
+            let step = 2.0 * PI / f32::from(sides);
+
+            let points = (0..sides)
+                .map(|i| {
+                    let angle = rotation + step * f32::from(i);
+
+                    Point::new(
+                        center.x + radius * angle.cos(),
+                        center.y + radius * angle.sin(),
+                    )
+                })
+                .collect();
+
+            Shape::Polygon { points }
+        }
+        Shape::TriangleFan { center, points } => {
+            let points =
+                std::iter::once(center).chain(points.into_iter()).collect();
+
+            Shape::Polygon { points }
+        }
+        shape => shape,
     }
 }
 
+fn border_radii(
+    bounds: Rectangle<f32>,
+    radius: f32,
+) -> lyon::basic_shapes::BorderRadii {
+    let radius = radius.min(bounds.width.min(bounds.height) / 2.0);
+
+    lyon::basic_shapes::BorderRadii::new_all_same(radius)
+}
+
 struct WithColor([f32; 4]);
 
 impl lyon::VertexConstructor<lyon::FillVertex, gpu::Vertex> for WithColor {
     fn new_vertex(&mut self, vertex: lyon::FillVertex) -> gpu::Vertex {
-        gpu::Vertex::new([vertex.position.x, vertex.position.y], self.0)
+        gpu::Vertex::new(
+            [vertex.position.x, vertex.position.y],
+            [0.0, 0.0],
+            self.0,
+        )
     }
 }
 
 impl lyon::VertexConstructor<lyon::StrokeVertex, gpu::Vertex> for WithColor {
     fn new_vertex(&mut self, vertex: lyon::StrokeVertex) -> gpu::Vertex {
-        gpu::Vertex::new([vertex.position.x, vertex.position.y], self.0)
+        gpu::Vertex::new(
+            [vertex.position.x, vertex.position.y],
+            [0.0, 0.0],
+            self.0,
+        )
     }
 }
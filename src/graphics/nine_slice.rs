@@ -0,0 +1,174 @@
+use crate::graphics::{Color, Point, Quad, Rectangle};
+
+/// A portion of a resource split into nine slices, which can be stretched
+/// without distorting its borders.
+///
+/// The corners of a [`NineSlice`] are drawn unscaled, its edges are stretched
+/// along a single axis, and its center fills the remaining space. This is
+/// useful to draw panels and dialog boxes of any size using a single small
+/// image.
+///
+/// If the `destination` is smaller than the borders, they are shrunk
+/// proportionally to fit.
+///
+/// Use [`Image::draw_nine_slice`] to draw a [`NineSlice`].
+///
+/// [`NineSlice`]: struct.NineSlice.html
+/// [`Image::draw_nine_slice`]: struct.Image.html#method.draw_nine_slice
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NineSlice {
+    /// The portion of a resource that contains the slices, in absolute
+    /// coordinates.
+    pub source: Rectangle<u16>,
+
+    /// The size of the borders of the `source`, in absolute coordinates.
+    pub borders: Borders,
+
+    /// The rectangle where the slices should be drawn.
+    pub destination: Rectangle<f32>,
+
+    /// The color the slices are multiplied by.
+    pub color: Color,
+}
+
+impl Default for NineSlice {
+    fn default() -> NineSlice {
+        NineSlice {
+            source: Rectangle {
+                x: 0,
+                y: 0,
+                width: 1,
+                height: 1,
+            },
+            borders: Borders::default(),
+            destination: Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 1.0,
+                height: 1.0,
+            },
+            color: Color::WHITE,
+        }
+    }
+}
+
+/// The borders of a [`NineSlice`].
+///
+/// [`NineSlice`]: struct.NineSlice.html
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Borders {
+    /// The height of the top border.
+    pub top: u16,
+
+    /// The width of the right border.
+    pub right: u16,
+
+    /// The height of the bottom border.
+    pub bottom: u16,
+
+    /// The width of the left border.
+    pub left: u16,
+}
+
+impl Borders {
+    /// Creates new [`Borders`] with the same size on every side.
+    ///
+    /// [`Borders`]: struct.Borders.html
+    pub fn uniform(size: u16) -> Borders {
+        Borders {
+            top: size,
+            right: size,
+            bottom: size,
+            left: size,
+        }
+    }
+}
+
+impl NineSlice {
+    pub(crate) fn into_quads(self, x_unit: f32, y_unit: f32) -> Vec<Quad> {
+        let source = self.source;
+        let borders = self.borders;
+        let destination = self.destination;
+
+        let columns = slices(
+            (source.x, source.width),
+            (borders.left, borders.right),
+            (destination.x, destination.width),
+        );
+
+        let rows = slices(
+            (source.y, source.height),
+            (borders.top, borders.bottom),
+            (destination.y, destination.height),
+        );
+
+        let mut quads = Vec::with_capacity(9);
+
+        for row in rows.iter().filter(|row| row.size > 0.0) {
+            for column in columns.iter().filter(|column| column.size > 0.0) {
+                quads.push(Quad {
+                    source: Rectangle {
+                        x: column.source.0 as f32 * x_unit,
+                        y: row.source.0 as f32 * y_unit,
+                        width: column.source.1 as f32 * x_unit,
+                        height: row.source.1 as f32 * y_unit,
+                    },
+                    position: Point::new(column.position, row.position),
+                    size: (column.size, row.size),
+                    color: self.color,
+                    ..Quad::default()
+                });
+            }
+        }
+
+        quads
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Slice {
+    source: (u16, u16),
+    position: f32,
+    size: f32,
+}
+
+fn slices(
+    (start, length): (u16, u16),
+    (first, last): (u16, u16),
+    (position, size): (f32, f32),
+) -> [Slice; 3] {
+    let first = first.min(length);
+    let last = last.min(length - first);
+    let middle = length - first - last;
+
+    let borders = f32::from(first) + f32::from(last);
+
+    // Borders shrink when they do not fit in the destination
+    let scale = if borders > size && borders > 0.0 {
+        size / borders
+    } else {
+        1.0
+    };
+
+    let first_size = f32::from(first) * scale;
+    let last_size = f32::from(last) * scale;
+    let middle_size = size - first_size - last_size;
+
+    [
+        Slice {
+            source: (start, first),
+            position,
+            size: first_size,
+        },
+        Slice {
+            source: (start + first, middle),
+            position: position + first_size,
+            size: middle_size,
+        },
+        Slice {
+            source: (start + first + middle, last),
+            position: position + first_size + middle_size,
+            size: last_size,
+        },
+    ]
+}
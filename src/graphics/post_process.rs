@@ -0,0 +1,126 @@
+use crate::graphics::{BlendMode, Canvas, Point, Quad, Shader, Target};
+use crate::Result;
+
+/// A chain of full-screen effects applied to a scene.
+///
+/// A [`PostProcess`] takes a scene drawn on a [`Canvas`] and runs it through
+/// a list of [`Shader`] passes. Each pass samples the output of the previous
+/// one, and the last pass draws on the [`Target`], usually the one of a
+/// [`Frame`]. This is useful to implement effects like bloom, vignettes, or
+/// screen shake.
+///
+/// A [`PostProcess`] keeps the intermediate canvases between frames, and only
+/// recreates them when the size or the [`Format`] of the scene changes. Use
+/// the parameters of each [`Shader`] to give it uniforms, like the current
+/// time.
+///
+/// [`PostProcess`]: struct.PostProcess.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`Shader`]: struct.Shader.html
+/// [`Target`]: struct.Target.html
+/// [`Frame`]: struct.Frame.html
+/// [`Format`]: enum.Format.html
+#[derive(Debug, Default)]
+pub struct PostProcess {
+    buffers: Vec<Canvas>,
+}
+
+impl PostProcess {
+    /// Creates a new, empty [`PostProcess`].
+    ///
+    /// [`PostProcess`]: struct.PostProcess.html
+    pub fn new() -> PostProcess {
+        PostProcess {
+            buffers: Vec::new(),
+        }
+    }
+
+    /// Draws the `scene` on the given [`Target`] through the given passes.
+    ///
+    /// Intermediate passes replace the contents of a [`Canvas`] with the size
+    /// of the `scene`. The last pass covers the whole [`Target`] using its
+    /// current [`BlendMode`]. Without passes, the `scene` is drawn unchanged.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`BlendMode`]: enum.BlendMode.html
+    pub fn draw(
+        &mut self,
+        scene: &Canvas,
+        passes: &[&Shader],
+        target: &mut Target<'_>,
+    ) -> Result<()> {
+        let (width, height) = target.size();
+
+        let (last, intermediate) = match passes.split_last() {
+            Some(passes) => passes,
+            None => {
+                scene.draw(fullscreen(width, height), target);
+                return Ok(());
+            }
+        };
+
+        let buffers = intermediate.len().min(2);
+
+        for buffer in &mut self.buffers {
+            if buffer.format() == scene.format() {
+                buffer.resize(target.gpu(), scene.width(), scene.height());
+            } else {
+                *buffer = Canvas::new_with_format(
+                    target.gpu(),
+                    scene.width(),
+                    scene.height(),
+                    scene.format(),
+                )?;
+            }
+        }
+
+        while self.buffers.len() < buffers {
+            self.buffers.push(Canvas::new_with_format(
+                target.gpu(),
+                scene.width(),
+                scene.height(),
+                scene.format(),
+            )?);
+        }
+
+        for (i, shader) in intermediate.iter().enumerate() {
+            let (first, second) = self.buffers.split_at_mut(1);
+
+            // Passes alternate between both buffers
+            let (input, output) = match i {
+                0 => (scene, &mut first[0]),
+                _ if i % 2 == 1 => (&first[0], &mut second[0]),
+                _ => (&second[0], &mut first[0]),
+            };
+
+            let quad = fullscreen(
+                u32::from(output.width()),
+                u32::from(output.height()),
+            );
+
+            let mut canvas = output.as_target(target.gpu());
+            let mut canvas = canvas.with_blend_mode(BlendMode::Replace);
+
+            input.draw(quad, &mut canvas.with_shader(shader));
+        }
+
+        let input = match intermediate.len() {
+            0 => scene,
+            n if n % 2 == 1 => &self.buffers[0],
+            _ => &self.buffers[1],
+        };
+
+        input.draw(fullscreen(width, height), &mut target.with_shader(last));
+
+        Ok(())
+    }
+}
+
+fn fullscreen(width: u32, height: u32) -> Quad {
+    Quad {
+        position: Point::new(0.0, 0.0),
+        size: (width as f32, height as f32),
+        ..Quad::default()
+    }
+}
@@ -1,11 +1,23 @@
+use crate::graphics::color::Color;
 use crate::graphics::point::Point;
 use crate::graphics::rectangle::Rectangle;
 
 /// A textured quad.
+///
+/// Use [`IntoQuad::flip_x`] and [`IntoQuad::flip_y`] to mirror a [`Quad`].
+///
+/// [`IntoQuad::flip_x`]: trait.IntoQuad.html#method.flip_x
+/// [`IntoQuad::flip_y`]: trait.IntoQuad.html#method.flip_y
+/// [`Quad`]: struct.Quad.html
 #[derive(Debug, PartialEq, Clone)]
 pub struct Quad {
     /// The region of the resource that should be shown on the quad, in relative
     /// coordinates: [0.0, 1.0].
+    ///
+    /// Coordinates outside of this range are sampled according to the
+    /// [`WrapMode`] of the resource.
+    ///
+    /// [`WrapMode`]: enum.WrapMode.html
     pub source: Rectangle<f32>,
 
     /// The position where the quad should be drawn.
@@ -13,6 +25,31 @@ pub struct Quad {
 
     /// The size of the quad.
     pub size: (f32, f32),
+
+    /// The rotation of the quad around its `origin`, in radians.
+    ///
+    /// Positive angles rotate the quad clockwise.
+    pub rotation: f32,
+
+    /// The point, relative to the top-left corner of the quad, that stays
+    /// fixed when rotating it.
+    pub origin: Point,
+
+    /// The color the texture of the quad is multiplied by.
+    ///
+    /// Use [`Color::WHITE`] to draw the texture unchanged. The alpha component
+    /// can be used to fade the quad out.
+    ///
+    /// [`Color::WHITE`]: struct.Color.html#associatedconstant.WHITE
+    pub color: Color,
+
+    /// The depth of the quad.
+    ///
+    /// Quads with a lower depth are drawn first when using
+    /// [`Batch::draw_sorted`]. Otherwise, it is ignored.
+    ///
+    /// [`Batch::draw_sorted`]: struct.Batch.html#method.draw_sorted
+    pub depth: f32,
 }
 
 impl Default for Quad {
@@ -26,10 +63,37 @@ impl Default for Quad {
             },
             position: Point::new(0.0, 0.0),
             size: (1.0, 1.0),
+            rotation: 0.0,
+            origin: Point::new(0.0, 0.0),
+            color: Color::WHITE,
+            depth: 0.0,
         }
     }
 }
 
+impl Quad {
+    /// Returns the column-major linear transformation and the translation that
+    /// map the unit square onto the [`Quad`].
+    ///
+    /// [`Quad`]: struct.Quad.html
+    pub(crate) fn instance_transform(&self) -> ([f32; 4], [f32; 2]) {
+        let (width, height) = self.size;
+        let (sin, cos) = self.rotation.sin_cos();
+        let origin = self.origin;
+
+        // The origin is rotated and moved back, so it stays in place
+        let translation = [
+            self.position.x + origin.x - (origin.x * cos - origin.y * sin),
+            self.position.y + origin.y - (origin.x * sin + origin.y * cos),
+        ];
+
+        (
+            [width * cos, width * sin, -height * sin, height * cos],
+            translation,
+        )
+    }
+}
+
 /// Turn a type into a quad.
 ///
 /// Most methods accept generic types that can be turned into quads. This allows
@@ -43,6 +107,44 @@ pub trait IntoQuad {
     ///
     /// [`source`]: struct.Quad.html#structfield.source
     fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad;
+
+    /// Mirrors the produced [`Quad`] horizontally.
+    ///
+    /// Only the [`source`] region is mirrored, so you can flip a single frame
+    /// of a sprite sheet. It simply swaps the texture coordinates and has no
+    /// additional cost.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    /// [`source`]: struct.Quad.html#structfield.source
+    fn flip_x(self) -> Flip<Self>
+    where
+        Self: Sized,
+    {
+        Flip {
+            quad: self,
+            x: true,
+            y: false,
+        }
+    }
+
+    /// Mirrors the produced [`Quad`] vertically.
+    ///
+    /// Only the [`source`] region is mirrored, so you can flip a single frame
+    /// of a sprite sheet. It simply swaps the texture coordinates and has no
+    /// additional cost.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    /// [`source`]: struct.Quad.html#structfield.source
+    fn flip_y(self) -> Flip<Self>
+    where
+        Self: Sized,
+    {
+        Flip {
+            quad: self,
+            x: false,
+            y: true,
+        }
+    }
 }
 
 impl IntoQuad for Quad {
@@ -50,3 +152,34 @@ impl IntoQuad for Quad {
         self
     }
 }
+
+/// A mirrored quad.
+///
+/// It is produced by [`IntoQuad::flip_x`] and [`IntoQuad::flip_y`].
+///
+/// [`IntoQuad::flip_x`]: trait.IntoQuad.html#method.flip_x
+/// [`IntoQuad::flip_y`]: trait.IntoQuad.html#method.flip_y
+#[derive(Debug, PartialEq, Clone)]
+pub struct Flip<Q> {
+    quad: Q,
+    x: bool,
+    y: bool,
+}
+
+impl<Q: IntoQuad> IntoQuad for Flip<Q> {
+    fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        let mut quad = self.quad.into_quad(x_unit, y_unit);
+
+        if self.x {
+            quad.source.x += quad.source.width;
+            quad.source.width = -quad.source.width;
+        }
+
+        if self.y {
+            quad.source.y += quad.source.height;
+            quad.source.height = -quad.source.height;
+        }
+
+        quad
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::graphics::Window;
+use crate::Result;
+
+/// A recorder of the last frames presented in a [`Window`].
+///
+/// A [`Recorder`] keeps the frames of the last few seconds in memory. Use it
+/// to implement a "clip that!" feature or to capture a trailer, and save the
+/// frames as a PNG sequence with [`save_png_sequence`] or as a video with
+/// [`save_video`].
+///
+/// Recording is opt-in: call [`capture`] once per frame, usually in
+/// [`Game::interact`]. It uses [`Window::request_screenshot`] under the hood,
+/// so you should not take screenshots yourself while recording.
+///
+/// _Note:_ Every captured frame is read from the GPU and kept uncompressed.
+/// This is slow and uses a lot of memory, so keep the duration and the frame
+/// rate of your recordings low.
+///
+/// [`Window`]: struct.Window.html
+/// [`Recorder`]: struct.Recorder.html
+/// [`save_png_sequence`]: #method.save_png_sequence
+/// [`save_video`]: #method.save_video
+/// [`capture`]: #method.capture
+/// [`Game::interact`]: ../trait.Game.html#method.interact
+/// [`Window::request_screenshot`]: struct.Window.html#method.request_screenshot
+#[derive(Debug)]
+pub struct Recorder {
+    frames: VecDeque<image::DynamicImage>,
+    capacity: usize,
+    frames_per_second: u16,
+    last_capture: Option<Instant>,
+}
+
+impl Recorder {
+    /// Creates a new [`Recorder`] that keeps the last given seconds of
+    /// frames, captured at the given frame rate.
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn new(seconds: u16, frames_per_second: u16) -> Recorder {
+        let frames_per_second = frames_per_second.max(1);
+        let capacity =
+            (usize::from(seconds) * usize::from(frames_per_second)).max(1);
+
+        Recorder {
+            frames: VecDeque::with_capacity(capacity),
+            capacity,
+            frames_per_second,
+            last_capture: None,
+        }
+    }
+
+    /// Captures the frames of the given [`Window`].
+    ///
+    /// Call it once per frame. The last presented frame is stored, if it was
+    /// requested, and the next one is requested when it is time to capture a
+    /// new frame. Once the [`Recorder`] is full, the oldest frame is dropped.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn capture(&mut self, window: &mut Window) {
+        if let Some(frame) = window.take_screenshot() {
+            if self.frames.len() == self.capacity {
+                let _ = self.frames.pop_front();
+            }
+
+            self.frames.push_back(frame);
+        }
+
+        let now = Instant::now();
+        let interval =
+            Duration::from_secs(1) / u32::from(self.frames_per_second);
+
+        let is_time_to_capture = match self.last_capture {
+            Some(last_capture) => now - last_capture >= interval,
+            None => true,
+        };
+
+        if is_time_to_capture {
+            window.request_screenshot();
+            self.last_capture = Some(now);
+        }
+    }
+
+    /// Returns the amount of frames stored in the [`Recorder`].
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns true if the [`Recorder`] has no frames.
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns the frame rate of the [`Recorder`].
+    ///
+    /// [`Recorder`]: struct.Recorder.html
+    pub fn frames_per_second(&self) -> u16 {
+        self.frames_per_second
+    }
+
+    /// Returns an iterator over the stored frames, from oldest to newest.
+    pub fn frames(&self) -> impl Iterator<Item = &image::DynamicImage> {
+        self.frames.iter()
+    }
+
+    /// Drops all the stored frames.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Saves the stored frames as a sequence of PNG files in the given
+    /// directory, from `frame_00000.png` onwards.
+    ///
+    /// The directory is created if it does not exist.
+    pub fn save_png_sequence<P: AsRef<Path>>(
+        &self,
+        directory: P,
+    ) -> Result<()> {
+        let directory = directory.as_ref();
+
+        std::fs::create_dir_all(directory)?;
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            frame.save(directory.join(format!("frame_{:05}.png", i)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the stored frames as a video file at the given path using
+    /// `ffmpeg`.
+    ///
+    /// The format of the video is deduced by `ffmpeg` from the extension of
+    /// the path. The `ffmpeg` executable must be available in the `PATH`.
+    /// Frames with a different size than the first one, which are captured
+    /// when the window is resized, are skipped.
+    pub fn save_video<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let (width, height) = match self.frames.front() {
+            Some(frame) => {
+                let rgba = frame.to_rgba();
+
+                (rgba.width(), rgba.height())
+            }
+            None => return Ok(()),
+        };
+
+        let mut ffmpeg = Command::new("ffmpeg")
+            .args(&["-y", "-loglevel", "error", "-f", "rawvideo"])
+            .args(&["-pix_fmt", "rgba"])
+            .arg("-s")
+            .arg(format!("{}x{}", width, height))
+            .arg("-r")
+            .arg(self.frames_per_second.to_string())
+            .args(&["-i", "-", "-pix_fmt", "yuv420p"])
+            .arg(path.as_ref())
+            .stdin(Stdio::piped())
+            .spawn()?;
+
+        // `ffmpeg` stops reading once its input is closed, which happens when
+        // `stdin` is dropped
+        if let Some(mut stdin) = ffmpeg.stdin.take() {
+            for frame in &self.frames {
+                let rgba = frame.to_rgba();
+
+                if rgba.dimensions() == (width, height) {
+                    stdin.write_all(&rgba.into_raw())?;
+                }
+            }
+        }
+
+        let status = ffmpeg.wait()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::Other,
+                format!("ffmpeg failed with {}", status),
+            )
+            .into())
+        }
+    }
+}
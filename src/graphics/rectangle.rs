@@ -39,3 +39,75 @@ impl Rectangle<f32> {
         )
     }
 }
+
+impl Rectangle<u32> {
+    /// Returns the intersection of two [`Rectangle`]s, if they overlap.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn intersection(
+        &self,
+        other: &Rectangle<u32>,
+    ) -> Option<Rectangle<u32>> {
+        let left = self.x.max(other.x);
+        let top = self.y.max(other.y);
+        let right = self
+            .x
+            .saturating_add(self.width)
+            .min(other.x.saturating_add(other.width));
+        let bottom = self
+            .y
+            .saturating_add(self.height)
+            .min(other.y.saturating_add(other.height));
+
+        if left < right && top < bottom {
+            Some(Rectangle {
+                x: left,
+                y: top,
+                width: right - left,
+                height: bottom - top,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rectangle(x: u32, y: u32, width: u32, height: u32) -> Rectangle<u32> {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn intersection_of_overlapping_rectangles() {
+        assert_eq!(
+            rectangle(0, 0, 10, 10).intersection(&rectangle(5, 2, 10, 4)),
+            Some(rectangle(5, 2, 5, 4))
+        );
+    }
+
+    #[test]
+    fn intersection_of_disjoint_rectangles() {
+        assert_eq!(
+            rectangle(0, 0, 10, 10).intersection(&rectangle(10, 0, 5, 5)),
+            None
+        );
+    }
+
+    #[test]
+    fn intersection_of_huge_rectangles() {
+        let huge = rectangle(u32::MAX - 5, 3, u32::MAX, u32::MAX);
+
+        assert_eq!(
+            huge.intersection(&rectangle(0, 0, u32::MAX, 10)),
+            Some(rectangle(u32::MAX - 5, 3, 5, 7))
+        );
+    }
+}
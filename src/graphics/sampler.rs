@@ -0,0 +1,66 @@
+use crate::graphics::WrapMode;
+
+/// The way the pixels of a texture are sampled when it is drawn.
+///
+/// You can choose the [`Sampler`] of an [`Image`] when loading it with
+/// [`Image::new_with_sampler`], or change it later with
+/// [`Image::set_sampler`]. A [`TextureArray`] can use one too.
+///
+/// The default [`Sampler`] uses [`Filter::Nearest`] and [`WrapMode::Clamp`].
+///
+/// [`Sampler`]: struct.Sampler.html
+/// [`Image`]: struct.Image.html
+/// [`Image::new_with_sampler`]: struct.Image.html#method.new_with_sampler
+/// [`Image::set_sampler`]: struct.Image.html#method.set_sampler
+/// [`TextureArray`]: texture_array/struct.TextureArray.html
+/// [`Filter::Nearest`]: enum.Filter.html#variant.Nearest
+/// [`WrapMode::Clamp`]: enum.WrapMode.html#variant.Clamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Sampler {
+    /// The [`Filter`] used when the texture is scaled.
+    ///
+    /// [`Filter`]: enum.Filter.html
+    pub filter: Filter,
+
+    /// The [`WrapMode`] used outside of the `[0.0, 1.0]` coordinates.
+    ///
+    /// [`WrapMode`]: enum.WrapMode.html
+    pub wrap_mode: WrapMode,
+}
+
+impl Sampler {
+    /// Returns every possible [`Sampler`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    pub(crate) fn all() -> impl Iterator<Item = Sampler> {
+        [Filter::Nearest, Filter::Linear]
+            .iter()
+            .flat_map(|&filter| {
+                [WrapMode::Clamp, WrapMode::Repeat, WrapMode::Mirror]
+                    .iter()
+                    .map(move |&wrap_mode| Sampler { filter, wrap_mode })
+            })
+    }
+}
+
+/// The way the pixels of a texture are combined when it is scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Filter {
+    /// The nearest pixel is used, keeping hard edges.
+    ///
+    /// This is the default filter. It is the best choice for pixel art.
+    Nearest,
+
+    /// The nearest pixels are interpolated, smoothing the texture.
+    ///
+    /// The pixels next to a sprite may bleed into it when drawing a portion
+    /// of a sprite sheet. Leave some transparent space between sprites to
+    /// avoid it.
+    Linear,
+}
+
+impl Default for Filter {
+    fn default() -> Filter {
+        Filter::Nearest
+    }
+}
@@ -0,0 +1,184 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::graphics::{gpu, Gpu};
+use crate::load::Task;
+use crate::Result;
+
+/// A custom fragment shader.
+///
+/// A [`Shader`] replaces the built-in fragment shader used to draw textured
+/// quads. Use [`Target::with_shader`] to draw an [`Image`], a [`Batch`], or a
+/// [`Canvas`] with it. This allows you to implement effects like palette swaps
+/// or screen distortion.
+///
+/// Every [`Shader`] has 4 parameters that you can change with
+/// [`Shader::set_parameters`] between draws, like the time or the strength of
+/// an effect.
+///
+/// The format of a shader depends on the graphics backend:
+///
+/// - With `opengl`, it is GLSL `150 core` source. The fragment shader receives
+///   `in vec2 v_Uv`, `flat in uint v_Layer`, and `in vec4 v_Color`, can sample
+///   `uniform sampler2DArray t_Texture`, and must write `out vec4 Target0`.
+///   Its parameters are available in
+///   `layout (std140) uniform Parameters { vec4 u_Parameters; }`.
+/// - With any `wgpu` backend, it is compiled SPIR-V. The fragment shader
+///   receives `v_Uv` at location 0, `v_Layer` at location 1, and `v_Color` at
+///   location 2, can sample `u_Texture` (set 1, binding 0) with `u_Sampler`
+///   (set 0, binding 1), and must write its color to location 0. Its
+///   parameters are available in a `vec4` uniform block at set 2, binding 0.
+///
+/// Take a look at the built-in `quad.frag` shaders of each backend for an
+/// example.
+///
+/// In debug builds, a [`Shader`] loaded from a path can be recompiled when
+/// its file changes with [`Shader::hot_reload`].
+///
+/// [`Shader`]: struct.Shader.html
+/// [`Shader::set_parameters`]: struct.Shader.html#method.set_parameters
+/// [`Shader::hot_reload`]: struct.Shader.html#method.hot_reload
+/// [`Target::with_shader`]: struct.Target.html#method.with_shader
+/// [`Image`]: struct.Image.html
+/// [`Batch`]: struct.Batch.html
+/// [`Canvas`]: struct.Canvas.html
+pub struct Shader {
+    pub(super) shader: gpu::Shader,
+    source: Option<Source>,
+}
+
+struct Source {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+}
+
+impl Shader {
+    /// Loads a [`Shader`] from the given path.
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Shader> {
+        let path = path.as_ref();
+        let modified = gpu.asset_modified(path);
+        let bytes = gpu.read_asset(path)?;
+
+        let mut shader = Shader::from_bytes(gpu, &bytes)?;
+
+        shader.source = Some(Source {
+            path: path.to_path_buf(),
+            modified,
+        });
+
+        Ok(shader)
+    }
+
+    /// Creates a [`Task`] that loads a [`Shader`] from the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn load<P: Into<PathBuf>>(path: P) -> Task<Shader> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| Shader::new(gpu, &p))
+    }
+
+    /// Creates a [`Shader`] from its source bytes.
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub fn from_bytes(gpu: &mut Gpu, bytes: &[u8]) -> Result<Shader> {
+        let shader = gpu.create_shader(bytes)?;
+
+        Ok(Shader {
+            shader,
+            source: None,
+        })
+    }
+
+    /// Recompiles the [`Shader`] if its file has changed since it was loaded.
+    ///
+    /// Call it every frame while iterating on an effect to see your changes
+    /// without restarting your game. The parameters of the [`Shader`] are
+    /// kept. Returns whether the [`Shader`] was reloaded.
+    ///
+    /// If the new version fails to compile, the previous one keeps being used
+    /// and the error is returned. The debug overlay shows the last error
+    /// until a [`Shader`] is reloaded successfully.
+    ///
+    /// Hot reloading only works in debug builds or with the `debug` feature,
+    /// for a [`Shader`] created with [`Shader::new`] or [`Shader::load`] and
+    /// an [`AssetSource`] that implements [`AssetSource::modified`], like the
+    /// default [`Filesystem`]. Otherwise, it does nothing.
+    ///
+    /// [`Shader`]: struct.Shader.html
+    /// [`Shader::new`]: struct.Shader.html#method.new
+    /// [`Shader::load`]: struct.Shader.html#method.load
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    /// [`AssetSource::modified`]: ../load/trait.AssetSource.html#method.modified
+    /// [`Filesystem`]: ../load/struct.Filesystem.html
+    pub fn hot_reload(&mut self, gpu: &mut Gpu) -> Result<bool> {
+        if !cfg!(any(debug_assertions, feature = "debug")) {
+            return Ok(false);
+        }
+
+        let source = match &mut self.source {
+            Some(source) => source,
+            None => return Ok(false),
+        };
+
+        let modified = gpu.asset_modified(&source.path);
+
+        if modified.is_none() || modified == source.modified {
+            return Ok(false);
+        }
+
+        source.modified = modified;
+
+        let bytes = gpu.read_asset(&source.path)?;
+
+        match gpu.create_shader(&bytes) {
+            Ok(mut shader) => {
+                shader.set_parameters(self.shader.parameters());
+
+                self.shader = shader;
+                gpu.set_shader_error(None);
+
+                Ok(true)
+            }
+            Err(error) => {
+                gpu.set_shader_error(Some(format!(
+                    "{}: {}",
+                    source.path.display(),
+                    error
+                )));
+
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns the current parameters of the [`Shader`].
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub fn parameters(&self) -> [f32; 4] {
+        self.shader.parameters()
+    }
+
+    /// Sets the parameters of the [`Shader`].
+    ///
+    /// They are used by every draw with the [`Shader`] until they change
+    /// again. By default, they are all zero.
+    ///
+    /// [`Shader`]: struct.Shader.html
+    pub fn set_parameters(&mut self, parameters: [f32; 4]) {
+        self.shader.set_parameters(parameters);
+    }
+}
+
+impl std::fmt::Debug for Shader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Shader")
+    }
+}
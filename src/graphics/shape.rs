@@ -6,6 +6,18 @@ pub enum Shape {
     /// A rectangle
     Rectangle(Rectangle<f32>),
 
+    /// A rectangle with rounded corners
+    ///
+    /// The radius is clamped to half the smallest side of the rectangle. A
+    /// radius of `0.0` produces a plain rectangle.
+    RoundedRectangle {
+        /// The bounds of the rectangle
+        bounds: Rectangle<f32>,
+
+        /// The radius of the corners
+        radius: f32,
+    },
+
     /// A circle
     Circle {
         /// The center of the circle
@@ -35,4 +47,44 @@ pub enum Shape {
         /// The points of the polyline
         points: Vec<Point>,
     },
+
+    /// A closed polygon
+    ///
+    /// Its stroke joins the last point with the first one.
+    Polygon {
+        /// The points of the polygon
+        points: Vec<Point>,
+    },
+
+    /// A regular polygon, like a triangle, a hexagon, or an octagon
+    RegularPolygon {
+        /// The center of the polygon
+        center: Point,
+
+        /// The distance from the center to every vertex
+        radius: f32,
+
+        /// The number of sides of the polygon
+        ///
+        /// It is clamped to a minimum of `3`.
+        sides: u16,
+
+        /// The rotation of the polygon in radians
+        rotation: f32,
+    },
+
+    /// A triangle fan
+    ///
+    /// Every pair of consecutive points forms a triangle with the center. It
+    /// is drawn as a [`Polygon`] starting at the center, which means the
+    /// points should be sorted around it.
+    ///
+    /// [`Polygon`]: #variant.Polygon
+    TriangleFan {
+        /// The shared vertex of every triangle
+        center: Point,
+
+        /// The outer points of the fan
+        points: Vec<Point>,
+    },
 }
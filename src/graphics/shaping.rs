@@ -0,0 +1,200 @@
+use crate::graphics::{HorizontalAlignment, Point, VerticalAlignment};
+
+/// A run of text with the same font face and size.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Run<'a> {
+    /// The text of the run
+    pub text: &'a str,
+
+    /// The raw data of the font face of the run
+    pub face: &'a [u8],
+
+    /// The amount of pixels per font unit
+    pub scale: f32,
+
+    /// The distance from the top of a line to its baseline, in pixels
+    pub ascent: f32,
+
+    /// The height of a line, in pixels
+    pub line_height: f32,
+}
+
+/// A shaped glyph.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Glyph {
+    /// The index of the `Run` the glyph belongs to
+    pub run: usize,
+
+    /// The identifier of the glyph in the font face of its `Run`
+    pub id: u16,
+
+    /// The position of the glyph baseline, in pixels
+    pub position: Point,
+}
+
+#[derive(Debug, Default)]
+struct Line {
+    glyphs: Vec<Glyph>,
+    width: f32,
+    visible_width: f32,
+    ascent: f32,
+    height: f32,
+}
+
+/// Shapes and positions the glyphs of the given runs using `rustybuzz`.
+///
+/// The glyph caches of the graphics backends lay out text one character at a
+/// time, which breaks scripts that need glyph substitution or are written
+/// from right to left. When the `shaping` feature is enabled, they use this
+/// layout instead.
+///
+/// The `position` is the point the text is aligned to, which lies on the
+/// edges or the center of its `bounds` depending on the alignment. Lines are
+/// broken on newlines and wrapped between words when they are wider than the
+/// `bounds`.
+///
+/// Every word is shaped on its own, guessing its script and direction from
+/// its contents. Words are laid out in logical order. Therefore, a line
+/// mixing left-to-right and right-to-left words will not be reordered.
+pub(crate) fn layout(
+    runs: &[Run<'_>],
+    position: (f32, f32),
+    bounds: (f32, f32),
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+) -> Vec<Glyph> {
+    let mut lines = vec![Line::default()];
+
+    for (index, run) in runs.iter().enumerate() {
+        let face = rustybuzz::Face::from_slice(run.face, 0);
+
+        for (i, text) in run.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Line::default());
+            }
+
+            let line = lines.last_mut().expect("Last line");
+            line.ascent = line.ascent.max(run.ascent);
+            line.height = line.height.max(run.line_height);
+
+            let face = match &face {
+                Some(face) if !text.is_empty() => face,
+                _ => continue,
+            };
+
+            for word in words(text) {
+                let mut buffer = rustybuzz::UnicodeBuffer::new();
+                buffer.push_str(word);
+                buffer.guess_segment_properties();
+
+                let shaped = rustybuzz::shape(face, &[], buffer);
+                let visible_length = word.trim_end().len();
+
+                let visible_width: f32 = shaped
+                    .glyph_infos()
+                    .iter()
+                    .zip(shaped.glyph_positions())
+                    .filter(|(info, _)| {
+                        (info.cluster as usize) < visible_length
+                    })
+                    .map(|(_, glyph)| glyph.x_advance as f32 * run.scale)
+                    .sum();
+
+                let wraps = {
+                    let line = lines.last().expect("Last line");
+
+                    line.width > 0.0 && line.width + visible_width > bounds.0
+                };
+
+                if wraps {
+                    lines.push(Line {
+                        ascent: run.ascent,
+                        height: run.line_height,
+                        ..Line::default()
+                    });
+                }
+
+                let line = lines.last_mut().expect("Last line");
+
+                if visible_length > 0 {
+                    line.visible_width = line.width + visible_width;
+                }
+
+                for (info, glyph_position) in
+                    shaped.glyph_infos().iter().zip(shaped.glyph_positions())
+                {
+                    line.glyphs.push(Glyph {
+                        run: index,
+                        id: info.glyph_id as u16,
+                        position: Point::new(
+                            line.width
+                                + glyph_position.x_offset as f32 * run.scale,
+                            -glyph_position.y_offset as f32 * run.scale,
+                        ),
+                    });
+
+                    line.width += glyph_position.x_advance as f32 * run.scale;
+                }
+            }
+        }
+    }
+
+    let height: f32 = lines.iter().map(|line| line.height).sum();
+
+    let mut y = match vertical_alignment {
+        VerticalAlignment::Top => position.1,
+        VerticalAlignment::Center => position.1 - height / 2.0,
+        VerticalAlignment::Bottom => position.1 - height,
+    };
+
+    let mut glyphs = Vec::new();
+
+    for line in lines {
+        // Trailing whitespace is ignored when aligning a line
+        let x = match horizontal_alignment {
+            HorizontalAlignment::Left => position.0,
+            HorizontalAlignment::Center => {
+                position.0 - line.visible_width / 2.0
+            }
+            HorizontalAlignment::Right => position.0 - line.visible_width,
+        };
+
+        let baseline = y + line.ascent;
+
+        glyphs.extend(line.glyphs.into_iter().map(|glyph| Glyph {
+            position: Point::new(
+                x + glyph.position.x,
+                baseline + glyph.position.y,
+            ),
+            ..glyph
+        }));
+
+        y += line.height;
+    }
+
+    glyphs
+}
+
+/// Splits the given text in words, keeping the whitespace that follows each
+/// of them.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let word_end =
+            rest.find(char::is_whitespace).unwrap_or_else(|| rest.len());
+
+        let end = rest[word_end..]
+            .find(|c: char| !c.is_whitespace())
+            .map_or(rest.len(), |length| word_end + length);
+
+        let (word, remaining) = rest.split_at(end);
+        rest = remaining;
+
+        Some(word)
+    })
+}
@@ -1,12 +1,17 @@
-use crate::graphics::{IntoQuad, Point, Quad, Rectangle};
+use crate::graphics::{Color, IntoQuad, Point, Quad, Rectangle};
 
 /// A quad describing the portion of a resource in absolute coordinates.
 ///
 /// Unlike a [`Quad`], the `source` coordinates of a [`Sprite`] are absolute. It
 /// can be used as a convenient alternative.
 ///
+/// Use [`IntoQuad::flip_x`] and [`IntoQuad::flip_y`] to mirror a [`Sprite`],
+/// like when a character changes its facing direction.
+///
 /// [`Quad`]: struct.Quad.html
 /// [`Sprite`]: struct.Sprite.html
+/// [`IntoQuad::flip_x`]: trait.IntoQuad.html#method.flip_x
+/// [`IntoQuad::flip_y`]: trait.IntoQuad.html#method.flip_y
 #[derive(Debug, PartialEq, Clone)]
 pub struct Sprite {
     /// The portion of a resource that contains the sprite, in absolute
@@ -18,6 +23,30 @@ pub struct Sprite {
 
     /// The scale to apply to the sprite.
     pub scale: (f32, f32),
+
+    /// The rotation of the sprite around its `origin`, in radians.
+    ///
+    /// Positive angles rotate the sprite clockwise.
+    pub rotation: f32,
+
+    /// The point, relative to the top-left corner of the sprite and before
+    /// scaling, that stays fixed when rotating it.
+    pub origin: Point,
+
+    /// The color the sprite is multiplied by.
+    ///
+    /// Use [`Color::WHITE`] to draw the sprite unchanged.
+    ///
+    /// [`Color::WHITE`]: struct.Color.html#associatedconstant.WHITE
+    pub color: Color,
+
+    /// The depth of the sprite.
+    ///
+    /// Sprites with a lower depth are drawn first when using
+    /// [`Batch::draw_sorted`]. Otherwise, it is ignored.
+    ///
+    /// [`Batch::draw_sorted`]: struct.Batch.html#method.draw_sorted
+    pub depth: f32,
 }
 
 impl Default for Sprite {
@@ -32,6 +61,10 @@ impl Default for Sprite {
             },
             position: Point::new(0.0, 0.0),
             scale: (1.0, 1.0),
+            rotation: 0.0,
+            origin: Point::new(0.0, 0.0),
+            color: Color::WHITE,
+            depth: 0.0,
         }
     }
 }
@@ -50,6 +83,13 @@ impl IntoQuad for Sprite {
                 self.source.width as f32 * self.scale.0,
                 self.source.height as f32 * self.scale.1,
             ),
+            rotation: self.rotation,
+            origin: Point::new(
+                self.origin.x * self.scale.0,
+                self.origin.y * self.scale.1,
+            ),
+            color: self.color,
+            depth: self.depth,
         }
     }
 }
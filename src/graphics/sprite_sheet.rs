@@ -0,0 +1,148 @@
+//! Load and use packed sprite sheets.
+use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::graphics::{Image, Point, Rectangle, Sprite};
+use crate::load::Task;
+use crate::Result;
+
+/// An [`Image`] containing multiple named sprites.
+///
+/// A [`SpriteSheet`] maps names to the regions of the [`Image`] that contain
+/// each sprite. It can be used to avoid hardcoding rectangles in your game.
+///
+/// Cloning a [`SpriteSheet`] clones its regions, but the underlying
+/// [`Image`] is shared.
+///
+/// [`Image`]: ../struct.Image.html
+/// [`SpriteSheet`]: struct.SpriteSheet.html
+#[derive(Debug, Clone)]
+pub struct SpriteSheet {
+    image: Image,
+    regions: HashMap<String, Rectangle<u16>>,
+}
+
+impl SpriteSheet {
+    /// Creates a new [`SpriteSheet`] from an [`Image`] and the named regions
+    /// of its sprites.
+    ///
+    /// Every region must fit within the bounds of the [`Image`].
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [`Image`]: ../struct.Image.html
+    pub fn new(
+        image: Image,
+        regions: HashMap<String, Rectangle<u16>>,
+    ) -> Result<SpriteSheet> {
+        let width = u32::from(image.width());
+        let height = u32::from(image.height());
+
+        for (name, region) in &regions {
+            let right = u32::from(region.x) + u32::from(region.width);
+            let bottom = u32::from(region.y) + u32::from(region.height);
+
+            if right > width || bottom > height {
+                return Err(crate::Error::SpriteSheet(
+                    Error::RegionOutOfBounds {
+                        name: name.clone(),
+                        region: *region,
+                    },
+                ));
+            }
+        }
+
+        Ok(SpriteSheet { image, regions })
+    }
+
+    /// Creates a [`Task`] that loads a [`SpriteSheet`] from the image in the
+    /// given path and the named regions of its sprites.
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn load<P: Into<PathBuf>>(
+        path: P,
+        regions: HashMap<String, Rectangle<u16>>,
+    ) -> Task<SpriteSheet> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| {
+            SpriteSheet::new(Image::new(gpu, &path)?, regions)
+        })
+    }
+
+    /// Returns the [`Image`] shared by all the sprites of the
+    /// [`SpriteSheet`].
+    ///
+    /// [`Image`]: ../struct.Image.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the region of the sprite with the given name, if it exists.
+    pub fn source(&self, name: &str) -> Option<Rectangle<u16>> {
+        self.regions.get(name).cloned()
+    }
+
+    /// Returns a [`Sprite`] ready to be drawn for the given name, if it
+    /// exists.
+    ///
+    /// The returned [`Sprite`] is positioned at the origin. Use it with the
+    /// [`Image`] of the [`SpriteSheet`].
+    ///
+    /// [`Sprite`]: ../struct.Sprite.html
+    /// [`Image`]: ../struct.Image.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn sprite(&self, name: &str) -> Option<Sprite> {
+        self.source(name).map(|source| Sprite {
+            source,
+            position: Point::new(0.0, 0.0),
+            ..Sprite::default()
+        })
+    }
+
+    /// Returns an iterator over the names of the sprites in the
+    /// [`SpriteSheet`].
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.regions.keys().map(String::as_str)
+    }
+}
+
+/// A sprite sheet loading error.
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// A region does not fit within the bounds of the sprite sheet image.
+    RegionOutOfBounds {
+        /// The name of the sprite
+        name: String,
+
+        /// The region of the sprite
+        region: Rectangle<u16>,
+    },
+
+    /// An image did not fit in the maximum size of an [`AtlasBuilder`].
+    ///
+    /// [`AtlasBuilder`]: ../struct.AtlasBuilder.html
+    AtlasIsFull {
+        /// The name of the image
+        name: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::RegionOutOfBounds { name, region } => write!(
+                f,
+                "Region of \"{}\" is out of bounds: {:?}",
+                name, region
+            ),
+            Error::AtlasIsFull { name } => {
+                write!(f, "Image \"{}\" does not fit in the atlas", name)
+            }
+        }
+    }
+}
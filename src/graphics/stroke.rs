@@ -0,0 +1,76 @@
+use lyon_tessellation as lyon;
+
+/// The style of the stroke of a [`Shape`].
+///
+/// Use it with [`Mesh::stroke_with`] to control how the segments of a line are
+/// joined and how its ends look.
+///
+/// [`Shape`]: enum.Shape.html
+/// [`Mesh::stroke_with`]: struct.Mesh.html#method.stroke_with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stroke {
+    /// The width of the line.
+    pub width: f32,
+
+    /// The shape of the corners where two segments meet.
+    pub join: LineJoin,
+
+    /// The shape of both ends of an open line.
+    pub cap: LineCap,
+}
+
+impl Default for Stroke {
+    fn default() -> Stroke {
+        Stroke {
+            width: 1.0,
+            join: LineJoin::Miter,
+            cap: LineCap::Butt,
+        }
+    }
+}
+
+/// The shape of the corners where two segments of a line meet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    /// A sharp corner.
+    Miter,
+
+    /// A rounded corner.
+    Round,
+
+    /// A corner cut by a straight line.
+    Bevel,
+}
+
+/// The shape of the ends of an open line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    /// The line stops exactly at its end points.
+    Butt,
+
+    /// The line is extended past its end points by half its width.
+    Square,
+
+    /// The line ends with a semicircle around its end points.
+    Round,
+}
+
+impl From<LineJoin> for lyon::LineJoin {
+    fn from(join: LineJoin) -> lyon::LineJoin {
+        match join {
+            LineJoin::Miter => lyon::LineJoin::Miter,
+            LineJoin::Round => lyon::LineJoin::Round,
+            LineJoin::Bevel => lyon::LineJoin::Bevel,
+        }
+    }
+}
+
+impl From<LineCap> for lyon::LineCap {
+    fn from(cap: LineCap) -> lyon::LineCap {
+        match cap {
+            LineCap::Butt => lyon::LineCap::Butt,
+            LineCap::Square => lyon::LineCap::Square,
+            LineCap::Round => lyon::LineCap::Round,
+        }
+    }
+}
@@ -1,5 +1,11 @@
-use crate::graphics::gpu::{self, Font, Gpu, TargetView, Texture, Vertex};
-use crate::graphics::{Color, Transformation};
+use crate::graphics::gpu::{
+    self, texture, Font, Gpu, Multisample, StencilView, TargetView, Texture,
+    Vertex,
+};
+use crate::graphics::{
+    BlendMode, Color, CoordinateSystem, DebugDraw, Format, Mesh, Point,
+    Rectangle, Shader, Shape, Transformation, Vector, Viewport,
+};
 
 /// A rendering target.
 ///
@@ -17,12 +23,26 @@ pub struct Target<'a> {
     gpu: &'a mut Gpu,
     view: &'a TargetView,
     transformation: Transformation,
+    shader: Option<&'a gpu::Shader>,
+    blend_mode: BlendMode,
+    width: u32,
+    height: u32,
+    visible_size: (u32, u32),
+    clip: Option<Rectangle<u32>>,
+    flip_clip: bool,
+    stencil: &'a StencilView,
+    mask: Mask,
+    multisample: Option<&'a Multisample>,
+    format: Format,
+    pixel_snap: bool,
+    coordinates: CoordinateSystem,
 }
 
 impl<'a> Target<'a> {
     pub(super) fn new(
         gpu: &'a mut Gpu,
         view: &'a TargetView,
+        stencil: &'a StencilView,
         width: f32,
         height: f32,
     ) -> Self {
@@ -30,21 +50,64 @@ impl<'a> Target<'a> {
             gpu,
             view,
             transformation: Transformation::orthographic(width, height),
+            shader: None,
+            blend_mode: BlendMode::Alpha,
+            width: width as u32,
+            height: height as u32,
+            visible_size: (width as u32, height as u32),
+            clip: None,
+            flip_clip: gpu::Surface::FLIP_SCISSOR,
+            stencil,
+            mask: Mask::None,
+            multisample: None,
+            format: Format::Rgba8,
+            pixel_snap: false,
+            coordinates: CoordinateSystem::Screen,
         }
     }
 
     pub(super) fn with_transformation(
         gpu: &'a mut Gpu,
         view: &'a TargetView,
+        stencil: &'a StencilView,
+        multisample: Option<&'a Multisample>,
+        format: Format,
         width: f32,
         height: f32,
         transformation: Transformation,
     ) -> Self {
-        let mut target = Self::new(gpu, view, width, height);
+        let mut target = Self::new(gpu, view, stencil, width, height);
         target.transformation = transformation * target.transformation;
+        target.flip_clip = false;
+        target.multisample = multisample;
+        target.format = format;
         target
     }
 
+    /// Restricts drawing to the area of the given size at the top-left corner
+    /// of the [`Target`], like when only a part of a texture is in use.
+    ///
+    /// [`Target`]: struct.Target.html
+    pub(super) fn with_visible_size(mut self, width: u32, height: u32) -> Self {
+        if (width, height) != (self.width, self.height) {
+            self.clip = Some(Rectangle {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            });
+        }
+
+        self.visible_size = (width, height);
+        self
+    }
+
+    pub(super) fn scaled(mut self, scale: f32) -> Self {
+        self.transformation =
+            self.transformation * Transformation::scale(scale);
+        self
+    }
+
     /// Creates a new [`Target`] applying the given transformation.
     ///
     /// This is equivalent to multiplying the current [`Target`] transform by
@@ -80,10 +143,168 @@ impl<'a> Target<'a> {
     ///
     /// [`Target`]: struct.Target.html
     pub fn transform(&mut self, transformation: Transformation) -> Target<'_> {
+        let mut target = self.derive();
+        target.transformation = target.transformation * transformation;
+        target
+    }
+
+    /// Creates a new [`Target`] that draws textured quads with the given
+    /// [`Shader`].
+    ///
+    /// Images, batches, and canvases drawn on the returned [`Target`] will use
+    /// the [`Shader`] instead of the built-in one. Meshes and text are not
+    /// affected.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Shader`]: struct.Shader.html
+    pub fn with_shader<'b>(&'b mut self, shader: &'b Shader) -> Target<'b> {
+        let mut target = self.derive();
+        target.shader = Some(&shader.shader);
+        target
+    }
+
+    /// Creates a new [`Target`] that draws quads and meshes with the given
+    /// [`BlendMode`].
+    ///
+    /// Text is always drawn with [`BlendMode::Alpha`].
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`BlendMode::Alpha`]: enum.BlendMode.html#variant.Alpha
+    pub fn with_blend_mode(&mut self, blend_mode: BlendMode) -> Target<'_> {
+        let mut target = self.derive();
+        target.blend_mode = blend_mode;
+        target
+    }
+
+    /// Creates a new [`Target`] that only draws inside the given bounds.
+    ///
+    /// The bounds are in pixels, relative to the top-left corner of the
+    /// [`Target`], and they are not affected by its transformation. Clipping
+    /// an already clipped [`Target`] draws only in the intersection of both
+    /// bounds.
+    ///
+    /// Quads and meshes are clipped, but text and [`Target::clear`] are not.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Target::clear`]: #method.clear
+    pub fn with_clip(&mut self, bounds: Rectangle<u32>) -> Target<'_> {
+        let clip = self.intersect_clip(bounds);
+
+        let mut target = self.derive();
+        target.clip = Some(clip);
+        target
+    }
+
+    /// Creates a new [`Target`] that draws in the logical resolution of the
+    /// given [`Viewport`].
+    ///
+    /// The scene is scaled and centered in the [`Target`], and quads and
+    /// meshes drawn on the bars around it are clipped, like with
+    /// [`with_clip`].
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Viewport`]: struct.Viewport.html
+    /// [`with_clip`]: #method.with_clip
+    pub fn with_viewport(&mut self, viewport: &Viewport) -> Target<'_> {
+        let clip = self.intersect_clip(viewport.clip_bounds());
+
+        let mut target = self.derive();
+        target.transformation =
+            target.transformation * viewport.transformation();
+        target.clip = Some(clip);
+        target
+    }
+
+    /// Creates a new [`Target`] that only draws where the given closure drew.
+    ///
+    /// The closure receives a [`Target`] whose draw operations fill a stencil
+    /// mask instead of color. Quads and meshes drawn on the returned
+    /// [`Target`] are then discarded outside of that mask.
+    ///
+    /// Masks do not nest: creating a new mask replaces the previous one for
+    /// the whole [`Target`]. Text is never masked.
+    ///
+    /// [`Target`]: struct.Target.html
+    pub fn with_mask<F>(&mut self, draw_mask: F) -> Target<'_>
+    where
+        F: FnOnce(&mut Target<'_>),
+    {
+        self.gpu.clear_stencil(self.stencil);
+
+        let mut mask = self.derive();
+        mask.mask = Mask::Write;
+
+        draw_mask(&mut mask);
+
+        let mut target = self.derive();
+        target.mask = Mask::Test;
+        target
+    }
+
+    /// Creates a new [`Target`] that uses the given [`CoordinateSystem`].
+    ///
+    /// The [`CoordinateSystem`] is set up for the size of the [`Target`], on
+    /// top of its current transformation. Therefore, you should usually
+    /// change it before applying any other transformation, like a camera.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`CoordinateSystem`]: enum.CoordinateSystem.html
+    pub fn with_coordinates(
+        &mut self,
+        coordinates: CoordinateSystem,
+    ) -> Target<'_> {
+        let transformation = self.transformation
+            * coordinates.transformation(
+                self.visible_size.0 as f32,
+                self.visible_size.1 as f32,
+            );
+
+        let mut target = self.derive();
+        target.transformation = transformation;
+        target.coordinates = coordinates;
+        target
+    }
+
+    /// Creates a new [`Target`] that snaps the quads it draws to whole pixels.
+    ///
+    /// Images, batches, and canvases drawn at fractional positions, like when
+    /// a camera moves at sub-pixel speeds, shimmer as their edges fall between
+    /// pixels. The returned [`Target`] moves their origin to the closest pixel
+    /// of the [`Target`], keeping their edges crisp. Meshes and text are not
+    /// affected.
+    ///
+    /// Combine it with [`Filter::Nearest`], the default [`Filter`] of an
+    /// [`Image`], for pixel-perfect rendering.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Filter`]: enum.Filter.html
+    /// [`Filter::Nearest`]: enum.Filter.html#variant.Nearest
+    /// [`Image`]: struct.Image.html
+    pub fn with_pixel_snapping(&mut self) -> Target<'_> {
+        let mut target = self.derive();
+        target.pixel_snap = true;
+        target
+    }
+
+    fn derive(&mut self) -> Target<'_> {
         Target {
             gpu: self.gpu,
             view: self.view,
-            transformation: self.transformation * transformation,
+            transformation: self.transformation,
+            shader: self.shader,
+            blend_mode: self.blend_mode,
+            width: self.width,
+            height: self.height,
+            visible_size: self.visible_size,
+            clip: self.clip,
+            flip_clip: self.flip_clip,
+            stencil: self.stencil,
+            mask: self.mask,
+            multisample: self.multisample,
+            format: self.format,
+            pixel_snap: self.pixel_snap,
+            coordinates: self.coordinates,
         }
     }
 
@@ -97,14 +318,48 @@ impl<'a> Target<'a> {
 
     pub(super) fn draw_triangles(
         &mut self,
+        texture: Option<&Texture>,
         vertices: &[Vertex],
         indices: &[u32],
     ) {
+        if self.is_clipped_out() {
+            return;
+        }
+
         self.gpu.draw_triangles(
+            texture,
+            vertices,
+            indices,
+            &self.view,
+            &self.transformation,
+            self.blend_mode,
+            self.scissor(),
+            self.mask,
+            self.stencil,
+            self.samples(),
+            self.format,
+        );
+    }
+
+    pub(super) fn clear_triangles(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+    ) {
+        if self.is_clipped_out() {
+            return;
+        }
+
+        self.gpu.clear_triangles(
             vertices,
             indices,
             &self.view,
             &self.transformation,
+            self.scissor(),
+            self.mask,
+            self.stencil,
+            self.samples(),
+            self.format,
         );
     }
 
@@ -113,16 +368,277 @@ impl<'a> Target<'a> {
         texture: &Texture,
         instances: &[gpu::Quad],
     ) {
+        if self.is_clipped_out() {
+            return;
+        }
+
+        // Quads are flipped in Y-up coordinates, so images are drawn upright
+        let flipped;
+        let instances = if self.coordinates == CoordinateSystem::Cartesian {
+            flipped = instances
+                .iter()
+                .map(|instance| {
+                    let mut flipped = *instance;
+                    flipped.flip_vertically();
+                    flipped
+                })
+                .collect::<Vec<_>>();
+
+            &flipped[..]
+        } else {
+            instances
+        };
+
+        let snapped;
+        let instances = if self.pixel_snap {
+            snapped = self.snap_to_pixels(instances);
+            &snapped[..]
+        } else {
+            instances
+        };
+
         self.gpu.draw_texture_quads(
             texture,
             instances,
             &self.view,
             &self.transformation,
+            self.shader,
+            self.blend_mode,
+            self.scissor(),
+            self.mask,
+            self.stencil,
+            self.samples(),
+            self.format,
         );
+
+        self.draw_bounds(instances, Color::GREEN);
+    }
+
+    pub(super) fn draw_canvas_bounds(&mut self, instance: &gpu::Quad) {
+        self.draw_bounds(std::slice::from_ref(instance), Color::MAGENTA);
+    }
+
+    pub(in crate::graphics) fn draw_font(
+        &mut self,
+        font: &mut Font,
+        rasterize_at_scale: bool,
+    ) {
+        // Glyphs can be rasterized at the scale they end up with on the
+        // target, which keeps text crisp when the target is scaled or zoomed.
+        let scale = if rasterize_at_scale {
+            self.pixel_scale()
+        } else {
+            Vector::new(1.0, 1.0)
+        };
+
+        let mut transformation = self.transformation
+            * Transformation::nonuniform_scale(Vector::new(
+                1.0 / scale.x,
+                1.0 / scale.y,
+            ));
+
+        // Text layout is always Y-down, so glyphs are flipped back upright
+        // around the position of their text
+        let flip = self.coordinates == CoordinateSystem::Cartesian;
+
+        if flip {
+            transformation = transformation * Transformation::flip_vertically();
+        }
+
+        font.queue(scale, flip);
+
+        self.draw_queued_font(font, transformation);
+    }
+
+    pub(in crate::graphics) fn preload_font(&mut self, font: &mut Font) {
+        let transformation = self.transformation;
+
+        self.draw_queued_font(font, transformation);
     }
 
-    pub(in crate::graphics) fn draw_font(&mut self, font: &mut Font) {
-        self.gpu.draw_font(font, &self.view, self.transformation);
+    fn draw_queued_font(
+        &mut self,
+        font: &mut Font,
+        transformation: Transformation,
+    ) {
+        match self.multisample {
+            Some(multisample) => self.gpu.draw_font_multisampled(
+                font,
+                multisample,
+                transformation,
+            ),
+            None if self.format != Format::Rgba8 => self.gpu.draw_font_hdr(
+                font,
+                &self.view,
+                self.stencil,
+                self.format,
+                self.width,
+                self.height,
+                transformation,
+            ),
+            None => self.gpu.draw_font(font, &self.view, transformation),
+        }
+    }
+
+    pub(super) fn resolve(
+        &mut self,
+        multisample: &Multisample,
+        drawable: &texture::Drawable,
+    ) {
+        self.gpu.resolve(multisample, drawable);
+    }
+
+    pub(super) fn gpu(&mut self) -> &mut Gpu {
+        self.gpu
+    }
+
+    pub(super) fn size(&self) -> (u32, u32) {
+        self.visible_size
+    }
+
+    /// Outlines the given quads, and the bounds of all of them when there are
+    /// many, if the `DebugDraw::Bounds` mode is enabled.
+    fn draw_bounds(&mut self, instances: &[gpu::Quad], color: Color) {
+        if self.gpu.debug_draw() != DebugDraw::Bounds
+            || self.mask == Mask::Write
+            || instances.is_empty()
+        {
+            return;
+        }
+
+        let mut mesh = Mesh::new();
+        let mut min = Point::new(std::f32::INFINITY, std::f32::INFINITY);
+        let mut max =
+            Point::new(std::f32::NEG_INFINITY, std::f32::NEG_INFINITY);
+
+        for instance in instances {
+            let points: Vec<Point> = instance
+                .corners()
+                .iter()
+                .map(|&[x, y]| Point::new(x, y))
+                .collect();
+
+            for point in &points {
+                min = Point::new(min.x.min(point.x), min.y.min(point.y));
+                max = Point::new(max.x.max(point.x), max.y.max(point.y));
+            }
+
+            mesh.stroke(Shape::Polygon { points }, color, 1.0);
+        }
+
+        if instances.len() > 1 {
+            mesh.stroke(
+                Shape::Rectangle(Rectangle {
+                    x: min.x,
+                    y: min.y,
+                    width: max.x - min.x,
+                    height: max.y - min.y,
+                }),
+                Color::YELLOW,
+                1.0,
+            );
+        }
+
+        mesh.draw(&mut self.with_blend_mode(BlendMode::Alpha));
+    }
+
+    /// Returns the amount of pixels a unit of each axis of the current
+    /// transformation covers in the target.
+    fn pixel_scale(&self) -> Vector {
+        let axis_scale = |axis: Vector| {
+            let unit = self.transformation.transform_vector(axis);
+
+            let scale = (unit.x * self.width as f32 / 2.0)
+                .hypot(unit.y * self.height as f32 / 2.0);
+
+            if scale.is_finite() && scale > 0.0 {
+                scale
+            } else {
+                1.0
+            }
+        };
+
+        Vector::new(
+            axis_scale(Vector::new(1.0, 0.0)),
+            axis_scale(Vector::new(0.0, 1.0)),
+        )
+    }
+
+    /// Moves the given quads so their origin lands on a whole pixel of the
+    /// target.
+    fn snap_to_pixels(&self, instances: &[gpu::Quad]) -> Vec<gpu::Quad> {
+        let projection =
+            Transformation::orthographic(self.width as f32, self.height as f32);
+
+        let to_pixels = match projection.inverse() {
+            Some(inverse) => inverse * self.transformation,
+            None => return instances.to_vec(),
+        };
+
+        let from_pixels = match to_pixels.inverse() {
+            Some(inverse) => inverse,
+            None => return instances.to_vec(),
+        };
+
+        instances
+            .iter()
+            .map(|instance| {
+                let [x, y] = instance.corners()[0];
+                let origin = to_pixels.transform_point(Point::new(x, y));
+                let offset = from_pixels.transform_vector(Vector::new(
+                    origin.x.round() - origin.x,
+                    origin.y.round() - origin.y,
+                ));
+
+                let mut snapped = *instance;
+                snapped.apply(&Transformation::translate(offset));
+                snapped
+            })
+            .collect()
+    }
+
+    fn intersect_clip(&self, bounds: Rectangle<u32>) -> Rectangle<u32> {
+        let current = self.clip.unwrap_or(Rectangle {
+            x: 0,
+            y: 0,
+            width: self.visible_size.0,
+            height: self.visible_size.1,
+        });
+
+        current.intersection(&bounds).unwrap_or(Rectangle {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        })
+    }
+
+    fn samples(&self) -> u32 {
+        self.multisample.map_or(1, Multisample::samples)
+    }
+
+    fn is_clipped_out(&self) -> bool {
+        match self.clip {
+            Some(clip) => clip.width == 0 || clip.height == 0,
+            None => false,
+        }
+    }
+
+    /// Returns the clip bounds in the coordinates of the framebuffer.
+    fn scissor(&self) -> Option<Rectangle<u32>> {
+        self.clip.map(|clip| {
+            if self.flip_clip {
+                Rectangle {
+                    y: self
+                        .height
+                        .saturating_sub(clip.y)
+                        .saturating_sub(clip.height),
+                    ..clip
+                }
+            } else {
+                clip
+            }
+        })
     }
 }
 
@@ -131,3 +647,18 @@ impl<'a> std::fmt::Debug for Target<'a> {
         write!(f, "Target {{ transformation: {:?} }}", self.transformation)
     }
 }
+
+/// How draw operations interact with the stencil mask of a [`Target`].
+///
+/// [`Target`]: struct.Target.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mask {
+    /// Draw normally, ignoring the mask.
+    None,
+
+    /// Fill the mask instead of drawing color.
+    Write,
+
+    /// Draw only where the mask is filled.
+    Test,
+}
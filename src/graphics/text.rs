@@ -12,6 +12,9 @@ pub struct Text<'a> {
     pub position: Point,
 
     /// Text bounds, in screen coordinates
+    ///
+    /// Lines longer than the width of the bounds are wrapped between words.
+    /// The alignment options align the text inside these bounds.
     pub bounds: (f32, f32),
 
     /// Text size
@@ -42,8 +45,127 @@ impl Default for Text<'static> {
     }
 }
 
+/// A section of text made of multiple [`Span`]s.
+///
+/// Every [`Span`] can have its own color, size, and [`FontFace`]. Spans are
+/// laid out contiguously and wrapped as a whole, as if they were a single
+/// [`Text`].
+///
+/// [`Span`]: struct.Span.html
+/// [`FontFace`]: struct.FontFace.html
+/// [`Text`]: struct.Text.html
+#[derive(Clone, PartialEq, Debug)]
+pub struct RichText<'a> {
+    /// Text spans
+    pub spans: Vec<Span<'a>>,
+
+    /// Text position
+    pub position: Point,
+
+    /// Text bounds, in screen coordinates
+    ///
+    /// Lines longer than the width of the bounds are wrapped between words.
+    /// The alignment options align the text inside these bounds.
+    pub bounds: (f32, f32),
+
+    /// Text horizontal alignment
+    pub horizontal_alignment: HorizontalAlignment,
+
+    /// Text vertical alignment
+    pub vertical_alignment: VerticalAlignment,
+}
+
+impl Default for RichText<'static> {
+    #[inline]
+    fn default() -> RichText<'static> {
+        RichText {
+            spans: Vec::new(),
+            position: Point::new(0.0, 0.0),
+            bounds: (f32::INFINITY, f32::INFINITY),
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Top,
+        }
+    }
+}
+
+impl<'a> From<Text<'a>> for RichText<'a> {
+    fn from(text: Text<'a>) -> RichText<'a> {
+        RichText {
+            spans: vec![Span {
+                content: text.content,
+                size: text.size,
+                color: text.color,
+                face: FontFace::DEFAULT,
+            }],
+            position: text.position,
+            bounds: text.bounds,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+        }
+    }
+}
+
+/// A run of text with the same color, size, and [`FontFace`] inside some
+/// [`RichText`].
+///
+/// [`FontFace`]: struct.FontFace.html
+/// [`RichText`]: struct.RichText.html
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Span<'a> {
+    /// Span content
+    pub content: &'a str,
+
+    /// Span size
+    pub size: f32,
+
+    /// Span color
+    pub color: Color,
+
+    /// Span font face
+    pub face: FontFace,
+}
+
+impl Default for Span<'static> {
+    #[inline]
+    fn default() -> Span<'static> {
+        Span {
+            content: "",
+            size: 16.0,
+            color: Color::BLACK,
+            face: FontFace::DEFAULT,
+        }
+    }
+}
+
+/// A face of a [`Font`], like its bold or italic variant.
+///
+/// Every [`Font`] starts with a single face, the [`FontFace::DEFAULT`]. Add
+/// more with [`Font::add_face`] and use them in the [`Span`]s of some
+/// [`RichText`] to mix faces on the same line.
+///
+/// [`Font`]: struct.Font.html
+/// [`FontFace::DEFAULT`]: #associatedconstant.DEFAULT
+/// [`Font::add_face`]: struct.Font.html#method.add_face
+/// [`Span`]: struct.Span.html
+/// [`RichText`]: struct.RichText.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontFace(pub(crate) usize);
+
+impl FontFace {
+    /// The face a [`Font`] was created with.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub const DEFAULT: FontFace = FontFace(0);
+}
+
+impl Default for FontFace {
+    fn default() -> FontFace {
+        FontFace::DEFAULT
+    }
+}
+
 /// The horizontal alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HorizontalAlignment {
     /// Align left
     Left,
@@ -56,7 +178,7 @@ pub enum HorizontalAlignment {
 }
 
 /// The vertical alignment of some resource.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VerticalAlignment {
     /// Align top
     Top,
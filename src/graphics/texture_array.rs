@@ -7,10 +7,14 @@ pub use batch::Batch;
 pub use builder::Builder;
 pub use loader::{Indices, Key, Loader};
 
+use std::cell::Cell;
 use std::fmt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use crate::graphics::gpu::Texture;
+use crate::graphics::{Gpu, Rectangle, Sampler};
+use crate::Result;
 
 /// A collection of different textures with the same size.
 ///
@@ -20,6 +24,9 @@ use crate::graphics::gpu::Texture;
 /// You need to use a [`Builder`] or a [`Loader`] to create one. Use a [`Batch`]
 /// to draw it.
 ///
+/// A [`TextureArray`] can reserve empty layers when it is built. You can fill
+/// them later with [`TextureArray::push`] without invalidating any [`Index`].
+///
 /// Cloning a [`TextureArray`] is cheap, it only clones a handle. It does not
 /// create new copy of the texture on the GPU.
 ///
@@ -27,11 +34,104 @@ use crate::graphics::gpu::Texture;
 /// [`Builder`]: struct.Builder.html
 /// [`Loader`]: struct.Loader.html
 /// [`Batch`]: struct.Batch.html
+/// [`TextureArray::push`]: struct.TextureArray.html#method.push
+/// [`Index`]: struct.Index.html
 #[derive(Debug, Clone)]
 pub struct TextureArray {
     texture: Texture,
     x_unit: f32,
     y_unit: f32,
+    layers: Rc<Cell<u16>>,
+    capacity: u16,
+}
+
+impl TextureArray {
+    /// Loads a new image from the given path and adds it to the first empty
+    /// layer of the [`TextureArray`].
+    ///
+    /// The file is read from the [`AssetSource`] of the [`Gpu`]. The image is
+    /// placed at the top-left corner of the layer.
+    ///
+    /// Fails if the image does not fit in a layer or if there are no empty
+    /// layers left. Reserve them with [`Builder::set_capacity`] or
+    /// [`Loader::set_capacity`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`AssetSource`]: ../../load/trait.AssetSource.html
+    /// [`Gpu`]: ../struct.Gpu.html
+    /// [`Builder::set_capacity`]: struct.Builder.html#method.set_capacity
+    /// [`Loader::set_capacity`]: struct.Loader.html#method.set_capacity
+    pub fn push<P: AsRef<Path>>(
+        &mut self,
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<Index> {
+        let rgba = image::load_from_memory(&gpu.read_asset(&path)?)?.to_rgba();
+
+        if rgba.width() > u32::from(self.texture.width())
+            || rgba.height() > u32::from(self.texture.height())
+        {
+            return Err(crate::Error::TextureArray(Error::ImageIsTooBig(
+                PathBuf::from(path.as_ref()),
+            )));
+        }
+
+        let layer = self.layers.get();
+
+        if layer >= self.capacity {
+            return Err(crate::Error::TextureArray(Error::ArrayIsFull));
+        }
+
+        let region = Rectangle {
+            x: 0,
+            y: 0,
+            width: rgba.width() as u16,
+            height: rgba.height() as u16,
+        };
+
+        gpu.update_texture(&self.texture, layer, region, &rgba.into_raw());
+        self.layers.set(layer + 1);
+
+        Ok(Index {
+            layer,
+            offset: Offset { x: 0.0, y: 0.0 },
+        })
+    }
+
+    /// Returns the amount of layers in use of the [`TextureArray`].
+    ///
+    /// It is shared between clones of the same [`TextureArray`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn layers(&self) -> u16 {
+        self.layers.get()
+    }
+
+    /// Returns the maximum amount of layers of the [`TextureArray`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn capacity(&self) -> u16 {
+        self.capacity
+    }
+
+    /// Returns the [`Sampler`] of the [`TextureArray`].
+    ///
+    /// [`Sampler`]: ../struct.Sampler.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn sampler(&self) -> Sampler {
+        self.texture.sampler()
+    }
+
+    /// Sets the [`Sampler`] of the [`TextureArray`].
+    ///
+    /// The [`Sampler`] is not shared between clones of the same
+    /// [`TextureArray`].
+    ///
+    /// [`Sampler`]: ../struct.Sampler.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.texture.set_sampler(sampler);
+    }
 }
 
 /// An index that identifies a texture in a [`TextureArray`].
@@ -63,6 +163,9 @@ pub enum Error {
 
     /// A provided image did not fit in a texture array layer.
     ImageIsTooBig(PathBuf),
+
+    /// There were no empty layers left in a texture array.
+    ArrayIsFull,
 }
 
 impl fmt::Display for Error {
@@ -72,6 +175,7 @@ impl fmt::Display for Error {
             Error::ImageIsTooBig(path) => {
                 write!(f, "Image is too big: {}", path.display())
             }
+            Error::ArrayIsFull => write!(f, "Texture array is full"),
         }
     }
 }
@@ -1,4 +1,5 @@
 use super::{Index, TextureArray};
+use crate::graphics::batch::sort_by_depth;
 use crate::graphics::{gpu, IntoQuad, Target};
 
 /// A collection of quads that can be drawn with a [`TextureArray`] all at once.
@@ -8,6 +9,7 @@ use crate::graphics::{gpu, IntoQuad, Target};
 pub struct Batch {
     texture_array: TextureArray,
     instances: Vec<gpu::Quad>,
+    depths: Vec<f32>,
 }
 
 impl Batch {
@@ -19,6 +21,7 @@ impl Batch {
         Batch {
             texture_array,
             instances: Vec::new(),
+            depths: Vec::new(),
         }
     }
 
@@ -35,10 +38,12 @@ impl Batch {
         quad.source.x += index.offset.x;
         quad.source.y += index.offset.y;
 
+        let depth = quad.depth;
         let mut instance = gpu::Quad::from(quad);
 
         instance.layer = index.layer.into();
 
+        self.depths.push(depth);
         self.instances.push(instance);
     }
 
@@ -52,4 +57,18 @@ impl Batch {
             &self.instances[..],
         );
     }
+
+    /// Sorts the quads of the [`Batch`] by their `depth` and draws them on the
+    /// given [`Target`].
+    ///
+    /// Quads with a lower depth are drawn first. Quads with the same depth keep
+    /// the order in which they were added.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target`]: ../struct.Target.html
+    pub fn draw_sorted(&mut self, target: &mut Target<'_>) {
+        sort_by_depth(&mut self.depths, &mut self.instances);
+
+        self.draw(target);
+    }
 }
@@ -1,10 +1,12 @@
+use std::cell::Cell;
 use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use std::sync::Arc;
 
 use super::{Index, Offset, TextureArray};
-use crate::graphics::Gpu;
+use crate::graphics::{Gpu, Sampler};
 use crate::{Error, Result};
 
 /// A [`TextureArray`] builder.
@@ -16,22 +18,51 @@ pub struct Builder {
     height: u32,
     layers: Vec<Layer>,
     current: Layer,
+    capacity: u16,
+    sampler: Sampler,
 }
 
 impl Builder {
     /// Creates a new [`Builder`] of a [`TextureArray`] of the given size.
     ///
+    /// The size and the amount of layers of a [`TextureArray`] are limited by
+    /// the [`Capabilities`] of the [`Gpu`]. Check them up front if you target
+    /// weak hardware.
+    ///
     /// [`Builder`]: struct.Builder.html
     /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Capabilities`]: ../struct.Capabilities.html
+    /// [`Gpu`]: ../struct.Gpu.html
     pub fn new(width: u16, height: u16) -> Builder {
         Builder {
             width: width as u32,
             height: height as u32,
             layers: Vec::new(),
             current: Layer::new(width, height),
+            capacity: 0,
+            sampler: Sampler::default(),
         }
     }
 
+    /// Sets the minimum amount of layers of the produced [`TextureArray`].
+    ///
+    /// The layers not used by the added images are left empty. You can fill
+    /// them later with [`TextureArray::push`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`TextureArray::push`]: struct.TextureArray.html#method.push
+    pub fn set_capacity(&mut self, layers: u16) {
+        self.capacity = layers;
+    }
+
+    /// Sets the [`Sampler`] of the produced [`TextureArray`].
+    ///
+    /// [`Sampler`]: ../struct.Sampler.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
+
     /// Loads a new image from the given path and adds it to the produced
     /// [`TextureArray`].
     ///
@@ -93,7 +124,7 @@ impl Builder {
             self.current = Layer::new(0, 0);
         }
 
-        let images: Vec<image::DynamicImage> = self
+        let mut images: Vec<image::DynamicImage> = self
             .layers
             .iter()
             .map(|layer| {
@@ -101,12 +132,23 @@ impl Builder {
             })
             .collect();
 
-        let texture = gpu.upload_texture_array(&images[..]);
+        let layers = images.len() as u16;
+
+        while images.len() < usize::from(self.capacity) {
+            images.push(image::DynamicImage::ImageRgba8(
+                image::RgbaImage::new(self.width, self.height),
+            ));
+        }
+
+        let mut texture = gpu.upload_texture_array(&images[..]);
+        texture.set_sampler(self.sampler);
 
         TextureArray {
             texture,
             x_unit: 1.0 / self.width as f32,
             y_unit: 1.0 / self.height as f32,
+            layers: Rc::new(Cell::new(layers)),
+            capacity: images.len() as u16,
         }
     }
 }
@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 use std::path::PathBuf;
 
 use super::{Builder, Index, TextureArray};
+use crate::graphics::Sampler;
 use crate::load::Task;
 use crate::{Error, Result};
 
@@ -57,6 +58,8 @@ pub struct Loader {
     width: u16,
     height: u16,
     paths: Vec<PathBuf>,
+    capacity: u16,
+    sampler: Sampler,
 }
 
 impl Loader {
@@ -70,9 +73,30 @@ impl Loader {
             width,
             height,
             paths: Vec::new(),
+            capacity: 0,
+            sampler: Sampler::default(),
         }
     }
 
+    /// Sets the minimum amount of layers of the produced [`TextureArray`].
+    ///
+    /// The layers not used by the queued images are left empty. You can fill
+    /// them later with [`TextureArray::push`].
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`TextureArray::push`]: struct.TextureArray.html#method.push
+    pub fn set_capacity(&mut self, layers: u16) {
+        self.capacity = layers;
+    }
+
+    /// Sets the [`Sampler`] of the produced [`TextureArray`].
+    ///
+    /// [`Sampler`]: ../struct.Sampler.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
+
     /// Queues an image to be added to the produced [`TextureArray`] and obtain
     /// a [`Key`] to its [`Index`].
     ///
@@ -99,6 +123,8 @@ impl Loader {
 
         Task::sequence(total_work, move |task| {
             let mut builder = Builder::new(self.width, self.height);
+            builder.set_capacity(self.capacity);
+            builder.set_sampler(self.sampler);
             let mut work_todo = VecDeque::from(self.paths.clone());
             let mut indices = Vec::new();
 
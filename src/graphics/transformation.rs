@@ -1,7 +1,7 @@
 use nalgebra::Matrix3;
 use std::ops::Mul;
 
-use crate::graphics::Vector;
+use crate::graphics::{Point, Vector};
 
 /// A 2D transformation matrix.
 ///
@@ -32,6 +32,22 @@ impl Transformation {
         ))
     }
 
+    /// Creates a transformation from a Y-up coordinate system, with the origin
+    /// at the center of an area of the given size, into the default one.
+    ///
+    /// See [`CoordinateSystem::Cartesian`].
+    ///
+    /// [`CoordinateSystem::Cartesian`]: enum.CoordinateSystem.html#variant.Cartesian
+    pub fn cartesian(width: f32, height: f32) -> Transformation {
+        Transformation::translate(Vector::new(width / 2.0, height / 2.0))
+            * Transformation::flip_vertically()
+    }
+
+    /// Creates a transformation that mirrors the Y axis.
+    pub fn flip_vertically() -> Transformation {
+        Transformation::nonuniform_scale(Vector::new(1.0, -1.0))
+    }
+
     /// Creates a translate transformation.
     ///
     /// You can use this to pan your camera, for example.
@@ -63,6 +79,43 @@ impl Transformation {
     pub fn rotate(rotation: f32) -> Transformation {
         Transformation(Matrix3::new_rotation(rotation))
     }
+
+    /// Returns the inverse of the [`Transformation`], if it exists.
+    ///
+    /// A degenerate [`Transformation`], like a scale of `0.0`, cannot be
+    /// inverted. In that case, `None` is returned.
+    ///
+    /// You can use this to convert a position in screen space back into world
+    /// space, for example.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    pub fn inverse(&self) -> Option<Transformation> {
+        self.0.try_inverse().map(Transformation)
+    }
+
+    /// Applies the [`Transformation`] to the given [`Point`].
+    ///
+    /// The whole transformation is applied, including translation.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    /// [`Point`]: type.Point.html
+    pub fn transform_point(&self, point: Point) -> Point {
+        let transformed = self.0 * point.to_homogeneous();
+
+        Point::new(transformed.x / transformed.z, transformed.y / transformed.z)
+    }
+
+    /// Applies the [`Transformation`] to the given [`Vector`].
+    ///
+    /// Unlike [`transform_point`], the translation is ignored. Use it to
+    /// transform directions and distances, like the velocity of an object.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    /// [`Vector`]: type.Vector.html
+    /// [`transform_point`]: #method.transform_point
+    pub fn transform_vector(&self, vector: Vector) -> Vector {
+        self.0.transform_vector(&vector)
+    }
 }
 
 impl Mul for Transformation {
@@ -108,3 +161,77 @@ impl Into<Matrix3<f32>> for Transformation {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Point, b: Point) {
+        assert!((a - b).norm() < 1e-4, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn inverse_undoes_the_transformation() {
+        let transformation = Transformation::translate(Vector::new(10.0, -5.0))
+            * Transformation::rotate(0.7)
+            * Transformation::nonuniform_scale(Vector::new(2.0, 0.5));
+
+        let inverse = transformation.inverse().expect("Invertible");
+        let point = Point::new(3.0, 4.0);
+
+        assert_close(
+            inverse.transform_point(transformation.transform_point(point)),
+            point,
+        );
+        assert_close((transformation * inverse).transform_point(point), point);
+    }
+
+    #[test]
+    fn inverse_of_degenerate_transformation() {
+        assert_eq!(Transformation::scale(0.0).inverse(), None);
+        assert_eq!(
+            Transformation::nonuniform_scale(Vector::new(1.0, 0.0)).inverse(),
+            None
+        );
+        assert_eq!(
+            Transformation::identity().inverse(),
+            Some(Transformation::identity())
+        );
+    }
+
+    #[test]
+    fn transform_point_applies_translation() {
+        let transformation = Transformation::translate(Vector::new(10.0, 20.0))
+            * Transformation::scale(2.0);
+
+        assert_close(
+            transformation.transform_point(Point::new(1.0, 2.0)),
+            Point::new(12.0, 24.0),
+        );
+    }
+
+    #[test]
+    fn transform_vector_ignores_translation() {
+        let transformation = Transformation::translate(Vector::new(10.0, 20.0))
+            * Transformation::nonuniform_scale(Vector::new(2.0, 3.0));
+
+        assert_eq!(
+            transformation.transform_vector(Vector::new(1.0, 1.0)),
+            Vector::new(2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn orthographic_maps_corners_to_clip_space() {
+        let transformation = Transformation::orthographic(800.0, 600.0);
+
+        assert_close(
+            transformation.transform_point(Point::new(0.0, 0.0)),
+            Point::new(-1.0, 1.0),
+        );
+        assert_close(
+            transformation.transform_point(Point::new(800.0, 600.0)),
+            Point::new(1.0, -1.0),
+        );
+    }
+}
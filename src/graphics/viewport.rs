@@ -0,0 +1,207 @@
+use crate::graphics::{Point, Rectangle, Transformation, Vector};
+
+/// A fixed logical resolution fitted into the screen.
+///
+/// A [`Viewport`] scales a scene drawn at a fixed resolution, like `320x180`
+/// in a pixel-art game, to fill as much of the screen as possible while
+/// keeping its aspect ratio. The remaining space is left as letterbox or
+/// pillarbox bars.
+///
+/// Draw your scene using [`Target::with_viewport`], which clips the bars out.
+/// Clear the whole [`Frame`] first to paint the bars. Use
+/// [`screen_to_logical`] to convert the cursor position into the logical
+/// resolution.
+///
+/// Keep the `screen` updated with the size of the [`Target`] you are drawing
+/// on, so the [`Viewport`] adapts when the window is resized.
+///
+/// [`Viewport`]: struct.Viewport.html
+/// [`Target::with_viewport`]: struct.Target.html#method.with_viewport
+/// [`Frame`]: struct.Frame.html
+/// [`screen_to_logical`]: #method.screen_to_logical
+/// [`Target`]: struct.Target.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The logical resolution of the scene.
+    pub resolution: (f32, f32),
+
+    /// The size of the screen, in screen coordinates.
+    pub screen: (f32, f32),
+
+    /// Defines whether the scene is only scaled by whole numbers.
+    ///
+    /// Integer scaling keeps every logical pixel the same size, at the cost
+    /// of bigger bars.
+    pub integer_scaling: bool,
+}
+
+impl Viewport {
+    /// Creates a new [`Viewport`] with the given logical resolution.
+    ///
+    /// The screen starts with the same size as the resolution. Integer scaling
+    /// is disabled.
+    ///
+    /// [`Viewport`]: struct.Viewport.html
+    pub fn fit(width: f32, height: f32) -> Viewport {
+        Viewport {
+            resolution: (width, height),
+            screen: (width, height),
+            integer_scaling: false,
+        }
+    }
+
+    /// Returns the scale applied to the logical resolution.
+    pub fn scale(&self) -> f32 {
+        let (width, height) = self.resolution;
+        let (screen_width, screen_height) = self.screen;
+
+        let scale = (screen_width / width).min(screen_height / height);
+
+        if self.integer_scaling && scale >= 1.0 {
+            scale.floor()
+        } else {
+            scale
+        }
+    }
+
+    /// Returns the area of the screen covered by the scene, in screen
+    /// coordinates.
+    pub fn bounds(&self) -> Rectangle<f32> {
+        let scale = self.scale();
+        let width = self.resolution.0 * scale;
+        let height = self.resolution.1 * scale;
+
+        Rectangle {
+            x: ((self.screen.0 - width) / 2.0).round(),
+            y: ((self.screen.1 - height) / 2.0).round(),
+            width,
+            height,
+        }
+    }
+
+    /// Returns the [`Transformation`] that turns logical coordinates into
+    /// screen coordinates.
+    ///
+    /// [`Transformation`]: struct.Transformation.html
+    pub fn transformation(&self) -> Transformation {
+        let bounds = self.bounds();
+
+        Transformation::translate(Vector::new(bounds.x, bounds.y))
+            * Transformation::scale(self.scale())
+    }
+
+    /// Converts a [`Point`] in screen coordinates, like the cursor position,
+    /// into logical coordinates.
+    ///
+    /// The result is outside of the logical resolution when the [`Point`] is
+    /// on the bars.
+    ///
+    /// [`Point`]: type.Point.html
+    pub fn screen_to_logical(&self, point: Point) -> Point {
+        let bounds = self.bounds();
+        let scale = self.scale();
+
+        Point::new((point.x - bounds.x) / scale, (point.y - bounds.y) / scale)
+    }
+
+    /// Converts a [`Point`] in logical coordinates into screen coordinates.
+    ///
+    /// [`Point`]: type.Point.html
+    pub fn logical_to_screen(&self, point: Point) -> Point {
+        self.transformation().transform_point(point)
+    }
+
+    pub(super) fn clip_bounds(&self) -> Rectangle<u32> {
+        let bounds = self.bounds();
+
+        Rectangle {
+            x: bounds.x.max(0.0) as u32,
+            y: bounds.y.max(0.0) as u32,
+            width: bounds.width.round() as u32,
+            height: bounds.height.round() as u32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport(screen: (f32, f32), integer_scaling: bool) -> Viewport {
+        Viewport {
+            screen,
+            integer_scaling,
+            ..Viewport::fit(320.0, 180.0)
+        }
+    }
+
+    #[test]
+    fn letterbox_on_taller_screens() {
+        let viewport = viewport((800.0, 600.0), false);
+
+        assert_eq!(viewport.scale(), 2.5);
+        assert_eq!(
+            viewport.clip_bounds(),
+            Rectangle {
+                x: 0,
+                y: 75,
+                width: 800,
+                height: 450,
+            }
+        );
+    }
+
+    #[test]
+    fn pillarbox_on_wider_screens() {
+        let viewport = viewport((1000.0, 450.0), false);
+
+        assert_eq!(viewport.scale(), 2.5);
+        assert_eq!(
+            viewport.clip_bounds(),
+            Rectangle {
+                x: 100,
+                y: 0,
+                width: 800,
+                height: 450,
+            }
+        );
+    }
+
+    #[test]
+    fn integer_scaling_rounds_down() {
+        let viewport = viewport((800.0, 600.0), true);
+
+        assert_eq!(viewport.scale(), 2.0);
+        assert_eq!(
+            viewport.clip_bounds(),
+            Rectangle {
+                x: 80,
+                y: 120,
+                width: 640,
+                height: 360,
+            }
+        );
+    }
+
+    #[test]
+    fn integer_scaling_keeps_downscaling() {
+        assert_eq!(viewport((160.0, 90.0), true).scale(), 0.5);
+    }
+
+    #[test]
+    fn screen_and_logical_conversions() {
+        let viewport = viewport((800.0, 600.0), false);
+
+        assert_eq!(
+            viewport.screen_to_logical(Point::new(400.0, 300.0)),
+            Point::new(160.0, 90.0)
+        );
+        assert_eq!(
+            viewport.logical_to_screen(Point::new(160.0, 90.0)),
+            Point::new(400.0, 300.0)
+        );
+
+        // The letterbox bars are outside of the logical resolution
+        assert!(viewport.screen_to_logical(Point::new(0.0, 10.0)).y < 0.0);
+    }
+}
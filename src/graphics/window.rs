@@ -1,15 +1,31 @@
+#[cfg(feature = "clipboard")]
+mod clipboard;
 mod cursor_icon;
+mod custom_cursor;
 mod frame;
+mod icon;
+mod monitor;
+mod secondary;
 mod settings;
 
 pub(crate) use winit;
 
+#[cfg(feature = "clipboard")]
+pub use clipboard::Clipboard;
 pub use cursor_icon::CursorIcon;
+pub use custom_cursor::CustomCursor;
 pub use frame::Frame;
+pub use icon::Icon;
+pub use monitor::{Fullscreen, Monitor, VideoMode};
+pub use secondary::SecondaryWindow;
 pub use settings::Settings;
 
+use std::path::PathBuf;
+
 use crate::graphics::gpu::{self, Gpu};
-use crate::Result;
+use crate::graphics::{BlendMode, Canvas, Point, Quad, Target};
+use crate::{Error, Result};
+use secondary::Secondary;
 
 /// An open window.
 ///
@@ -23,6 +39,29 @@ pub struct Window {
     height: f32,
     is_fullscreen: bool,
     cursor_icon: Option<winit::window::CursorIcon>,
+    custom_cursor: Option<CustomCursor>,
+    cursor_position: Option<Point>,
+    is_cursor_hidden: bool,
+    is_cursor_grabbed: bool,
+    scale_factor: f32,
+    dpi_scaling: bool,
+    max_frames_per_second: Option<u16>,
+    vsync: bool,
+    is_exit_requested: bool,
+    samples: Option<u8>,
+    is_screenshot_requested: bool,
+    is_capturing: bool,
+    canvas: Option<Canvas>,
+    screenshot: Option<image::DynamicImage>,
+    #[cfg(feature = "clipboard")]
+    clipboard: Option<copypasta::ClipboardContext>,
+    hovered_files: Vec<PathBuf>,
+    dropped_files: Vec<PathBuf>,
+    secondaries: Vec<Secondary>,
+    pending_secondaries: Vec<(SecondaryWindow, Settings)>,
+    next_secondary: usize,
+    aspect_ratio: Option<(u32, u32)>,
+    requested_size: Option<winit::dpi::PhysicalSize<u32>>,
 }
 
 impl Window {
@@ -32,18 +71,54 @@ impl Window {
     ) -> Result<Window> {
         let (width, height) = settings.size;
         let is_fullscreen = settings.fullscreen;
+        let max_frames_per_second = settings.max_frames_per_second;
+        let vsync = settings.vsync;
+        let samples = settings.samples;
+        let aspect_ratio = settings.aspect_ratio;
 
-        let (gpu, surface) =
-            Gpu::for_window(settings.into_builder(event_loop), event_loop)?;
+        let (gpu, surface) = Gpu::for_window(
+            settings.into_builder(event_loop.primary_monitor())?,
+            event_loop,
+            vsync,
+        )?;
 
-        Ok(Window {
+        let mut window = Window {
             is_fullscreen,
             gpu,
             surface,
             width: width as f32,
             height: height as f32,
             cursor_icon: Some(winit::window::CursorIcon::Default),
-        })
+            custom_cursor: None,
+            cursor_position: None,
+            is_cursor_hidden: false,
+            is_cursor_grabbed: false,
+            scale_factor: 1.0,
+            dpi_scaling: false,
+            max_frames_per_second,
+            vsync,
+            is_exit_requested: false,
+            samples,
+            is_screenshot_requested: false,
+            is_capturing: false,
+            canvas: None,
+            screenshot: None,
+            #[cfg(feature = "clipboard")]
+            clipboard: None,
+            hovered_files: Vec::new(),
+            dropped_files: Vec::new(),
+            secondaries: Vec::new(),
+            pending_secondaries: Vec::new(),
+            next_secondary: 0,
+            aspect_ratio: None,
+            requested_size: None,
+        };
+
+        window.scale_factor = window.surface.window().scale_factor() as f32;
+        window.update_canvas();
+        window.set_aspect_ratio(aspect_ratio);
+
+        Ok(window)
     }
 
     /// Returns the [`Gpu`] linked to the [`Window`].
@@ -55,54 +130,837 @@ impl Window {
     }
 
     pub(crate) fn frame(&mut self) -> Frame<'_> {
-        Frame::new(self)
+        let scale = self.logical_scale();
+
+        Frame::new(
+            &mut self.gpu,
+            &mut self.surface,
+            self.canvas.as_mut(),
+            self.width,
+            self.height,
+            scale,
+        )
+    }
+
+    pub(crate) fn id(&self) -> winit::window::WindowId {
+        self.surface.window().id()
+    }
+
+    /// Opens a new secondary window with the given [`WindowSettings`].
+    ///
+    /// Secondary windows share the [`Gpu`] of the [`Window`], so resources
+    /// can be drawn on any of them. The window is opened before the next
+    /// frame and drawn in [`Game::draw_secondary`]. It is closed when the
+    /// player requests it or with [`close_secondary`].
+    ///
+    /// The `max_frames_per_second` and `samples` settings are ignored, as
+    /// secondary windows are presented together with the [`Window`].
+    ///
+    /// It fails right away when the [`Gpu`] does not support
+    /// [`secondary_windows`], which is currently the case with the `opengl`
+    /// feature. If the window cannot be opened later on, a
+    /// [`SecondaryEvent::Closed`] is sent to your [`Game::Input`] instead.
+    ///
+    /// [`WindowSettings`]: struct.WindowSettings.html
+    /// [`Gpu`]: struct.Gpu.html
+    /// [`Window`]: struct.Window.html
+    /// [`Game::draw_secondary`]: ../trait.Game.html#method.draw_secondary
+    /// [`close_secondary`]: #method.close_secondary
+    /// [`SecondaryEvent::Closed`]: ../input/enum.SecondaryEvent.html#variant.Closed
+    /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+    /// [`secondary_windows`]: struct.Capabilities.html#structfield.secondary_windows
+    pub fn spawn_secondary(
+        &mut self,
+        settings: Settings,
+    ) -> Result<SecondaryWindow> {
+        if !self.gpu.capabilities().secondary_windows {
+            return Err(Error::WindowCreation(String::from(
+                "Secondary windows are not supported by the graphics backend",
+            )));
+        }
+
+        let handle = SecondaryWindow::new(self.next_secondary);
+
+        self.next_secondary += 1;
+        self.pending_secondaries.push((handle, settings));
+
+        Ok(handle)
+    }
+
+    /// Closes the given secondary window.
+    ///
+    /// Nothing happens if it is already closed.
+    pub fn close_secondary(&mut self, window: SecondaryWindow) {
+        self.secondaries
+            .retain(|secondary| secondary.handle != window);
+        self.pending_secondaries
+            .retain(|(handle, _)| *handle != window);
+    }
+
+    /// Returns the secondary windows that are currently open.
+    pub fn secondary_windows(&self) -> Vec<SecondaryWindow> {
+        self.secondaries
+            .iter()
+            .map(|secondary| secondary.handle)
+            .collect()
+    }
+
+    /// Opens the pending secondary windows and returns the ones that failed
+    /// to open.
+    pub(crate) fn open_secondaries(
+        &mut self,
+        event_loop: &winit::event_loop::EventLoopWindowTarget<()>,
+    ) -> Vec<SecondaryWindow> {
+        let mut failed = Vec::new();
+
+        for (handle, settings) in std::mem::take(&mut self.pending_secondaries)
+        {
+            let vsync = settings.vsync;
+            let primary_monitor = self.surface.window().primary_monitor();
+
+            let surface =
+                settings.into_builder(primary_monitor).and_then(|builder| {
+                    self.gpu
+                        .create_secondary_surface(builder, event_loop, vsync)
+                });
+
+            match surface {
+                Ok(surface) => {
+                    self.secondaries.push(Secondary::new(handle, surface))
+                }
+                Err(_) => failed.push(handle),
+            }
+        }
+
+        failed
+    }
+
+    pub(crate) fn find_secondary(
+        &self,
+        id: winit::window::WindowId,
+    ) -> Option<SecondaryWindow> {
+        self.secondaries
+            .iter()
+            .find(|secondary| secondary.surface.window().id() == id)
+            .map(|secondary| secondary.handle)
+    }
+
+    pub(crate) fn secondary_frame(
+        &mut self,
+        window: SecondaryWindow,
+    ) -> Option<Frame<'_>> {
+        let Window {
+            gpu,
+            secondaries,
+            dpi_scaling,
+            ..
+        } = self;
+
+        secondaries
+            .iter_mut()
+            .find(|secondary| secondary.handle == window)
+            .map(move |secondary| {
+                let scale = if *dpi_scaling {
+                    secondary.scale_factor
+                } else {
+                    1.0
+                };
+
+                Frame::new(
+                    gpu,
+                    &mut secondary.surface,
+                    None,
+                    secondary.width,
+                    secondary.height,
+                    scale,
+                )
+            })
+    }
+
+    pub(crate) fn secondary_scale_factor(
+        &self,
+        window: SecondaryWindow,
+    ) -> Option<f32> {
+        self.secondaries
+            .iter()
+            .find(|secondary| secondary.handle == window)
+            .map(|secondary| secondary.scale_factor)
+    }
+
+    pub(crate) fn update_secondary_scale_factor(
+        &mut self,
+        window: SecondaryWindow,
+        scale_factor: f32,
+    ) {
+        if let Some(secondary) = self
+            .secondaries
+            .iter_mut()
+            .find(|secondary| secondary.handle == window)
+        {
+            secondary.scale_factor = scale_factor;
+        }
+    }
+
+    pub(crate) fn resize_secondary(
+        &mut self,
+        window: SecondaryWindow,
+        new_size: winit::dpi::PhysicalSize<u32>,
+    ) {
+        let Window {
+            gpu, secondaries, ..
+        } = self;
+
+        if let Some(secondary) = secondaries
+            .iter_mut()
+            .find(|secondary| secondary.handle == window)
+        {
+            secondary.surface.resize(gpu, new_size);
+            secondary.width = new_size.width as f32;
+            secondary.height = new_size.height as f32;
+        }
     }
 
     /// Toggles the [`Window`]'s fullscreen state.
     ///
+    /// The [`Window`] becomes a borderless window covering its current
+    /// [`Monitor`]. Use [`set_fullscreen`] for more control.
+    ///
     /// [`Window`]: struct.Window.html
+    /// [`Monitor`]: struct.Monitor.html
+    /// [`set_fullscreen`]: #method.set_fullscreen
     pub fn toggle_fullscreen(&mut self) {
-        let window = self.surface.window();
-
-        let monitor = if self.is_fullscreen {
+        let fullscreen = if self.is_fullscreen() {
             None
         } else {
-            Some(window.primary_monitor())
+            Some(Fullscreen::Borderless(self.current_monitor()))
+        };
+
+        self.set_fullscreen(fullscreen);
+    }
+
+    /// Returns the current [`Fullscreen`] mode of the [`Window`], if any.
+    ///
+    /// [`Fullscreen`]: enum.Fullscreen.html
+    /// [`Window`]: struct.Window.html
+    pub fn fullscreen(&self) -> Option<Fullscreen> {
+        self.surface.window().fullscreen().map(Fullscreen::from)
+    }
+
+    /// Returns true if the [`Window`] is in fullscreen mode.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn is_fullscreen(&self) -> bool {
+        self.is_fullscreen
+    }
+
+    /// Changes the [`Fullscreen`] mode of the [`Window`].
+    ///
+    /// Use `None` to go back to windowed mode. You can list the modes
+    /// supported in exclusive fullscreen with [`Monitor::video_modes`].
+    ///
+    /// [`Fullscreen`]: enum.Fullscreen.html
+    /// [`Window`]: struct.Window.html
+    /// [`Monitor::video_modes`]: struct.Monitor.html#method.video_modes
+    pub fn set_fullscreen(&mut self, fullscreen: Option<Fullscreen>) {
+        self.is_fullscreen = fullscreen.is_some();
+
+        self.surface
+            .window()
+            .set_fullscreen(fullscreen.map(Into::into));
+    }
+
+    /// Returns the [`Monitor`]s connected to the system.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.surface
+            .window()
+            .available_monitors()
+            .map(Monitor::new)
+            .collect()
+    }
+
+    /// Returns the primary [`Monitor`] of the system.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn primary_monitor(&self) -> Monitor {
+        Monitor::new(self.surface.window().primary_monitor())
+    }
+
+    /// Returns the [`Monitor`] the [`Window`] is currently on.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    /// [`Window`]: struct.Window.html
+    pub fn current_monitor(&self) -> Monitor {
+        Monitor::new(self.surface.window().current_monitor())
+    }
+
+    /// Changes the [`Icon`] of the [`Window`].
+    ///
+    /// Use `None` to go back to the default icon.
+    ///
+    /// [`Icon`]: enum.Icon.html
+    /// [`Window`]: struct.Window.html
+    pub fn set_icon(&mut self, icon: Option<Icon>) -> Result<()> {
+        let icon = match icon {
+            Some(icon) => Some(icon.into_winit()?),
+            None => None,
         };
 
-        window
-            .set_fullscreen(monitor.map(winit::window::Fullscreen::Borderless));
+        self.surface.window().set_window_icon(icon);
+
+        Ok(())
+    }
+
+    /// Changes the title of the [`Window`].
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_title(&mut self, title: &str) {
+        self.surface.window().set_title(title);
+    }
+
+    /// Sets whether the [`Window`] can be resized by the player.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_resizable(&mut self, resizable: bool) {
+        self.surface.window().set_resizable(resizable);
+    }
+
+    /// Sets whether the [`Window`] has a title bar and borders.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_decorations(&mut self, decorations: bool) {
+        self.surface.window().set_decorations(decorations);
+    }
+
+    /// Sets whether the [`Window`] stays on top of other windows.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_always_on_top(&mut self, always_on_top: bool) {
+        self.surface.window().set_always_on_top(always_on_top);
+    }
+
+    /// Requests a new size for the [`Window`], in pixels.
+    ///
+    /// The size of the [`Window`] is updated once the system resizes it,
+    /// which may not happen immediately or exactly as requested.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.surface
+            .window()
+            .set_inner_size(winit::dpi::PhysicalSize { width, height });
+    }
 
-        self.is_fullscreen = !self.is_fullscreen;
+    /// Sets the minimum size of the [`Window`], in pixels.
+    ///
+    /// Use `None` to remove the constraint.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_min_size(&mut self, size: Option<(u32, u32)>) {
+        self.surface.window().set_min_inner_size(
+            size.map(|(width, height)| winit::dpi::PhysicalSize {
+                width,
+                height,
+            }),
+        );
+    }
+
+    /// Sets the maximum size of the [`Window`], in pixels.
+    ///
+    /// Use `None` to remove the constraint.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_max_size(&mut self, size: Option<(u32, u32)>) {
+        self.surface.window().set_max_inner_size(
+            size.map(|(width, height)| winit::dpi::PhysicalSize {
+                width,
+                height,
+            }),
+        );
+    }
+
+    /// Locks the aspect ratio of the [`Window`] to the given
+    /// `(width, height)` pair, like `(16, 9)`.
+    ///
+    /// When the player resizes the [`Window`] into a different shape, a new
+    /// size that keeps the aspect ratio is requested. A
+    /// [`window::Event::Resized`] is only sent once the size is settled. Use
+    /// `None` to remove the lock.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`window::Event::Resized`]: ../input/window/enum.Event.html#variant.Resized
+    pub fn set_aspect_ratio(&mut self, aspect_ratio: Option<(u32, u32)>) {
+        self.aspect_ratio =
+            aspect_ratio.filter(|&(width, height)| width > 0 && height > 0);
+        self.requested_size = None;
+
+        let size = self.surface.window().inner_size();
+        let _ = self.constrain_size(size);
+    }
+
+    /// Returns the position of the top-left corner of the [`Window`] in the
+    /// desktop, in pixels.
+    ///
+    /// It returns `None` if the system does not support it.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn position(&self) -> Option<(i32, i32)> {
+        self.surface
+            .window()
+            .outer_position()
+            .ok()
+            .map(|position| (position.x, position.y))
+    }
+
+    /// Moves the top-left corner of the [`Window`] to the given position in
+    /// the desktop, in pixels.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_position(&mut self, x: i32, y: i32) {
+        self.surface
+            .window()
+            .set_outer_position(winit::dpi::PhysicalPosition { x, y });
+    }
+
+    /// Moves the [`Window`] to the center of the given [`Monitor`].
+    ///
+    /// A [`Window`] in borderless fullscreen covers the new [`Monitor`]
+    /// instead. List the available monitors with [`monitors`].
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Monitor`]: struct.Monitor.html
+    /// [`monitors`]: #method.monitors
+    pub fn move_to_monitor(&mut self, monitor: &Monitor) {
+        if let Some(Fullscreen::Borderless(_)) = self.fullscreen() {
+            self.set_fullscreen(Some(Fullscreen::Borderless(monitor.clone())));
+            return;
+        }
+
+        let (x, y) = monitor.position();
+        let (width, height) = monitor.size();
+        let size = self.surface.window().outer_size();
+
+        self.set_position(
+            x + (width as i32 - size.width as i32) / 2,
+            y + (height as i32 - size.height as i32) / 2,
+        );
+    }
+
+    /// Requests the attention of the player, usually by flashing the icon of
+    /// the [`Window`] in the taskbar or bouncing it in the dock.
+    ///
+    /// It only has an effect on macOS for now.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn request_attention(&mut self) {
+        #[cfg(target_os = "macos")]
+        {
+            use winit::platform::macos::{
+                RequestUserAttentionType, WindowExtMacOS,
+            };
+
+            self.surface.window().request_user_attention(
+                RequestUserAttentionType::Informational,
+            );
+        }
+    }
+
+    /// Moves the candidate box of the input method editor (IME) of the
+    /// system to the given [`Point`] of the [`Window`].
+    ///
+    /// Place it next to your text field, so players typing with an IME, like
+    /// in Chinese or Japanese, can see what they compose. The composed text
+    /// is sent as [`keyboard::Event::TextEntered`] once it is committed.
+    ///
+    /// [`Point`]: type.Point.html
+    /// [`Window`]: struct.Window.html
+    /// [`keyboard::Event::TextEntered`]: ../input/keyboard/enum.Event.html#variant.TextEntered
+    pub fn set_ime_position(&mut self, position: Point) {
+        let scale = self.logical_scale();
+
+        self.surface
+            .window()
+            .set_ime_position(winit::dpi::PhysicalPosition {
+                x: f64::from(position.x * scale),
+                y: f64::from(position.y * scale),
+            });
+    }
+
+    /// Replaces the mouse cursor of the [`Window`] with a [`CustomCursor`].
+    ///
+    /// Use `None` to go back to the cursor defined by [`Game::cursor_icon`].
+    /// The [`CustomCursor`] is not drawn while the cursor is hidden.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`CustomCursor`]: struct.CustomCursor.html
+    /// [`Game::cursor_icon`]: ../trait.Game.html#method.cursor_icon
+    pub fn set_custom_cursor(&mut self, cursor: Option<CustomCursor>) {
+        self.custom_cursor = cursor;
+        self.update_cursor_visibility();
+    }
+
+    /// Returns true if the mouse cursor is hidden with [`hide_cursor`].
+    ///
+    /// [`hide_cursor`]: #method.hide_cursor
+    pub fn is_cursor_hidden(&self) -> bool {
+        self.is_cursor_hidden
+    }
+
+    /// Hides or shows the mouse cursor while it is over the [`Window`].
+    ///
+    /// A hidden cursor stays hidden regardless of [`Game::cursor_icon`].
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`Game::cursor_icon`]: ../trait.Game.html#method.cursor_icon
+    pub fn hide_cursor(&mut self, hidden: bool) {
+        self.is_cursor_hidden = hidden;
+        self.update_cursor_visibility();
+    }
+
+    /// Returns true if the mouse cursor is grabbed with [`grab_cursor`].
+    ///
+    /// [`grab_cursor`]: #method.grab_cursor
+    pub fn is_cursor_grabbed(&self) -> bool {
+        self.is_cursor_grabbed
+    }
+
+    /// Grabs or releases the mouse cursor.
+    ///
+    /// A grabbed cursor cannot leave the [`Window`]. Combine it with
+    /// [`hide_cursor`] to implement mouse-look.
+    ///
+    /// It returns false if the platform does not support it.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`hide_cursor`]: #method.hide_cursor
+    pub fn grab_cursor(&mut self, grab: bool) -> bool {
+        let is_supported = self.surface.window().set_cursor_grab(grab).is_ok();
+
+        if is_supported {
+            self.is_cursor_grabbed = grab;
+        }
+
+        is_supported
+    }
+
+    /// Returns the [`Clipboard`] of the system.
+    ///
+    /// This method is only available with the `clipboard` feature.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    #[cfg(feature = "clipboard")]
+    pub fn clipboard(&mut self) -> Clipboard<'_> {
+        Clipboard::new(&mut self.clipboard)
+    }
+
+    /// Returns the paths of the files being dragged over the [`Window`].
+    ///
+    /// A [`FileHovered`] event is sent for every hovered file.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`FileHovered`]: ../input/window/enum.Event.html#variant.FileHovered
+    pub fn hovered_files(&self) -> &[PathBuf] {
+        &self.hovered_files
+    }
+
+    /// Takes the paths of the files dropped on the [`Window`] since the last
+    /// call.
+    ///
+    /// A [`FileDropped`] event is sent for every dropped file. Paths are
+    /// kept until they are taken.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`FileDropped`]: ../input/window/enum.Event.html#variant.FileDropped
+    pub fn take_dropped_files(&mut self) -> Vec<PathBuf> {
+        std::mem::take(&mut self.dropped_files)
     }
 
     /// Returns the width of the [`Window`].
     ///
+    /// It is measured in logical pixels when [`dpi_scaling`] is enabled.
+    ///
     /// [`Window`]: struct.Window.html
+    /// [`dpi_scaling`]: #method.dpi_scaling
     pub fn width(&self) -> f32 {
-        self.width
+        self.width / self.logical_scale()
     }
 
     /// Returns the height of the [`Window`].
     ///
+    /// It is measured in logical pixels when [`dpi_scaling`] is enabled.
+    ///
     /// [`Window`]: struct.Window.html
+    /// [`dpi_scaling`]: #method.dpi_scaling
     pub fn height(&self) -> f32 {
-        self.height
+        self.height / self.logical_scale()
+    }
+
+    /// Returns the scale factor of the [`Window`].
+    ///
+    /// It is the ratio between physical and logical pixels of the monitor the
+    /// [`Window`] is on, like `2.0` on most high-DPI displays. It changes when
+    /// the [`Window`] moves to a monitor with a different DPI, which is
+    /// notified with a [`ScaleFactorChanged`] event.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`ScaleFactorChanged`]: ../input/window/enum.Event.html#variant.ScaleFactorChanged
+    pub fn scale_factor(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Returns whether or not the [`Window`] is drawn in logical pixels.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn dpi_scaling(&self) -> bool {
+        self.dpi_scaling
+    }
+
+    /// Enables or disables DPI scaling for the [`Window`].
+    ///
+    /// When enabled, the [`Frame`] is scaled by the [`scale_factor`], so text,
+    /// user interfaces, and everything else you draw keep the same physical
+    /// size on every monitor. The sizes of the [`Window`] and the [`Frame`],
+    /// and the cursor positions of mouse events, are then measured in logical
+    /// pixels. Text is still rasterized at the physical resolution, so it
+    /// stays crisp.
+    ///
+    /// It is disabled by default.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`scale_factor`]: #method.scale_factor
+    /// [`Window`]: struct.Window.html
+    pub fn set_dpi_scaling(&mut self, dpi_scaling: bool) {
+        self.dpi_scaling = dpi_scaling;
+    }
+
+    /// Returns the amount of physical pixels per unit of the [`Frame`].
+    pub(crate) fn logical_scale(&self) -> f32 {
+        if self.dpi_scaling {
+            self.scale_factor
+        } else {
+            1.0
+        }
+    }
+
+    pub(crate) fn update_scale_factor(&mut self, scale_factor: f32) {
+        self.scale_factor = scale_factor;
+    }
+
+    /// Returns the maximum amount of frames the [`Window`] draws every second.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn max_frames_per_second(&self) -> Option<u16> {
+        self.max_frames_per_second
+    }
+
+    /// Sets the maximum amount of frames the [`Window`] draws every second.
+    ///
+    /// Use `None` to remove the limit.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn set_max_frames_per_second(
+        &mut self,
+        max_frames_per_second: Option<u16>,
+    ) {
+        self.max_frames_per_second = max_frames_per_second;
+    }
+
+    /// Returns whether or not the [`Window`] waits for the vertical blank of
+    /// the display before presenting a frame.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn vsync(&self) -> bool {
+        self.vsync
+    }
+
+    /// Enables or disables vsync for the [`Window`].
+    ///
+    /// Disabling vsync reduces input latency, but it may cause tearing. The
+    /// change takes effect on the next frame.
+    ///
+    /// The OpenGL backend cannot change the swap interval of an existing
+    /// context. Therefore, with the `opengl` feature, only the value set in
+    /// [`WindowSettings::vsync`] is honored.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`WindowSettings::vsync`]: struct.WindowSettings.html#structfield.vsync
+    pub fn set_vsync(&mut self, vsync: bool) {
+        if self.vsync != vsync {
+            self.surface.set_vsync(&mut self.gpu, vsync);
+            self.vsync = vsync;
+        }
+    }
+
+    /// Requests the game loop to end.
+    ///
+    /// The window will be closed gracefully after the current interaction.
+    /// Use it to implement a "Quit" button, or to close the window after
+    /// keeping it open in [`Game::on_close_requested`].
+    ///
+    /// [`Game::on_close_requested`]: ../trait.Game.html#method.on_close_requested
+    pub fn request_exit(&mut self) {
+        self.is_exit_requested = true;
+    }
+
+    pub(crate) fn is_exit_requested(&self) -> bool {
+        self.is_exit_requested
+    }
+
+    /// Requests a screenshot of the next frame of the [`Window`].
+    ///
+    /// Once the frame is presented, you can obtain its pixels with
+    /// [`take_screenshot`]. Note that the frame being drawn when this method is
+    /// called is not captured.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`take_screenshot`]: #method.take_screenshot
+    pub fn request_screenshot(&mut self) {
+        self.is_screenshot_requested = true;
+    }
+
+    /// Takes the last screenshot of the [`Window`], if there is any.
+    ///
+    /// A screenshot must be requested first with [`request_screenshot`].
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`request_screenshot`]: #method.request_screenshot
+    pub fn take_screenshot(&mut self) -> Option<image::DynamicImage> {
+        self.screenshot.take()
     }
 
     pub(crate) fn swap_buffers(&mut self) {
+        // The backbuffer cannot be read nor multisampled in every backend.
+        // Instead, when needed, frames are drawn on a canvas, which is copied
+        // to the backbuffer before presenting.
+        let Window {
+            surface,
+            gpu,
+            width,
+            height,
+            canvas,
+            is_capturing,
+            screenshot,
+            ..
+        } = self;
+
+        if let Some(canvas) = canvas {
+            let (view, stencil) = surface.views();
+
+            canvas.draw(
+                Quad {
+                    position: Point::new(0.0, 0.0),
+                    size: (*width, *height),
+                    ..Quad::default()
+                },
+                &mut Target::new(gpu, view, stencil, *width, *height)
+                    .with_blend_mode(BlendMode::Replace),
+            );
+
+            if *is_capturing {
+                *screenshot = Some(canvas.read_pixels(gpu));
+            }
+        }
+
         self.surface.swap_buffers(&mut self.gpu);
+
+        for secondary in &mut self.secondaries {
+            secondary.surface.present(&mut self.gpu);
+        }
+
+        self.is_capturing = self.is_screenshot_requested;
+        self.is_screenshot_requested = false;
+
+        self.update_canvas();
+    }
+
+    /// Creates, resizes, or drops the canvas frames are drawn on.
+    fn update_canvas(&mut self) {
+        let width = self.width as u16;
+        let height = self.height as u16;
+
+        let is_needed = self.samples.is_some() || self.is_capturing;
+
+        if !is_needed || width == 0 || height == 0 {
+            self.canvas = None;
+            return;
+        }
+
+        match &mut self.canvas {
+            Some(canvas) => canvas.resize(&mut self.gpu, width, height),
+            None => {
+                self.canvas = Canvas::with_samples(
+                    &mut self.gpu,
+                    width,
+                    height,
+                    self.samples.unwrap_or(1),
+                )
+                .ok();
+            }
+        }
     }
 
     pub(crate) fn request_redraw(&mut self) {
         self.surface.request_redraw();
     }
 
+    /// Requests a new size that keeps the aspect ratio of the window, if the
+    /// given size does not.
+    ///
+    /// Returns true if the given size is final.
+    pub(crate) fn constrain_size(
+        &mut self,
+        size: winit::dpi::PhysicalSize<u32>,
+    ) -> bool {
+        let (ratio_width, ratio_height) = match self.aspect_ratio {
+            Some(aspect_ratio) => aspect_ratio,
+            None => return true,
+        };
+
+        if size.width == 0 || size.height == 0 {
+            return true;
+        }
+
+        let ratio = ratio_width as f32 / ratio_height as f32;
+
+        // Keep the dimension the player changed and adjust the other one
+        let constrained = if size.width != self.width as u32 {
+            winit::dpi::PhysicalSize {
+                width: size.width,
+                height: ((size.width as f32 / ratio).round() as u32).max(1),
+            }
+        } else {
+            winit::dpi::PhysicalSize {
+                width: ((size.height as f32 * ratio).round() as u32).max(1),
+                height: size.height,
+            }
+        };
+
+        let is_close_enough =
+            (size.width as i64 - constrained.width as i64).abs() <= 1
+                && (size.height as i64 - constrained.height as i64).abs() <= 1;
+
+        // The system may refuse the requested size, so we accept it anyway
+        // to avoid fighting against it forever
+        if is_close_enough || self.requested_size == Some(constrained) {
+            self.requested_size = None;
+
+            true
+        } else {
+            self.requested_size = Some(constrained);
+            self.surface.window().set_inner_size(constrained);
+
+            false
+        }
+    }
+
     pub(crate) fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.surface.resize(&mut self.gpu, new_size);
 
         self.width = new_size.width as f32;
         self.height = new_size.height as f32;
+
+        self.update_canvas();
     }
 
     pub(crate) fn update_cursor(
@@ -113,12 +971,59 @@ impl Window {
             if let Some(cursor_icon) = new_cursor {
                 self.surface.window().set_cursor_icon(cursor_icon);
             }
-            self.surface
-                .window()
-                .set_cursor_visible(new_cursor.is_some());
             self.cursor_icon = new_cursor;
+            self.update_cursor_visibility();
         }
     }
+
+    pub(crate) fn hover_file(&mut self, path: PathBuf) {
+        self.hovered_files.push(path);
+    }
+
+    pub(crate) fn cancel_file_hover(&mut self) {
+        self.hovered_files.clear();
+    }
+
+    pub(crate) fn drop_file(&mut self, path: PathBuf) {
+        self.hovered_files.retain(|hovered| *hovered != path);
+        self.dropped_files.push(path);
+    }
+
+    pub(crate) fn update_cursor_position(&mut self, position: Option<Point>) {
+        self.cursor_position = position;
+    }
+
+    pub(crate) fn draw_custom_cursor(&mut self) {
+        if self.cursor_icon.is_none() || self.is_cursor_hidden {
+            return;
+        }
+
+        if let (Some(cursor), Some(position)) =
+            (self.custom_cursor.clone(), self.cursor_position)
+        {
+            let image = &cursor.image;
+
+            let position = position / self.logical_scale();
+
+            image.draw(
+                Quad {
+                    position: position - cursor.hotspot.coords,
+                    size: (f32::from(image.width()), f32::from(image.height())),
+                    ..Quad::default()
+                },
+                &mut self.frame().as_target(),
+            );
+        }
+    }
+
+    fn update_cursor_visibility(&self) {
+        // The custom cursor is drawn in place of the system one
+        let is_visible = self.cursor_icon.is_some()
+            && !self.is_cursor_hidden
+            && self.custom_cursor.is_none();
+
+        self.surface.window().set_cursor_visible(is_visible);
+    }
 }
 
 impl std::fmt::Debug for Window {
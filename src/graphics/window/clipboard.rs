@@ -0,0 +1,53 @@
+use copypasta::{ClipboardContext, ClipboardProvider};
+
+use crate::{Error, Result};
+
+/// The clipboard of the system.
+///
+/// Obtain it with [`Window::clipboard`]. This type is only available with the
+/// `clipboard` feature.
+///
+/// [`Window::clipboard`]: struct.Window.html#method.clipboard
+#[allow(missing_debug_implementations)]
+pub struct Clipboard<'a> {
+    context: &'a mut Option<ClipboardContext>,
+}
+
+impl<'a> Clipboard<'a> {
+    pub(super) fn new(context: &'a mut Option<ClipboardContext>) -> Self {
+        Clipboard { context }
+    }
+
+    /// Reads the text contents of the [`Clipboard`].
+    ///
+    /// Fails if the [`Clipboard`] is unavailable or does not contain text.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn read_text(&mut self) -> Result<String> {
+        self.context()?
+            .get_contents()
+            .map_err(|error| Error::Clipboard(error.to_string()))
+    }
+
+    /// Replaces the contents of the [`Clipboard`] with the given text.
+    ///
+    /// [`Clipboard`]: struct.Clipboard.html
+    pub fn write_text(&mut self, text: &str) -> Result<()> {
+        self.context()?
+            .set_contents(text.to_owned())
+            .map_err(|error| Error::Clipboard(error.to_string()))
+    }
+
+    fn context(&mut self) -> Result<&mut ClipboardContext> {
+        // The connection to the clipboard is opened on first use and kept
+        // until the window is closed
+        if self.context.is_none() {
+            *self.context = Some(
+                ClipboardContext::new()
+                    .map_err(|error| Error::Clipboard(error.to_string()))?,
+            );
+        }
+
+        Ok(self.context.as_mut().unwrap())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::graphics::{Image, Point};
+
+/// A mouse cursor drawn with an [`Image`].
+///
+/// Set it with [`Window::set_custom_cursor`]. Use [`Image::from_raw`] to
+/// create a [`CustomCursor`] from raw RGBA pixels.
+///
+/// The cursor is drawn by Coffee on top of every frame, after
+/// [`Game::debug`]. Therefore, it moves at the frame rate of your game.
+///
+/// [`Image`]: struct.Image.html
+/// [`Window::set_custom_cursor`]: struct.Window.html#method.set_custom_cursor
+/// [`Image::from_raw`]: struct.Image.html#method.from_raw
+/// [`CustomCursor`]: struct.CustomCursor.html
+/// [`Game::debug`]: ../trait.Game.html#method.debug
+#[derive(Debug, Clone)]
+pub struct CustomCursor {
+    /// The [`Image`] of the cursor.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub image: Image,
+
+    /// The point of the [`Image`] that is placed at the position of the
+    /// cursor, relative to its top-left corner.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub hotspot: Point,
+}
@@ -1,6 +1,5 @@
-use super::Window;
-
-use crate::graphics::{Color, Gpu, Target};
+use crate::graphics::gpu::{self, Vertex};
+use crate::graphics::{Canvas, Color, Gpu, Rectangle, Target};
 
 /// The next frame of your game.
 ///
@@ -13,14 +12,32 @@ use crate::graphics::{Color, Gpu, Target};
 /// [`Frame`]: struct.Frame.html
 /// [`Window::frame`]: struct.Window.html#method.frame
 /// [`Target`]: struct.Target.html
-#[derive(Debug)]
 pub struct Frame<'a> {
-    window: &'a mut Window,
+    gpu: &'a mut Gpu,
+    surface: &'a mut gpu::Surface,
+    canvas: Option<&'a mut Canvas>,
+    width: f32,
+    height: f32,
+    scale: f32,
 }
 
 impl<'a> Frame<'a> {
-    pub(crate) fn new(window: &mut Window) -> Frame<'_> {
-        Frame { window }
+    pub(super) fn new(
+        gpu: &'a mut Gpu,
+        surface: &'a mut gpu::Surface,
+        canvas: Option<&'a mut Canvas>,
+        width: f32,
+        height: f32,
+        scale: f32,
+    ) -> Frame<'a> {
+        Frame {
+            gpu,
+            surface,
+            canvas,
+            width,
+            height,
+            scale,
+        }
     }
 
     /// Get the [`Gpu`] linked to the [`Window`] of this [`Frame`].
@@ -29,17 +46,27 @@ impl<'a> Frame<'a> {
     /// [`Window`]: struct.Window.html
     /// [`Frame`]: struct.Frame.html
     pub fn gpu(&mut self) -> &mut Gpu {
-        self.window.gpu()
+        self.gpu
     }
 
     /// Get the width of the frame.
+    ///
+    /// It is measured in logical pixels when [`Window::dpi_scaling`] is
+    /// enabled.
+    ///
+    /// [`Window::dpi_scaling`]: struct.Window.html#method.dpi_scaling
     pub fn width(&self) -> f32 {
-        self.window.width
+        self.width / self.scale
     }
 
     /// Get the height of the frame.
+    ///
+    /// It is measured in logical pixels when [`Window::dpi_scaling`] is
+    /// enabled.
+    ///
+    /// [`Window::dpi_scaling`]: struct.Window.html#method.dpi_scaling
     pub fn height(&self) -> f32 {
-        self.window.height
+        self.height / self.scale
     }
 
     /// See the frame as a [`Target`].
@@ -48,17 +75,25 @@ impl<'a> Frame<'a> {
     ///
     /// [`Target`]: struct.Target.html
     pub fn as_target(&mut self) -> Target<'_> {
-        let Window {
-            surface,
+        let Frame {
             gpu,
+            surface,
+            canvas,
             width,
             height,
-            ..
-        } = &mut self.window;
+            scale,
+        } = self;
+
+        let target = match canvas {
+            Some(canvas) => canvas.as_target(gpu),
+            None => {
+                let (view, stencil) = surface.views();
 
-        let view = surface.target();
+                Target::new(gpu, view, stencil, *width, *height)
+            }
+        };
 
-        Target::new(gpu, view, *width, *height)
+        target.scaled(*scale)
     }
 
     /// Clear the frame with the given [`Color`].
@@ -67,4 +102,50 @@ impl<'a> Frame<'a> {
     pub fn clear(&mut self, color: Color) {
         self.as_target().clear(color);
     }
+
+    /// Clear a region of the frame with the given [`Color`].
+    ///
+    /// The contents of the region are replaced, even if the [`Color`] is
+    /// translucent. The region is clamped to the bounds of the frame.
+    ///
+    /// If only a small part of your game changes between frames, you can use
+    /// this to redraw just that part.
+    ///
+    /// [`Color`]: struct.Color.html
+    pub fn clear_region(&mut self, bounds: Rectangle<u16>, color: Color) {
+        let left = f32::from(bounds.x).min(self.width());
+        let top = f32::from(bounds.y).min(self.height());
+        let right =
+            (f32::from(bounds.x) + f32::from(bounds.width)).min(self.width());
+        let bottom =
+            (f32::from(bounds.y) + f32::from(bounds.height)).min(self.height());
+
+        if left >= right || top >= bottom {
+            return;
+        }
+
+        // Neither backend supports scissored clears, so we draw a quad that
+        // replaces the contents of the frame instead
+        let color = color.into_linear();
+
+        let vertices = [
+            Vertex::new([left, top], [0.0, 0.0], color),
+            Vertex::new([right, top], [0.0, 0.0], color),
+            Vertex::new([right, bottom], [0.0, 0.0], color),
+            Vertex::new([left, bottom], [0.0, 0.0], color),
+        ];
+
+        self.as_target()
+            .clear_triangles(&vertices, &[0, 1, 2, 0, 2, 3]);
+    }
+}
+
+impl std::fmt::Debug for Frame<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Frame {{ width: {}, height: {} }}",
+            self.width, self.height
+        )
+    }
 }
@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use super::winit;
+use crate::{Error, Result};
+
+/// The icon of a window, shown in its title bar and in the taskbar.
+///
+/// When the `serialize` feature is enabled, an [`Icon::Path`] is saved as a
+/// plain string and an [`Icon::Rgba`] as a table with its pixels.
+///
+/// [`Icon::Path`]: #variant.Path
+/// [`Icon::Rgba`]: #variant.Rgba
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum Icon {
+    /// An image file at the given path.
+    ///
+    /// The file is read directly from the filesystem, as the icon is needed
+    /// before the [`Gpu`] and its [`AssetSource`] exist.
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    /// [`AssetSource`]: ../load/trait.AssetSource.html
+    Path(PathBuf),
+
+    /// Raw RGBA pixels.
+    Rgba {
+        /// The pixels of the icon, row by row, with 4 bytes per pixel.
+        pixels: Vec<u8>,
+
+        /// The width of the icon, in pixels.
+        width: u32,
+
+        /// The height of the icon, in pixels.
+        height: u32,
+    },
+}
+
+impl Icon {
+    pub(super) fn into_winit(self) -> Result<winit::window::Icon> {
+        let (pixels, width, height) = match self {
+            Icon::Path(path) => {
+                let image = image::open(path)?.to_rgba();
+                let (width, height) = image.dimensions();
+
+                (image.into_raw(), width, height)
+            }
+            Icon::Rgba {
+                pixels,
+                width,
+                height,
+            } => (pixels, width, height),
+        };
+
+        winit::window::Icon::from_rgba(pixels, width, height)
+            .map_err(|error| Error::WindowCreation(error.to_string()))
+    }
+}
@@ -0,0 +1,146 @@
+use crate::graphics::window::winit;
+
+/// A monitor connected to the system.
+///
+/// You can list the monitors with [`Window::monitors`].
+///
+/// [`Window::monitors`]: struct.Window.html#method.monitors
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Monitor(winit::monitor::MonitorHandle);
+
+impl Monitor {
+    pub(super) fn new(handle: winit::monitor::MonitorHandle) -> Monitor {
+        Monitor(handle)
+    }
+
+    /// Returns a human-readable name of the [`Monitor`], if available.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn name(&self) -> Option<String> {
+        self.0.name()
+    }
+
+    /// Returns the resolution of the [`Monitor`], in pixels.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn size(&self) -> (u32, u32) {
+        let size = self.0.size();
+
+        (size.width, size.height)
+    }
+
+    /// Returns the position of the top-left corner of the [`Monitor`] in the
+    /// desktop, in pixels.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn position(&self) -> (i32, i32) {
+        let position = self.0.position();
+
+        (position.x, position.y)
+    }
+
+    /// Returns the scale factor of the [`Monitor`].
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn scale_factor(&self) -> f64 {
+        self.0.scale_factor()
+    }
+
+    /// Returns the video modes supported by the [`Monitor`] in exclusive
+    /// fullscreen.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    pub fn video_modes(&self) -> Vec<VideoMode> {
+        self.0.video_modes().map(VideoMode).collect()
+    }
+}
+
+/// A video mode supported by a [`Monitor`].
+///
+/// [`Monitor`]: struct.Monitor.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VideoMode(winit::monitor::VideoMode);
+
+impl VideoMode {
+    /// Returns the resolution of the [`VideoMode`], in pixels.
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    pub fn size(&self) -> (u32, u32) {
+        let size = self.0.size();
+
+        (size.width, size.height)
+    }
+
+    /// Returns the bit depth of the [`VideoMode`].
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    pub fn bit_depth(&self) -> u16 {
+        self.0.bit_depth()
+    }
+
+    /// Returns the refresh rate of the [`VideoMode`], in hertz.
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    pub fn refresh_rate(&self) -> u16 {
+        self.0.refresh_rate()
+    }
+
+    /// Returns the [`Monitor`] of the [`VideoMode`].
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    /// [`VideoMode`]: struct.VideoMode.html
+    pub fn monitor(&self) -> Monitor {
+        Monitor(self.0.monitor())
+    }
+}
+
+/// A fullscreen mode of a [`Window`].
+///
+/// Use [`Window::set_fullscreen`] to change it.
+///
+/// [`Window`]: struct.Window.html
+/// [`Window::set_fullscreen`]: struct.Window.html#method.set_fullscreen
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Fullscreen {
+    /// A borderless window covering the given [`Monitor`].
+    ///
+    /// It keeps the resolution of the desktop, which makes switching to other
+    /// applications fast.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    Borderless(Monitor),
+
+    /// Exclusive fullscreen with the given [`VideoMode`].
+    ///
+    /// It changes the resolution of the [`Monitor`] of the [`VideoMode`].
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    /// [`Monitor`]: struct.Monitor.html
+    Exclusive(VideoMode),
+}
+
+impl From<Fullscreen> for winit::window::Fullscreen {
+    fn from(fullscreen: Fullscreen) -> winit::window::Fullscreen {
+        match fullscreen {
+            Fullscreen::Borderless(monitor) => {
+                winit::window::Fullscreen::Borderless(monitor.0)
+            }
+            Fullscreen::Exclusive(video_mode) => {
+                winit::window::Fullscreen::Exclusive(video_mode.0)
+            }
+        }
+    }
+}
+
+impl From<winit::window::Fullscreen> for Fullscreen {
+    fn from(fullscreen: winit::window::Fullscreen) -> Fullscreen {
+        match fullscreen {
+            winit::window::Fullscreen::Borderless(monitor) => {
+                Fullscreen::Borderless(Monitor(monitor))
+            }
+            winit::window::Fullscreen::Exclusive(video_mode) => {
+                Fullscreen::Exclusive(VideoMode(video_mode))
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use crate::graphics::gpu;
+
+/// A handle to a secondary window.
+///
+/// Open one with [`Window::spawn_secondary`] and draw on it in
+/// [`Game::draw_secondary`]. Its input events are delivered as
+/// [`input::Event::Secondary`].
+///
+/// [`Window::spawn_secondary`]: struct.Window.html#method.spawn_secondary
+/// [`Game::draw_secondary`]: ../trait.Game.html#method.draw_secondary
+/// [`input::Event::Secondary`]: ../input/enum.Event.html#variant.Secondary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SecondaryWindow(usize);
+
+impl SecondaryWindow {
+    pub(super) fn new(id: usize) -> SecondaryWindow {
+        SecondaryWindow(id)
+    }
+}
+
+pub(super) struct Secondary {
+    pub handle: SecondaryWindow,
+    pub surface: gpu::Surface,
+    pub width: f32,
+    pub height: f32,
+    pub scale_factor: f32,
+}
+
+impl Secondary {
+    pub fn new(handle: SecondaryWindow, surface: gpu::Surface) -> Secondary {
+        let size = surface.window().inner_size();
+
+        Secondary {
+            handle,
+            surface,
+            width: size.width as f32,
+            height: size.height as f32,
+            scale_factor: surface.window().scale_factor() as f32,
+        }
+    }
+}
@@ -1,7 +1,19 @@
-use super::winit;
+use super::{winit, Icon};
+use crate::Result;
+
+#[cfg(feature = "serialize")]
+use std::{fs, io, path::Path};
 
 /// A window configuration.
+///
+/// When the `serialize` feature is enabled, it implements `Serialize` and
+/// `Deserialize` from [`serde`], and it can be saved to and loaded from a
+/// TOML file.
+///
+/// [`serde`]: https://serde.rs
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serialize", serde(default))]
 pub struct Settings {
     /// A title for the window.
     pub title: String,
@@ -17,20 +29,207 @@ pub struct Settings {
 
     /// Defines whether or not the window should start maximized.
     pub maximized: bool,
+
+    /// The maximum amount of frames the window should draw every second.
+    ///
+    /// It limits the frame rate independently of vsync and
+    /// [`Game::TICKS_PER_SECOND`]. Use `None` to draw as fast as possible.
+    ///
+    /// The game loop sleeps between frames and only spins for the last
+    /// couple of milliseconds, keeping the frame pacing precise without
+    /// burning CPU time. Capping the frame rate of menus and other static
+    /// screens can noticeably reduce power usage on laptops.
+    ///
+    /// [`Game::TICKS_PER_SECOND`]: ../trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub max_frames_per_second: Option<u16>,
+
+    /// Defines whether or not the window should wait for the vertical blank
+    /// of the display before presenting a frame.
+    ///
+    /// Vsync avoids tearing at the cost of some input latency. It can be
+    /// toggled later with [`Window::set_vsync`].
+    ///
+    /// [`Window::set_vsync`]: struct.Window.html#method.set_vsync
+    pub vsync: bool,
+
+    /// The number of samples per pixel used to anti-alias the window, if any.
+    ///
+    /// Multisampling smooths the edges of quads and meshes. Most GPUs support
+    /// 2, 4, and 8 samples. When enabled, frames are drawn on a multisampled
+    /// [`Canvas`] before being presented.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub samples: Option<u8>,
+
+    /// The icon of the window, if any.
+    ///
+    /// It is shown in the title bar and the taskbar on Windows and Linux. It
+    /// can be changed later with [`Window::set_icon`].
+    ///
+    /// [`Window::set_icon`]: struct.Window.html#method.set_icon
+    pub icon: Option<Icon>,
+
+    /// Defines whether or not the background of the window should be
+    /// transparent.
+    ///
+    /// Clear the [`Frame`] with a translucent [`Color`] to see through the
+    /// window. Transparency depends on the platform and the graphics backend,
+    /// and it cannot be changed once the window is created.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    /// [`Color`]: struct.Color.html
+    pub transparent: bool,
+
+    /// Defines whether or not the window should have a title bar and borders.
+    ///
+    /// It can be changed later with [`Window::set_decorations`].
+    ///
+    /// [`Window::set_decorations`]: struct.Window.html#method.set_decorations
+    pub decorations: bool,
+
+    /// Defines whether or not the window should stay on top of other windows.
+    ///
+    /// It can be changed later with [`Window::set_always_on_top`].
+    ///
+    /// [`Window::set_always_on_top`]: struct.Window.html#method.set_always_on_top
+    pub always_on_top: bool,
+
+    /// The minimum size of the window, in pixels, if any.
+    ///
+    /// It can be changed later with [`Window::set_min_size`].
+    ///
+    /// [`Window::set_min_size`]: struct.Window.html#method.set_min_size
+    pub min_size: Option<(u32, u32)>,
+
+    /// The maximum size of the window, in pixels, if any.
+    ///
+    /// It can be changed later with [`Window::set_max_size`].
+    ///
+    /// [`Window::set_max_size`]: struct.Window.html#method.set_max_size
+    pub max_size: Option<(u32, u32)>,
+
+    /// The aspect ratio the window keeps when resized, as a `(width, height)`
+    /// pair like `(16, 9)`, if any.
+    ///
+    /// It can be changed later with [`Window::set_aspect_ratio`].
+    ///
+    /// [`Window::set_aspect_ratio`]: struct.Window.html#method.set_aspect_ratio
+    pub aspect_ratio: Option<(u32, u32)>,
+}
+
+impl Default for Settings {
+    /// Returns a resizable `1280x1024` window with vsync enabled and without
+    /// any other option.
+    fn default() -> Settings {
+        Settings {
+            title: String::from("Coffee"),
+            size: (1280, 1024),
+            resizable: true,
+            fullscreen: false,
+            maximized: false,
+            max_frames_per_second: None,
+            vsync: true,
+            samples: None,
+            icon: None,
+            transparent: false,
+            decorations: true,
+            always_on_top: false,
+            min_size: None,
+            max_size: None,
+            aspect_ratio: None,
+        }
+    }
 }
 
 impl Settings {
+    /// The maximum width or height of a window loaded from a file.
+    #[cfg(feature = "serialize")]
+    const MAX_SIZE: u32 = 16384;
+
+    /// Loads window settings from the TOML file at the given path.
+    ///
+    /// Any setting that is missing is taken from `defaults` instead, and
+    /// sizes, `max_frames_per_second`, and `samples` are clamped to a valid
+    /// range. If the file cannot be read or parsed, `defaults` is returned.
+    /// This way, a corrupted file never stops your game from starting.
+    #[cfg(feature = "serialize")]
+    pub fn load<P: AsRef<Path>>(path: P, defaults: Settings) -> Settings {
+        let table = match fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| contents.parse::<toml::Value>().ok())
+        {
+            Some(toml::Value::Table(table)) => table,
+            _ => return defaults,
+        };
+
+        // The keys missing from the file are filled in from `defaults`. Its
+        // `None` values are not serialized, so they fall back to the
+        // `#[serde(default)]` of `Settings`, which is `None` as well.
+        let mut settings = match toml::Value::try_from(&defaults) {
+            Ok(toml::Value::Table(settings)) => settings,
+            _ => return defaults,
+        };
+
+        settings.extend(table);
+
+        match toml::Value::Table(settings).try_into::<Settings>() {
+            Ok(settings) => settings.clamped(),
+            Err(_) => defaults,
+        }
+    }
+
+    #[cfg(feature = "serialize")]
+    fn clamped(self) -> Settings {
+        let dimensions = |(width, height): (u32, u32)| {
+            (
+                width.clamp(1, Self::MAX_SIZE),
+                height.clamp(1, Self::MAX_SIZE),
+            )
+        };
+
+        Settings {
+            size: dimensions(self.size),
+            max_frames_per_second: self
+                .max_frames_per_second
+                .map(|fps| fps.max(1)),
+            samples: self.samples.map(|samples| samples.max(1)),
+            min_size: self.min_size.map(dimensions),
+            max_size: self.max_size.map(dimensions),
+            aspect_ratio: self.aspect_ratio.map(dimensions),
+            ..self
+        }
+    }
+
+    /// Saves the window settings to a TOML file at the given path.
+    #[cfg(feature = "serialize")]
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        // Converting to a `Value` first emits nested tables, like the one of
+        // an `Icon::Rgba`, after the plain values
+        let contents = toml::Value::try_from(self)
+            .and_then(|value| toml::to_string(&value))
+            .map_err(|error| {
+                io::Error::new(io::ErrorKind::InvalidData, error)
+            })?;
+
+        Ok(fs::write(path, contents)?)
+    }
+
     pub(super) fn into_builder(
         self,
-        events_loop: &winit::event_loop::EventLoop<()>,
-    ) -> winit::window::WindowBuilder {
+        primary_monitor: winit::monitor::MonitorHandle,
+    ) -> Result<winit::window::WindowBuilder> {
         let monitor = if self.fullscreen {
-            Some(events_loop.primary_monitor())
+            Some(primary_monitor)
         } else {
             None
         };
 
-        winit::window::WindowBuilder::new()
+        let icon = match self.icon {
+            Some(icon) => Some(icon.into_winit()?),
+            None => None,
+        };
+
+        let mut builder = winit::window::WindowBuilder::new()
             .with_title(self.title)
             .with_inner_size(winit::dpi::PhysicalSize {
                 width: self.size.0,
@@ -39,5 +238,117 @@ impl Settings {
             .with_resizable(self.resizable)
             .with_fullscreen(monitor.map(winit::window::Fullscreen::Borderless))
             .with_maximized(self.maximized)
+            .with_window_icon(icon)
+            .with_transparent(self.transparent)
+            .with_decorations(self.decorations)
+            .with_always_on_top(self.always_on_top);
+
+        if let Some((width, height)) = self.min_size {
+            builder = builder.with_min_inner_size(winit::dpi::PhysicalSize {
+                width,
+                height,
+            });
+        }
+
+        if let Some((width, height)) = self.max_size {
+            builder = builder.with_max_inner_size(winit::dpi::PhysicalSize {
+                width,
+                height,
+            });
+        }
+
+        Ok(builder)
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use super::*;
+
+    fn load(name: &str, contents: &str) -> Settings {
+        let path = std::env::temp_dir().join(format!("coffee-{}.toml", name));
+
+        fs::write(&path, contents).expect("Write settings");
+
+        let settings = Settings::load(&path, defaults());
+
+        fs::remove_file(&path).expect("Remove settings");
+
+        settings
+    }
+
+    fn defaults() -> Settings {
+        Settings {
+            title: String::from("Defaults"),
+            size: (640, 480),
+            vsync: false,
+            samples: Some(4),
+            ..Settings::default()
+        }
+    }
+
+    #[test]
+    fn load_takes_missing_settings_from_defaults() {
+        let settings = load("missing", "title = \"Loaded\"\nfullscreen = true");
+
+        assert_eq!(
+            settings,
+            Settings {
+                title: String::from("Loaded"),
+                fullscreen: true,
+                ..defaults()
+            }
+        );
+    }
+
+    #[test]
+    fn load_clamps_out_of_range_settings() {
+        let settings = load(
+            "clamped",
+            "size = [0, 100000]\n\
+             max_frames_per_second = 0\n\
+             samples = 0\n\
+             min_size = [0, 0]\n\
+             aspect_ratio = [16, 0]",
+        );
+
+        assert_eq!(settings.size, (1, Settings::MAX_SIZE));
+        assert_eq!(settings.max_frames_per_second, Some(1));
+        assert_eq!(settings.samples, Some(1));
+        assert_eq!(settings.min_size, Some((1, 1)));
+        assert_eq!(settings.aspect_ratio, Some((16, 1)));
+    }
+
+    #[test]
+    fn load_returns_defaults_when_the_file_is_invalid() {
+        assert_eq!(load("invalid", "size = \"big\""), defaults());
+        assert_eq!(load("corrupted", "title = "), defaults());
+    }
+
+    #[test]
+    fn load_returns_defaults_when_the_file_is_missing() {
+        let path = std::env::temp_dir().join("coffee-nonexistent.toml");
+
+        assert_eq!(Settings::load(path, defaults()), defaults());
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let settings = Settings {
+            title: String::from("Saved"),
+            max_frames_per_second: Some(60),
+            max_size: Some((1920, 1080)),
+            ..Settings::default()
+        };
+
+        let path = std::env::temp_dir().join("coffee-round-trip.toml");
+
+        settings.save(&path).expect("Save settings");
+
+        let loaded = Settings::load(&path, Settings::default());
+
+        fs::remove_file(&path).expect("Remove settings");
+
+        assert_eq!(loaded, settings);
     }
 }
@@ -0,0 +1,27 @@
+/// The way a texture is sampled outside of its `[0.0, 1.0]` coordinates.
+///
+/// You can set the [`WrapMode`] of an [`Image`] with
+/// [`Image::set_wrap_mode`]. Then, use a [`Quad`] with a `source` exceeding
+/// the `[0.0, 1.0]` range to tile the [`Image`].
+///
+/// [`WrapMode`]: enum.WrapMode.html
+/// [`Image`]: struct.Image.html
+/// [`Image::set_wrap_mode`]: struct.Image.html#method.set_wrap_mode
+/// [`Quad`]: struct.Quad.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WrapMode {
+    /// The coordinates are clamped to the edges of the texture.
+    Clamp,
+
+    /// The texture is repeated.
+    Repeat,
+
+    /// The texture is repeated, mirroring it on every repetition.
+    Mirror,
+}
+
+impl Default for WrapMode {
+    fn default() -> WrapMode {
+        WrapMode::Clamp
+    }
+}
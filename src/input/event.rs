@@ -1,5 +1,9 @@
-use crate::input::{gamepad, keyboard, mouse, window};
+use crate::graphics::SecondaryWindow;
+use crate::input::{keyboard, mouse, touch, window};
 
+#[cfg(feature = "gamepad")]
+use crate::input::gamepad;
+#[cfg(feature = "gamepad")]
 use std::time::SystemTime;
 
 /// An input event.
@@ -12,7 +16,11 @@ use std::time::SystemTime;
 /// [`Game`]: ../trait.Game.html
 /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
 /// [`Input`]: trait.Input.html
+///
+/// New variants may be added by optional features, like `gamepad`, so this
+/// enum cannot be matched exhaustively.
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
@@ -20,7 +28,13 @@ pub enum Event {
     /// A mouse event
     Mouse(mouse::Event),
 
+    /// A touch event
+    Touch(touch::Event),
+
     /// A gamepad event
+    ///
+    /// Gamepad events are only available with the `gamepad` feature.
+    #[cfg(feature = "gamepad")]
     Gamepad {
         /// The gamepad identifier
         id: gamepad::Id,
@@ -34,4 +48,33 @@ pub enum Event {
 
     /// A window event
     Window(window::Event),
+
+    /// An event of a secondary window
+    Secondary {
+        /// The secondary window
+        window: SecondaryWindow,
+
+        /// The event
+        event: SecondaryEvent,
+    },
+}
+
+/// An input event of a secondary window.
+///
+/// See [`Window::spawn_secondary`].
+///
+/// [`Window::spawn_secondary`]: ../graphics/struct.Window.html#method.spawn_secondary
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SecondaryEvent {
+    /// A keyboard event
+    Keyboard(keyboard::Event),
+
+    /// A mouse event
+    Mouse(mouse::Event),
+
+    /// A window event
+    Window(window::Event),
+
+    /// The window was closed, or it could not be opened
+    Closed,
 }
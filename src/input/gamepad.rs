@@ -1,4 +1,6 @@
 //! Listen to gamepad events.
+//!
+//! This module is only available with the `gamepad` feature.
 
 mod event;
 
@@ -7,7 +9,10 @@ pub use event::Event;
 pub use gilrs::Axis;
 pub use gilrs::Button;
 
+use super::{Event as InputEvent, Input};
+
 use gilrs::Gilrs;
+use std::collections::{HashMap, HashSet};
 use std::convert::TryInto;
 use std::time::SystemTime;
 
@@ -15,6 +20,141 @@ use std::time::SystemTime;
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub struct Id(gilrs::GamepadId);
 
+/// A simple gamepad input tracker.
+///
+/// It keeps the state of every connected gamepad. Axis values inside the
+/// deadzone are reported as `0.0`, which stops worn sticks from drifting.
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct Gamepad {
+    deadzone: f32,
+    gamepads: HashMap<Id, State>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct State {
+    pressed_buttons: HashSet<Button>,
+    released_buttons: HashSet<Button>,
+    axes: HashMap<Axis, f32>,
+}
+
+impl Gamepad {
+    /// The default deadzone of the axes.
+    pub const DEFAULT_DEADZONE: f32 = 0.1;
+
+    /// Returns the deadzone of the axes.
+    pub fn deadzone(&self) -> f32 {
+        self.deadzone
+    }
+
+    /// Sets the deadzone of the axes, between `0.0` and `1.0`.
+    ///
+    /// Axis values with a smaller magnitude are reported as `0.0`, and the
+    /// rest are rescaled to still cover the whole range.
+    pub fn set_deadzone(&mut self, deadzone: f32) {
+        self.deadzone = deadzone.max(0.0).min(0.99);
+    }
+
+    /// Returns the identifiers of the connected gamepads.
+    pub fn connected(&self) -> impl Iterator<Item = Id> + '_ {
+        self.gamepads.keys().copied()
+    }
+
+    /// Returns true if the given gamepad is connected.
+    pub fn is_connected(&self, id: Id) -> bool {
+        self.gamepads.contains_key(&id)
+    }
+
+    /// Returns true if the given button of a gamepad is currently pressed.
+    pub fn is_button_pressed(&self, id: Id, button: Button) -> bool {
+        self.gamepads
+            .get(&id)
+            .map(|state| state.pressed_buttons.contains(&button))
+            .unwrap_or(false)
+    }
+
+    /// Returns true if the given button of a gamepad was released during the
+    /// last interaction.
+    pub fn was_button_released(&self, id: Id, button: Button) -> bool {
+        self.gamepads
+            .get(&id)
+            .map(|state| state.released_buttons.contains(&button))
+            .unwrap_or(false)
+    }
+
+    /// Returns the value of the given axis of a gamepad, between `-1.0` and
+    /// `1.0`, with the deadzone applied.
+    pub fn axis(&self, id: Id, axis: Axis) -> f32 {
+        let value = self
+            .gamepads
+            .get(&id)
+            .and_then(|state| state.axes.get(&axis))
+            .copied()
+            .unwrap_or(0.0);
+
+        apply_deadzone(value, self.deadzone)
+    }
+}
+
+fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    if value.abs() < deadzone {
+        0.0
+    } else {
+        value.signum() * (value.abs() - deadzone) / (1.0 - deadzone)
+    }
+}
+
+impl Input for Gamepad {
+    fn new() -> Gamepad {
+        Gamepad {
+            deadzone: Self::DEFAULT_DEADZONE,
+            gamepads: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, event: InputEvent) {
+        match event {
+            InputEvent::Gamepad { id, event, .. } => match event {
+                Event::Connected => {
+                    let _ = self.gamepads.insert(id, State::default());
+                }
+                Event::Disconnected => {
+                    let _ = self.gamepads.remove(&id);
+                }
+                Event::ButtonPressed(button) => {
+                    let state = self.gamepads.entry(id).or_default();
+
+                    let _ = state.pressed_buttons.insert(button);
+                }
+                Event::ButtonReleased(button) => {
+                    let state = self.gamepads.entry(id).or_default();
+
+                    let _ = state.pressed_buttons.remove(&button);
+                    let _ = state.released_buttons.insert(button);
+                }
+                Event::ButtonChanged(_, _) => {}
+                Event::AxisChanged(axis, value) => {
+                    let state = self.gamepads.entry(id).or_default();
+
+                    let _ = state.axes.insert(axis, value);
+                }
+            },
+            _ => {
+                // Ignore other events...
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        for state in self.gamepads.values_mut() {
+            state.released_buttons.clear();
+        }
+    }
+}
+
 pub(crate) struct Tracker {
     context: Gilrs,
 }
@@ -52,3 +192,43 @@ impl Tracker {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_inside_the_deadzone_are_zero() {
+        assert_eq!(apply_deadzone(0.0, 0.1), 0.0);
+        assert_eq!(apply_deadzone(0.05, 0.1), 0.0);
+        assert_eq!(apply_deadzone(-0.099, 0.1), 0.0);
+    }
+
+    #[test]
+    fn values_outside_the_deadzone_cover_the_whole_range() {
+        assert_eq!(apply_deadzone(0.1, 0.1), 0.0);
+        assert_eq!(apply_deadzone(1.0, 0.1), 1.0);
+        assert_eq!(apply_deadzone(-1.0, 0.1), -1.0);
+        assert!((apply_deadzone(0.55, 0.1) - 0.5).abs() < 1e-6);
+        assert!((apply_deadzone(-0.55, 0.1) + 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn no_deadzone_keeps_values() {
+        assert_eq!(apply_deadzone(0.01, 0.0), 0.01);
+        assert_eq!(apply_deadzone(-0.5, 0.0), -0.5);
+    }
+
+    #[test]
+    fn set_deadzone_clamps() {
+        let mut gamepad = Gamepad::new();
+
+        assert_eq!(gamepad.deadzone(), Gamepad::DEFAULT_DEADZONE);
+
+        gamepad.set_deadzone(-1.0);
+        assert_eq!(gamepad.deadzone(), 0.0);
+
+        gamepad.set_deadzone(1.0);
+        assert_eq!(gamepad.deadzone(), 0.99);
+    }
+}
@@ -0,0 +1,325 @@
+use super::keyboard::{self, KeyCode};
+use super::{mouse, ButtonState, Event, Input};
+
+#[cfg(feature = "gamepad")]
+use super::gamepad;
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+
+/// An abstract action of your game, like jumping or moving left.
+///
+/// Implement this trait for your own action type to use it with an
+/// [`InputMap`].
+///
+/// [`InputMap`]: struct.InputMap.html
+pub trait Action: Copy + Eq + Hash + fmt::Debug {
+    /// Returns the bindings of a new [`InputMap`].
+    ///
+    /// An action can have multiple bindings.
+    ///
+    /// [`InputMap`]: struct.InputMap.html
+    fn default_bindings() -> Vec<(Self, Binding)>;
+}
+
+/// A physical input that can trigger an [`Action`].
+///
+/// New variants may be added by optional features, like `gamepad`, so this
+/// enum cannot be matched exhaustively.
+///
+/// [`Action`]: trait.Action.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Binding {
+    /// A keyboard key
+    Key(KeyCode),
+
+    /// A mouse button
+    MouseButton(mouse::Button),
+
+    /// A button of any gamepad
+    #[cfg(feature = "gamepad")]
+    GamepadButton(gamepad::Button),
+}
+
+/// An input tracker that maps physical inputs to the actions of your game.
+///
+/// Query the state of your actions in [`Game::interact`] instead of
+/// hardcoding keys, so that players can rebind them at runtime.
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`Game::interact`]: ../trait.Game.html#method.interact
+/// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct InputMap<A: Action> {
+    bindings: HashMap<A, Vec<Binding>>,
+    pressed: HashSet<Binding>,
+    just_pressed: HashSet<Binding>,
+    just_released: HashSet<Binding>,
+    capturing: Option<A>,
+}
+
+impl<A: Action> InputMap<A> {
+    /// Returns true if any binding of the given action is currently pressed.
+    pub fn is_pressed(&self, action: A) -> bool {
+        self.any(action, &self.pressed)
+    }
+
+    /// Returns true if any binding of the given action was pressed during the
+    /// last interaction.
+    pub fn was_pressed(&self, action: A) -> bool {
+        self.any(action, &self.just_pressed)
+    }
+
+    /// Returns true if any binding of the given action was released during
+    /// the last interaction.
+    pub fn was_released(&self, action: A) -> bool {
+        self.any(action, &self.just_released)
+    }
+
+    /// Returns the bindings of the given action.
+    pub fn bindings(&self, action: A) -> &[Binding] {
+        self.bindings.get(&action).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Adds a binding to the given action.
+    pub fn bind(&mut self, action: A, binding: Binding) {
+        let bindings = self.bindings.entry(action).or_insert_with(Vec::new);
+
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Removes a binding from the given action.
+    pub fn unbind(&mut self, action: A, binding: Binding) {
+        if let Some(bindings) = self.bindings.get_mut(&action) {
+            bindings.retain(|b| *b != binding);
+        }
+    }
+
+    /// Removes all the bindings of the given action.
+    pub fn clear_bindings(&mut self, action: A) {
+        let _ = self.bindings.remove(&action);
+    }
+
+    /// Replaces the bindings of the given action with the next pressed input.
+    ///
+    /// The captured input does not trigger any action.
+    pub fn capture(&mut self, action: A) {
+        self.capturing = Some(action);
+    }
+
+    /// Returns true if the [`InputMap`] is waiting for an input to capture.
+    ///
+    /// [`InputMap`]: struct.InputMap.html
+    pub fn is_capturing(&self) -> bool {
+        self.capturing.is_some()
+    }
+
+    fn any(&self, action: A, set: &HashSet<Binding>) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|binding| set.contains(binding))
+    }
+
+    fn press(&mut self, binding: Binding) {
+        if let Some(action) = self.capturing.take() {
+            let _ = self.bindings.insert(action, vec![binding]);
+            return;
+        }
+
+        if self.pressed.insert(binding) {
+            let _ = self.just_pressed.insert(binding);
+        }
+    }
+
+    fn release(&mut self, binding: Binding) {
+        if self.pressed.remove(&binding) {
+            let _ = self.just_released.insert(binding);
+        }
+    }
+}
+
+impl<A: Action> Input for InputMap<A> {
+    fn new() -> InputMap<A> {
+        let mut input_map = InputMap {
+            bindings: HashMap::new(),
+            pressed: HashSet::new(),
+            just_pressed: HashSet::new(),
+            just_released: HashSet::new(),
+            capturing: None,
+        };
+
+        for (action, binding) in A::default_bindings() {
+            input_map.bind(action, binding);
+        }
+
+        input_map
+    }
+
+    fn update(&mut self, event: Event) {
+        match event {
+            Event::Keyboard(keyboard::Event::Input { key_code, state }) => {
+                match state {
+                    ButtonState::Pressed => self.press(Binding::Key(key_code)),
+                    ButtonState::Released => {
+                        self.release(Binding::Key(key_code))
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::Input { state, button }) => {
+                match state {
+                    ButtonState::Pressed => {
+                        self.press(Binding::MouseButton(button))
+                    }
+                    ButtonState::Released => {
+                        self.release(Binding::MouseButton(button))
+                    }
+                }
+            }
+            #[cfg(feature = "gamepad")]
+            Event::Gamepad { event, .. } => match event {
+                gamepad::Event::ButtonPressed(button) => {
+                    self.press(Binding::GamepadButton(button))
+                }
+                gamepad::Event::ButtonReleased(button) => {
+                    self.release(Binding::GamepadButton(button))
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn clear(&mut self) {
+        self.just_pressed.clear();
+        self.just_released.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum TestAction {
+        Jump,
+        Shoot,
+    }
+
+    impl Action for TestAction {
+        fn default_bindings() -> Vec<(TestAction, Binding)> {
+            vec![
+                (TestAction::Jump, Binding::Key(KeyCode::Space)),
+                (TestAction::Jump, Binding::Key(KeyCode::W)),
+                (TestAction::Shoot, Binding::MouseButton(mouse::Button::Left)),
+            ]
+        }
+    }
+
+    fn key(key_code: KeyCode, state: ButtonState) -> Event {
+        Event::Keyboard(keyboard::Event::Input { key_code, state })
+    }
+
+    #[test]
+    fn press_and_release() {
+        let mut input_map = InputMap::<TestAction>::new();
+
+        input_map.update(key(KeyCode::W, ButtonState::Pressed));
+
+        assert!(input_map.is_pressed(TestAction::Jump));
+        assert!(input_map.was_pressed(TestAction::Jump));
+        assert!(!input_map.is_pressed(TestAction::Shoot));
+
+        input_map.clear();
+
+        assert!(input_map.is_pressed(TestAction::Jump));
+        assert!(!input_map.was_pressed(TestAction::Jump));
+
+        input_map.update(key(KeyCode::W, ButtonState::Released));
+
+        assert!(!input_map.is_pressed(TestAction::Jump));
+        assert!(input_map.was_released(TestAction::Jump));
+
+        input_map.clear();
+
+        assert!(!input_map.was_released(TestAction::Jump));
+    }
+
+    #[test]
+    fn repeated_press_is_not_pressed_again() {
+        let mut input_map = InputMap::<TestAction>::new();
+
+        input_map.update(key(KeyCode::Space, ButtonState::Pressed));
+        input_map.clear();
+        input_map.update(key(KeyCode::Space, ButtonState::Pressed));
+
+        assert!(input_map.is_pressed(TestAction::Jump));
+        assert!(!input_map.was_pressed(TestAction::Jump));
+    }
+
+    #[test]
+    fn mouse_buttons_trigger_actions() {
+        let mut input_map = InputMap::<TestAction>::new();
+
+        input_map.update(Event::Mouse(mouse::Event::Input {
+            state: ButtonState::Pressed,
+            button: mouse::Button::Left,
+        }));
+
+        assert!(input_map.was_pressed(TestAction::Shoot));
+    }
+
+    #[test]
+    fn capture_replaces_bindings() {
+        let mut input_map = InputMap::<TestAction>::new();
+
+        input_map.capture(TestAction::Jump);
+
+        assert!(input_map.is_capturing());
+
+        input_map.update(key(KeyCode::Up, ButtonState::Pressed));
+
+        assert!(!input_map.is_capturing());
+        assert_eq!(
+            input_map.bindings(TestAction::Jump),
+            &[Binding::Key(KeyCode::Up)]
+        );
+
+        // The captured input does not trigger the action
+        assert!(!input_map.is_pressed(TestAction::Jump));
+
+        input_map.update(key(KeyCode::Up, ButtonState::Released));
+        input_map.update(key(KeyCode::Space, ButtonState::Pressed));
+
+        assert!(!input_map.was_released(TestAction::Jump));
+        assert!(!input_map.is_pressed(TestAction::Jump));
+    }
+
+    #[test]
+    fn bind_and_unbind() {
+        let mut input_map = InputMap::<TestAction>::new();
+
+        input_map.bind(TestAction::Shoot, Binding::Key(KeyCode::X));
+        input_map.bind(TestAction::Shoot, Binding::Key(KeyCode::X));
+
+        assert_eq!(input_map.bindings(TestAction::Shoot).len(), 2);
+
+        input_map.unbind(
+            TestAction::Shoot,
+            Binding::MouseButton(mouse::Button::Left),
+        );
+
+        assert_eq!(
+            input_map.bindings(TestAction::Shoot),
+            &[Binding::Key(KeyCode::X)]
+        );
+
+        input_map.clear_bindings(TestAction::Shoot);
+
+        assert!(input_map.bindings(TestAction::Shoot).is_empty());
+    }
+}
@@ -45,6 +45,9 @@ impl Input for Keyboard {
             InputEvent::Mouse { .. } => {
                 // Ignore mouse events...
             }
+            InputEvent::Touch { .. } => {
+                // Ignore touch events...
+            }
             InputEvent::Keyboard(keyboard_event) => match keyboard_event {
                 Event::Input { key_code, state } => {
                     match state {
@@ -59,12 +62,16 @@ impl Input for Keyboard {
                 }
                 Event::TextEntered { .. } => {}
             },
+            #[cfg(feature = "gamepad")]
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
             InputEvent::Window { .. } => {
                 // Ignore window events...
             }
+            InputEvent::Secondary { .. } => {
+                // Ignore secondary window events...
+            }
         }
     }
 
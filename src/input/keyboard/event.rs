@@ -14,6 +14,15 @@ pub enum Event {
     },
 
     /// Text was entered.
+    ///
+    /// It takes the keyboard layout into account, and it is also sent for
+    /// the text committed by an input method editor (IME). Use it instead of
+    /// [`Input`] for text fields.
+    ///
+    /// The text being composed with an IME is not reported yet, as `winit`
+    /// does not expose composition events.
+    ///
+    /// [`Input`]: #variant.Input
     TextEntered {
         /// The character entered
         character: char,
@@ -127,16 +127,27 @@ impl Input for Mouse {
                     self.wheel_movement.horizontal += delta_x;
                     self.wheel_movement.vertical += delta_y;
                 }
+                Event::WheelScrolledPixels { delta_x, delta_y } => {
+                    self.wheel_movement.horizontal_pixels += delta_x;
+                    self.wheel_movement.vertical_pixels += delta_y;
+                }
             },
             InputEvent::Keyboard { .. } => {
                 // Ignore keyboard events...
             }
+            InputEvent::Touch { .. } => {
+                // Ignore touch events...
+            }
+            #[cfg(feature = "gamepad")]
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
             InputEvent::Window { .. } => {
                 // Ignore window events...
             }
+            InputEvent::Secondary { .. } => {
+                // Ignore secondary window events...
+            }
         }
     }
 
@@ -144,5 +155,7 @@ impl Input for Mouse {
         self.button_clicks.values_mut().for_each(Vec::clear);
         self.wheel_movement.horizontal = 0.0;
         self.wheel_movement.vertical = 0.0;
+        self.wheel_movement.horizontal_pixels = 0.0;
+        self.wheel_movement.vertical_pixels = 0.0;
     }
 }
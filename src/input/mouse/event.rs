@@ -47,4 +47,17 @@ pub enum Event {
         /// The number of vertical lines scrolled
         delta_y: f32,
     },
+
+    /// The mouse wheel or a touchpad was scrolled by a precise amount of
+    /// pixels.
+    ///
+    /// Touchpads and some mice report scrolling this way instead of in lines,
+    /// allowing smooth scrolling and zooming.
+    WheelScrolledPixels {
+        /// The number of horizontal pixels scrolled
+        delta_x: f32,
+
+        /// The number of vertical pixels scrolled
+        delta_y: f32,
+    },
 }
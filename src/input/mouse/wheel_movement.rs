@@ -6,6 +6,12 @@ pub struct WheelMovement {
 
     /// The number of vertical lines scrolled
     pub vertical: f32,
+
+    /// The number of horizontal pixels scrolled
+    pub horizontal_pixels: f32,
+
+    /// The number of vertical pixels scrolled
+    pub vertical_pixels: f32,
 }
 
 impl WheelMovement {
@@ -14,6 +20,8 @@ impl WheelMovement {
         WheelMovement {
             horizontal,
             vertical,
+            horizontal_pixels: 0.0,
+            vertical_pixels: 0.0,
         }
     }
 }
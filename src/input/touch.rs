@@ -0,0 +1,82 @@
+//! Listen to touch events.
+
+mod event;
+
+pub use event::Event;
+
+use super::{Event as InputEvent, Input};
+use crate::graphics::Point;
+
+use std::collections::HashMap;
+
+/// A simple touchscreen input tracker.
+///
+/// You can use this as your [`Game::Input`] directly!
+///
+/// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct Touch {
+    fingers: HashMap<u64, Point>,
+    taps: Vec<Point>,
+}
+
+impl Touch {
+    /// Returns the identifiers and positions of the fingers currently
+    /// touching the screen.
+    pub fn fingers(&self) -> impl Iterator<Item = (u64, Point)> + '_ {
+        self.fingers.iter().map(|(id, position)| (*id, *position))
+    }
+
+    /// Returns the position of the given finger, if it is touching the
+    /// screen.
+    pub fn finger_position(&self, id: u64) -> Option<Point> {
+        self.fingers.get(&id).copied()
+    }
+
+    /// Returns true if any finger is currently touching the screen.
+    pub fn is_touching(&self) -> bool {
+        !self.fingers.is_empty()
+    }
+
+    /// Returns the positions where fingers were lifted during the last
+    /// interaction.
+    ///
+    /// Cancelled touches are not considered taps.
+    pub fn taps(&self) -> &[Point] {
+        &self.taps
+    }
+}
+
+impl Input for Touch {
+    fn new() -> Touch {
+        Touch {
+            fingers: HashMap::new(),
+            taps: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, event: InputEvent) {
+        match event {
+            InputEvent::Touch(touch_event) => match touch_event {
+                Event::Started { id, x, y } | Event::Moved { id, x, y } => {
+                    let _ = self.fingers.insert(id, Point::new(x, y));
+                }
+                Event::Ended { id, x, y } => {
+                    let _ = self.fingers.remove(&id);
+
+                    self.taps.push(Point::new(x, y));
+                }
+                Event::Cancelled { id, .. } => {
+                    let _ = self.fingers.remove(&id);
+                }
+            },
+            _ => {
+                // Ignore other events...
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.taps.clear();
+    }
+}
@@ -0,0 +1,53 @@
+/// A touch event.
+///
+/// Every finger touching the screen has a unique `id` until it is lifted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Event {
+    /// A finger touched the screen.
+    Started {
+        /// The finger identifier
+        id: u64,
+
+        /// The X coordinate of the touch
+        x: f32,
+
+        /// The Y coordinate of the touch
+        y: f32,
+    },
+
+    /// A finger moved on the screen.
+    Moved {
+        /// The finger identifier
+        id: u64,
+
+        /// The X coordinate of the touch
+        x: f32,
+
+        /// The Y coordinate of the touch
+        y: f32,
+    },
+
+    /// A finger was lifted from the screen.
+    Ended {
+        /// The finger identifier
+        id: u64,
+
+        /// The X coordinate of the touch
+        x: f32,
+
+        /// The Y coordinate of the touch
+        y: f32,
+    },
+
+    /// The system cancelled the tracking of a finger.
+    Cancelled {
+        /// The finger identifier
+        id: u64,
+
+        /// The X coordinate of the touch
+        x: f32,
+
+        /// The Y coordinate of the touch
+        y: f32,
+    },
+}
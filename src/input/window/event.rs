@@ -7,6 +7,17 @@ pub enum Event {
     /// The game window lost focus.
     Unfocused,
 
+    /// The game window was resized.
+    ///
+    /// The size satisfies the aspect ratio of the window, if it is locked.
+    Resized {
+        /// The new width of the window
+        width: f32,
+
+        /// The new height of the window
+        height: f32,
+    },
+
     /// The game window was moved.
     Moved {
         /// The new X coordinate of the window
@@ -15,4 +26,37 @@ pub enum Event {
         /// The new Y coordinate of the window
         y: f32,
     },
+
+    /// The scale factor of the game window changed, usually because it was
+    /// moved to a monitor with a different DPI.
+    ScaleFactorChanged {
+        /// The new scale factor of the window
+        scale_factor: f32,
+    },
+
+    /// A file is being dragged over the game window.
+    ///
+    /// Its path can be obtained with [`Window::hovered_files`].
+    ///
+    /// [`Window::hovered_files`]: ../../graphics/struct.Window.html#method.hovered_files
+    FileHovered,
+
+    /// The files dragged over the game window left it without being dropped.
+    FileHoverCancelled,
+
+    /// A file was dropped on the game window.
+    ///
+    /// Its path can be obtained with [`Window::take_dropped_files`].
+    ///
+    /// [`Window::take_dropped_files`]: ../../graphics/struct.Window.html#method.take_dropped_files
+    FileDropped,
+
+    /// The game was suspended by the system, usually because it was sent to
+    /// the background on a mobile device.
+    ///
+    /// The game is not updated nor drawn until it is resumed.
+    Suspended,
+
+    /// The game was resumed after being suspended.
+    Resumed,
 }
@@ -42,6 +42,7 @@
 //!         resizable: true,
 //!         fullscreen: false,
 //!         maximized: false,
+//!         ..WindowSettings::default()
 //!     })
 //! }
 //!
@@ -81,12 +82,19 @@ mod game;
 mod result;
 mod timer;
 
+#[cfg(feature = "audio")]
+pub mod audio;
+#[cfg(feature = "dialogs")]
+pub mod dialogs;
 pub mod graphics;
 pub mod input;
 pub mod load;
 pub mod ui;
 
 pub use debug::Debug;
-pub use game::Game;
+pub use game::{CloseBehavior, Game};
 pub use result::{Error, Result};
 pub use timer::Timer;
+
+#[cfg(feature = "raw")]
+pub use winit;
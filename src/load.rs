@@ -10,17 +10,26 @@
 //! keep track of all the work they have to complete before even executing them.
 //! Read the [`Task`] docs to learn more!
 //!
+//! # Asset sources
+//! Resources loaded from a path read their bytes from an [`AssetSource`]. You
+//! can change the source of a [`Gpu`] to load assets from an archive or from
+//! memory without changing any call site.
+//!
 //! # Loading screens
 //! The [`LoadingScreen`] trait allows you to implement a loading screen that is
 //! compatible with any [`Task`]. Currently, Coffee includes a built-in loading
 //! screen: [`ProgressBar`], which shows a simple progress bar with some text.
 //!
 //! [`Task`]: struct.Task.html
+//! [`AssetSource`]: trait.AssetSource.html
+//! [`Gpu`]: ../graphics/struct.Gpu.html
 //! [`LoadingScreen`]: loading_screen/trait.LoadingScreen.html
 //! [`ProgressBar`]: loading_screen/struct.ProgressBar.html
+mod asset_source;
 mod task;
 
 pub mod loading_screen;
 
+pub use asset_source::{AssetSource, Filesystem, Memory};
 pub use loading_screen::LoadingScreen;
 pub use task::{Join, Progress, Task};
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::Result;
+
+/// A source of asset files.
+///
+/// Resources loaded from a path, like an [`Image`] or a [`Font`], obtain
+/// their bytes from the [`AssetSource`] of the [`Gpu`]. By default, assets are
+/// read from the [`Filesystem`].
+///
+/// Implement this trait to load your assets from an archive, the network, or
+/// anywhere else. Then, register your source with [`Gpu::set_asset_source`].
+///
+/// [`Image`]: ../graphics/struct.Image.html
+/// [`Font`]: ../graphics/struct.Font.html
+/// [`AssetSource`]: trait.AssetSource.html
+/// [`Gpu`]: ../graphics/struct.Gpu.html
+/// [`Filesystem`]: struct.Filesystem.html
+/// [`Gpu::set_asset_source`]: ../graphics/struct.Gpu.html#method.set_asset_source
+pub trait AssetSource {
+    /// Reads the contents of the asset with the given logical path.
+    fn read(&self, path: &Path) -> Result<Vec<u8>>;
+
+    /// Returns the last modification time of the asset with the given logical
+    /// path, if the source supports it.
+    ///
+    /// It is used to hot reload assets during development. By default, it
+    /// returns `None`, which disables hot reloading.
+    fn modified(&self, _path: &Path) -> Option<SystemTime> {
+        None
+    }
+}
+
+/// An [`AssetSource`] that reads files from disk.
+///
+/// Logical paths are resolved relative to its root directory. The default
+/// [`Filesystem`] uses the current working directory.
+///
+/// [`AssetSource`]: trait.AssetSource.html
+/// [`Filesystem`]: struct.Filesystem.html
+#[derive(Debug, Clone, Default)]
+pub struct Filesystem {
+    root: PathBuf,
+}
+
+impl Filesystem {
+    /// Creates a new [`Filesystem`] source with the given root directory.
+    ///
+    /// [`Filesystem`]: struct.Filesystem.html
+    pub fn new<P: Into<PathBuf>>(root: P) -> Filesystem {
+        Filesystem { root: root.into() }
+    }
+}
+
+impl AssetSource for Filesystem {
+    fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        let mut reader = File::open(self.root.join(path))?;
+        let _ = reader.read_to_end(&mut buf)?;
+
+        Ok(buf)
+    }
+
+    fn modified(&self, path: &Path) -> Option<SystemTime> {
+        self.root.join(path).metadata().ok()?.modified().ok()
+    }
+}
+
+/// An [`AssetSource`] that keeps its files in memory.
+///
+/// It is useful to embed your assets in your executable with `include_bytes!`
+/// or to unpack an archive once.
+///
+/// [`AssetSource`]: trait.AssetSource.html
+#[derive(Debug, Clone, Default)]
+pub struct Memory {
+    files: HashMap<PathBuf, Vec<u8>>,
+}
+
+impl Memory {
+    /// Creates a new empty [`Memory`] source.
+    ///
+    /// [`Memory`]: struct.Memory.html
+    pub fn new() -> Memory {
+        Memory::default()
+    }
+
+    /// Adds a file with the given logical path to the [`Memory`] source,
+    /// replacing any previous file with the same path.
+    ///
+    /// [`Memory`]: struct.Memory.html
+    pub fn insert<P: Into<PathBuf>, B: Into<Vec<u8>>>(
+        &mut self,
+        path: P,
+        bytes: B,
+    ) {
+        let _ = self.files.insert(path.into(), bytes.into());
+    }
+}
+
+impl AssetSource for Memory {
+    fn read(&self, path: &Path) -> Result<Vec<u8>> {
+        match self.files.get(path) {
+            Some(bytes) => Ok(bytes.clone()),
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("asset not found: {}", path.display()),
+            )
+            .into()),
+        }
+    }
+}
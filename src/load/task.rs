@@ -1,3 +1,7 @@
+use rayon::prelude::*;
+
+use std::any::Any;
+
 use crate::graphics;
 use crate::Result;
 
@@ -70,7 +74,45 @@ use crate::Result;
 /// [`map`]: #method.map
 pub struct Task<T> {
     total_work: u32,
-    function: Box<dyn FnOnce(&mut Worker<'_>) -> Result<T>>,
+    function: Function<T>,
+}
+
+/// The operation of a [`Task`].
+///
+/// A staged operation is split in a read stage and an upload stage that run
+/// on the main thread, and a decode stage in between that can run on any
+/// thread. This allows [`Task::join_parallel`] to decode many assets at once.
+///
+/// The result of the upload stage is turned into the output of the operation
+/// by its `finish` function, also on the main thread. This way, [`Task::map`]
+/// keeps an operation staged without its function being `Send`.
+///
+/// [`Task`]: struct.Task.html
+/// [`Task::join_parallel`]: struct.Task.html#method.join_parallel
+/// [`Task::map`]: struct.Task.html#method.map
+enum Function<T> {
+    Sequential(Box<dyn FnOnce(&mut Worker<'_>) -> Result<T>>),
+    Staged {
+        read: Box<dyn FnOnce(&mut Worker<'_>) -> Result<Decode>>,
+        finish: Box<dyn FnOnce(Box<dyn Any>) -> T>,
+    },
+}
+
+type Decode = Box<dyn FnOnce() -> Result<Upload> + Send>;
+type Upload = Box<dyn FnOnce(&mut Worker<'_>) -> Result<Box<dyn Any>> + Send>;
+
+impl<T> Function<T> {
+    fn run(self, worker: &mut Worker<'_>) -> Result<T> {
+        match self {
+            Function::Sequential(function) => function(worker),
+            Function::Staged { read, finish } => {
+                let decode = read(worker)?;
+                let upload = decode()?;
+
+                Ok(finish(upload(worker)?))
+            }
+        }
+    }
 }
 
 impl<T> Task<T> {
@@ -98,16 +140,13 @@ impl<T> Task<T> {
     where
         F: 'static + FnOnce() -> Result<T>,
     {
-        Task {
-            total_work: 1,
-            function: Box::new(move |worker| {
-                let result = f();
+        Task::sequence(1, move |worker| {
+            let result = f();
 
-                worker.notify_progress(1);
+            worker.notify_progress(1);
 
-                result
-            }),
-        }
+            result
+        })
     }
 
     /// Creates a new [`Task`] from a lazy operation that cannot fail.
@@ -173,6 +212,117 @@ impl<T> Task<T> {
         }
     }
 
+    /// Creates a new [`Task`] that uses a [`Gpu`] in stages.
+    ///
+    /// `read` and `upload` run on the main thread, while `decode` can run on
+    /// any thread. Tasks created like this are decoded in parallel when
+    /// using [`Task::join_parallel`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    /// [`Task::join_parallel`]: #method.join_parallel
+    pub(crate) fn using_gpu_in_stages<A, B, R, D, U>(
+        read: R,
+        decode: D,
+        upload: U,
+    ) -> Task<T>
+    where
+        A: 'static + Send,
+        B: 'static + Send,
+        R: 'static + FnOnce(&mut graphics::Gpu) -> Result<A>,
+        D: 'static + Send + FnOnce(A) -> Result<B>,
+        U: 'static + Send + FnOnce(&mut graphics::Gpu, B) -> Result<T>,
+        T: 'static,
+    {
+        Task {
+            total_work: 1,
+            function: Function::Staged {
+                read: Box::new(move |worker| {
+                    let data = read(worker.gpu())?;
+
+                    Ok(Box::new(move || {
+                        let decoded = decode(data)?;
+
+                        Ok(Box::new(move |worker: &mut Worker<'_>| {
+                            let result = upload(worker.gpu(), decoded);
+
+                            worker.notify_progress(1);
+
+                            result.map(|value| Box::new(value) as Box<dyn Any>)
+                        }) as Upload)
+                    }) as Decode)
+                }),
+                finish: Box::new(|value| {
+                    *value.downcast::<T>().expect("Staged task output")
+                }),
+            },
+        }
+    }
+
+    /// Joins a list of tasks into a new one that collects their results.
+    ///
+    /// The decoding stage of the tasks that support it, like [`Image::load`],
+    /// runs in parallel on a thread pool. Reading their assets and uploading
+    /// them to the GPU still happens on the main thread, in order. Any other
+    /// task simply runs in order.
+    ///
+    /// Use this to load many images at once:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// #
+    /// let load_tiles = Task::join_parallel(
+    ///     (0..200)
+    ///         .map(|i| Image::load(format!("tiles/{}.png", i)))
+    ///         .collect(),
+    /// );
+    /// ```
+    ///
+    /// [`Image::load`]: ../graphics/struct.Image.html#method.load
+    pub fn join_parallel(tasks: Vec<Task<T>>) -> Task<Vec<T>>
+    where
+        T: 'static,
+    {
+        let total_work = tasks.iter().map(Task::total_work).sum();
+
+        Task::sequence(total_work, move |worker| {
+            let mut results = Vec::with_capacity(tasks.len());
+            let mut finishes = Vec::with_capacity(tasks.len());
+            let mut decodes = Vec::new();
+
+            for (i, task) in tasks.into_iter().enumerate() {
+                match task.function {
+                    Function::Sequential(function) => {
+                        results.push(Some(function(worker)?));
+                        finishes.push(None);
+                    }
+                    Function::Staged { read, finish } => {
+                        decodes.push((i, read(worker)?));
+                        results.push(None);
+                        finishes.push(Some(finish));
+                    }
+                }
+            }
+
+            let uploads: Vec<(usize, Result<Upload>)> = decodes
+                .into_par_iter()
+                .map(|(i, decode)| (i, decode()))
+                .collect();
+
+            for (i, upload) in uploads {
+                let finish = finishes[i].take().expect("Staged task finish");
+
+                results[i] = Some(finish(upload?(worker)?));
+            }
+
+            Ok(results
+                .into_iter()
+                .map(|result| result.expect("Task result"))
+                .collect())
+        })
+    }
+
     /// Adds a title to the [`Task`].
     ///
     /// The title will be used when reporting progress once the [`Task`] is run.
@@ -213,12 +363,14 @@ impl<T> Task<T> {
     {
         let title = title.into();
 
-        Task {
-            total_work: task.total_work,
-            function: Box::new(move |worker| {
-                worker.with_stage(title.clone(), task.function)
-            }),
-        }
+        let function = task.function;
+
+        Task::sequence(task.total_work, move |worker| {
+            worker.with_stage(
+                title.clone(),
+                Box::new(move |worker| function.run(worker)),
+            )
+        })
     }
 
     /// Returns the total units of work of the [`Task`].
@@ -240,12 +392,22 @@ impl<T> Task<T> {
         T: 'static,
         F: 'static + FnOnce(T) -> A,
     {
-        Task {
-            total_work: self.total_work,
-            function: Box::new(move |worker| match (self.function)(worker) {
-                Ok(value) => Ok(f(value)),
-                Err(error) => Err(error),
-            }),
+        match self.function {
+            Function::Sequential(function) => {
+                Task::sequence(self.total_work, move |worker| {
+                    match function(worker) {
+                        Ok(value) => Ok(f(value)),
+                        Err(error) => Err(error),
+                    }
+                })
+            }
+            Function::Staged { read, finish } => Task {
+                total_work: self.total_work,
+                function: Function::Staged {
+                    read,
+                    finish: Box::new(move |value| f(finish(value))),
+                },
+            },
         }
     }
 
@@ -255,7 +417,7 @@ impl<T> Task<T> {
     pub fn run(self, gpu: &mut graphics::Gpu) -> Result<T> {
         let mut worker = Worker::Headless(gpu);
 
-        (self.function)(&mut worker)
+        self.function.run(&mut worker)
     }
 
     /// Runs a [`Task`] and obtains the produced value.
@@ -286,7 +448,7 @@ impl<T> Task<T> {
 
         worker.notify_progress(0);
 
-        (self.function)(&mut worker)
+        self.function.run(&mut worker)
     }
 }
 
@@ -425,8 +587,10 @@ impl<A: 'static, B: 'static> Join for (Task<A>, Task<B>) {
         Task::sequence(
             loader_a.total_work() + loader_b.total_work(),
             move |task| {
-                (loader_a.function)(task)
-                    .and_then(|a| (loader_b.function)(task).map(|b| (a, b)))
+                loader_a
+                    .function
+                    .run(task)
+                    .and_then(|a| loader_b.function.run(task).map(|b| (a, b)))
             },
         )
     }
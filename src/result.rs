@@ -2,7 +2,7 @@ use std::error;
 use std::fmt;
 use std::io;
 
-use crate::graphics::texture_array;
+use crate::graphics::{sprite_sheet, texture_array};
 
 /// A convenient result with a locked [`Error`] type.
 ///
@@ -18,14 +18,39 @@ pub enum Error {
     /// The window creation failed.
     WindowCreation(String),
 
+    /// The creation of a headless `Gpu` failed.
+    GpuCreation(String),
+
     /// A texture array failed to load.
     TextureArray(texture_array::Error),
 
+    /// A sprite sheet failed to load.
+    SpriteSheet(sprite_sheet::Error),
+
     /// A file failed to load.
     IO(io::Error),
 
     /// An image failed to load.
     Image(image::ImageError),
+
+    /// A shader failed to load or compile.
+    Shader(String),
+
+    /// An audio operation failed.
+    #[cfg(feature = "audio")]
+    Audio(String),
+
+    /// An SVG file failed to load or render.
+    #[cfg(feature = "svg")]
+    Svg(String),
+
+    /// A system font could not be found or loaded.
+    #[cfg(feature = "system-fonts")]
+    SystemFont(String),
+
+    /// The clipboard could not be read or written.
+    #[cfg(feature = "clipboard")]
+    Clipboard(String),
 }
 
 impl fmt::Display for Error {
@@ -34,11 +59,28 @@ impl fmt::Display for Error {
             Error::WindowCreation(error) => {
                 write!(f, "Window creation error: {}", error)
             }
+            Error::GpuCreation(error) => {
+                write!(f, "Gpu creation error: {}", error)
+            }
             Error::TextureArray(error) => {
                 write!(f, "Texture array error: {}", error)
             }
+            Error::SpriteSheet(error) => {
+                write!(f, "Sprite sheet error: {}", error)
+            }
             Error::IO(error) => write!(f, "IO error: {}", error),
             Error::Image(error) => write!(f, "Image error: {}", error),
+            Error::Shader(error) => write!(f, "Shader error: {}", error),
+            #[cfg(feature = "audio")]
+            Error::Audio(error) => write!(f, "Audio error: {}", error),
+            #[cfg(feature = "svg")]
+            Error::Svg(error) => write!(f, "SVG error: {}", error),
+            #[cfg(feature = "system-fonts")]
+            Error::SystemFont(error) => {
+                write!(f, "System font error: {}", error)
+            }
+            #[cfg(feature = "clipboard")]
+            Error::Clipboard(error) => write!(f, "Clipboard error: {}", error),
         }
     }
 }
@@ -14,6 +14,7 @@ pub struct Timer {
     last_tick: time::Instant,
     accumulated_delta: time::Duration,
     has_ticked: bool,
+    is_paused: bool,
 }
 
 impl Timer {
@@ -30,6 +31,7 @@ impl Timer {
             last_tick: time::Instant::now(),
             accumulated_delta: time::Duration::from_secs(0),
             has_ticked: false,
+            is_paused: false,
         }
     }
 
@@ -38,12 +40,43 @@ impl Timer {
         let diff = now - self.last_tick;
 
         self.last_tick = now;
-        self.accumulated_delta += diff;
         self.has_ticked = false;
+
+        if !self.is_paused {
+            self.accumulated_delta += diff;
+        }
+    }
+
+    /// Pauses the [`Timer`], so it stops ticking.
+    ///
+    /// The [`Timer`] of the game loop is paused and resumed automatically to
+    /// match [`Game::is_paused`] before every tick, so return `true` from
+    /// [`Game::is_paused`] instead of pausing it directly.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`Game::is_paused`]: trait.Game.html#method.is_paused
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+    }
+
+    /// Resumes a paused [`Timer`].
+    ///
+    /// Time spent paused is discarded, so there is no burst of ticks after
+    /// resuming. Like [`pause`], the game loop keeps its own [`Timer`] in
+    /// sync with [`Game::is_paused`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`pause`]: #method.pause
+    /// [`Game::is_paused`]: trait.Game.html#method.is_paused
+    pub fn resume(&mut self) {
+        // Time spent paused is never accumulated, so resuming does not cause
+        // a burst of ticks.
+        self.last_tick = time::Instant::now();
+        self.is_paused = false;
     }
 
     pub(crate) fn tick(&mut self) -> bool {
-        if self.accumulated_delta >= self.target_delta {
+        if !self.is_paused && self.accumulated_delta >= self.target_delta {
             self.accumulated_delta -= self.target_delta;
             self.has_ticked = true;
 
@@ -66,6 +99,17 @@ impl Timer {
         self.has_ticked
     }
 
+    /// Returns `true` if the [`Timer`] is paused.
+    ///
+    /// A paused [`Timer`] does not tick, and therefore your game is not
+    /// updated. See [`Game::is_paused`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`Game::is_paused`]: trait.Game.html#method.is_paused
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
     /// Returns how close the next tick is.
     ///
     /// The returned value is in the `[0.0, 1.0]` interval. You should use this
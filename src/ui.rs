@@ -145,8 +145,8 @@ pub mod widget;
 pub use self::core::{Align, Justify};
 pub use renderer::{Configuration, Renderer};
 pub use widget::{
-    button, image, progress_bar, slider, Button, Checkbox, Image, ProgressBar,
-    Radio, Slider, Text,
+    button, image, measured, pick_list, progress_bar, slider, Button, Checkbox,
+    Image, PickList, ProgressBar, Radio, Slider, Text,
 };
 
 /// A [`Column`] using the built-in [`Renderer`].
@@ -167,6 +167,12 @@ pub type Row<'a, Message> = widget::Row<'a, Message, Renderer>;
 /// [`Renderer`]: struct.Renderer.html
 pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 
+/// A [`Measured`] widget using the built-in [`Renderer`].
+///
+/// [`Measured`]: widget/measured/struct.Measured.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Measured<'a, Message> = widget::Measured<'a, Message, Renderer>;
+
 /// An [`Element`] using the built-in [`Renderer`].
 ///
 /// [`Element`]: core/struct.Element.html
@@ -175,7 +181,7 @@ pub type Element<'a, Message> = self::core::Element<'a, Message, Renderer>;
 
 use crate::game::{self, Loop as _};
 use crate::graphics::{Point, Window, WindowSettings};
-use crate::input::{self, mouse, Input as _};
+use crate::input::{self, mouse, touch, ButtonState, Input as _};
 use crate::load::Task;
 use crate::ui::core::{Event, Interface, MouseCursor, Renderer as _};
 use crate::{Debug, Game, Result};
@@ -282,6 +288,55 @@ struct Loop<UI: UserInterface> {
     cache: Option<core::Cache>,
     cursor_position: Point,
     events: Vec<Event>,
+    primary_touch: Option<u64>,
+}
+
+impl<UI: UserInterface> Loop<UI> {
+    // The first finger touching the screen drives the cursor of the user
+    // interface, so widgets work with touchscreens as they do with a mouse
+    fn on_touch(&mut self, event: touch::Event) {
+        let (id, x, y, state) = match event {
+            touch::Event::Started { id, x, y } => {
+                if self.primary_touch.is_some() {
+                    return;
+                }
+
+                self.primary_touch = Some(id);
+
+                (id, x, y, Some(ButtonState::Pressed))
+            }
+            touch::Event::Moved { id, x, y } => (id, x, y, None),
+            touch::Event::Ended { id, x, y } => {
+                (id, x, y, Some(ButtonState::Released))
+            }
+            touch::Event::Cancelled { id, .. } => {
+                if self.primary_touch == Some(id) {
+                    self.primary_touch = None;
+                }
+
+                return;
+            }
+        };
+
+        if self.primary_touch != Some(id) {
+            return;
+        }
+
+        self.cursor_position = Point::new(x, y);
+        self.events
+            .push(Event::Mouse(mouse::Event::CursorMoved { x, y }));
+
+        if let Some(state) = state {
+            self.events.push(Event::Mouse(mouse::Event::Input {
+                state,
+                button: mouse::Button::Left,
+            }));
+        }
+
+        if state == Some(ButtonState::Released) {
+            self.primary_touch = None;
+        }
+    }
 }
 
 impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
@@ -296,6 +351,7 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
             cache: Some(cache),
             cursor_position: Point::new(0.0, 0.0),
             events: Vec::new(),
+            primary_touch: None,
         }
     }
 
@@ -310,6 +366,9 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
             input::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
                 self.cursor_position = Point::new(x, y);
             }
+            input::Event::Touch(touch_event) => {
+                self.on_touch(touch_event);
+            }
             _ => {}
         };
 
@@ -336,7 +395,7 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         let messages = &mut self.messages;
 
         self.events.drain(..).for_each(|event| {
-            interface.on_event(event, cursor_position, messages)
+            let _ = interface.on_event(event, cursor_position, messages);
         });
 
         let new_cursor = interface.draw(
@@ -20,7 +20,7 @@ mod widget;
 pub use stretch::{geometry::Size, number::Number};
 
 pub use element::Element;
-pub use event::Event;
+pub use event::{Event, EventStatus};
 pub use hasher::Hasher;
 pub(crate) use interface::{Cache, Interface};
 pub use layout::Layout;
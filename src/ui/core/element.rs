@@ -1,7 +1,9 @@
 use stretch::{geometry, result};
 
 use crate::graphics::{Color, Point};
-use crate::ui::core::{self, Event, Hasher, Layout, MouseCursor, Node, Widget};
+use crate::ui::core::{
+    self, Event, EventStatus, Hasher, Layout, MouseCursor, Node, Widget,
+};
 
 /// A generic [`Widget`].
 ///
@@ -134,7 +136,7 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
     /// these modules to compose them together freely.
     pub fn map<F, B>(self, f: F) -> Element<'a, B, Renderer>
     where
-        Message: 'static + Copy,
+        Message: 'static,
         Renderer: 'a,
         B: 'static,
         F: 'static + Fn(Message) -> B,
@@ -198,10 +200,7 @@ impl<'a, A, B, Renderer> Map<'a, A, B, Renderer> {
     }
 }
 
-impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer>
-where
-    A: Copy,
-{
+impl<'a, A, B, Renderer> Widget<B, Renderer> for Map<'a, A, B, Renderer> {
     fn node(&self, renderer: &Renderer) -> Node {
         self.widget.node(renderer)
     }
@@ -212,20 +211,19 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<B>,
-    ) {
+    ) -> EventStatus {
         let mut original_messages = Vec::new();
 
-        self.widget.on_event(
+        let status = self.widget.on_event(
             event,
             layout,
             cursor_position,
             &mut original_messages,
         );
 
-        original_messages
-            .iter()
-            .cloned()
-            .for_each(|message| messages.push((self.mapper)(message)));
+        messages.extend(original_messages.into_iter().map(&self.mapper));
+
+        status
     }
 
     fn draw(
@@ -276,7 +274,7 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
         self.element
             .widget
             .on_event(event, layout, cursor_position, messages)
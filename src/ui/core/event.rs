@@ -1,11 +1,18 @@
-use crate::input::{self, gamepad, keyboard, mouse};
+use crate::input::{self, keyboard, mouse};
+
+#[cfg(feature = "gamepad")]
+use crate::input::gamepad;
 
 /// A user interface event.
 ///
 /// This is a subset of [`input::Event`].
 ///
+/// New variants may be added by optional features, like `gamepad`, so this
+/// enum cannot be matched exhaustively.
+///
 /// [`input::Event`]: ../../input/enum.Event.html
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[non_exhaustive]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
@@ -14,6 +21,7 @@ pub enum Event {
     Mouse(mouse::Event),
 
     /// A gamepad event
+    #[cfg(feature = "gamepad")]
     Gamepad {
         /// The gamepad identifier
         id: gamepad::Id,
@@ -23,6 +31,23 @@ pub enum Event {
     },
 }
 
+/// The status of an [`Event`] after being processed by a [`Widget`].
+///
+/// [`Event`]: enum.Event.html
+/// [`Widget`]: trait.Widget.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    /// The [`Event`] was handled and it should not be propagated any further.
+    ///
+    /// [`Event`]: enum.Event.html
+    Captured,
+
+    /// The [`Event`] was not handled and it may be processed by other widgets.
+    ///
+    /// [`Event`]: enum.Event.html
+    Ignored,
+}
+
 impl Event {
     pub(crate) fn from_input(event: input::Event) -> Option<Event> {
         match event {
@@ -30,6 +55,7 @@ impl Event {
                 Some(Event::Keyboard(keyboard_event))
             }
             input::Event::Mouse(mouse_event) => Some(Event::Mouse(mouse_event)),
+            #[cfg(feature = "gamepad")]
             input::Event::Gamepad { id, event, .. } => {
                 Some(Event::Gamepad { id, event })
             }
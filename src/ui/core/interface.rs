@@ -2,7 +2,7 @@ use std::hash::Hasher;
 use stretch::result;
 
 use crate::graphics::{Frame, Point};
-use crate::ui::core::{self, Element, Event, Layout, MouseCursor};
+use crate::ui::core::{self, Element, Event, EventStatus, Layout, MouseCursor};
 
 pub struct Interface<'a, Message, Renderer> {
     hash: u64,
@@ -56,7 +56,7 @@ where
         event: Event,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
         let Interface { root, layout, .. } = self;
 
         root.widget.on_event(
@@ -64,7 +64,7 @@ where
             Self::layout(layout),
             cursor_position,
             messages,
-        );
+        )
     }
 
     pub fn draw(
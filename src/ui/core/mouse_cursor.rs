@@ -20,6 +20,13 @@ pub enum MouseCursor {
 
     /// The cursor is grabbing a widget.
     Grabbing,
+
+    /// The cursor is over a widget containing editable text.
+    Text,
+
+    /// The cursor is over a widget that can be resized or dragged
+    /// horizontally.
+    ResizeHorizontal,
 }
 
 #[doc(hidden)]
@@ -32,6 +39,10 @@ impl From<MouseCursor> for winit::window::CursorIcon {
             MouseCursor::Working => winit::window::CursorIcon::Progress,
             MouseCursor::Grab => winit::window::CursorIcon::Grab,
             MouseCursor::Grabbing => winit::window::CursorIcon::Grabbing,
+            MouseCursor::Text => winit::window::CursorIcon::Text,
+            MouseCursor::ResizeHorizontal => {
+                winit::window::CursorIcon::EwResize
+            }
         }
     }
 }
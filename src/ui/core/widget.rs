@@ -1,5 +1,5 @@
 use crate::graphics::Point;
-use crate::ui::core::{Event, Hasher, Layout, MouseCursor, Node};
+use crate::ui::core::{Event, EventStatus, Hasher, Layout, MouseCursor, Node};
 
 /// A component that displays information or allows interaction.
 ///
@@ -58,17 +58,24 @@ pub trait Widget<Message, Renderer>: std::fmt::Debug {
     ///   * a mutable `Message` vector, allowing the [`Widget`] to produce
     ///   new messages based on user interaction.
     ///
-    /// By default, it does nothing.
+    /// It must return [`EventStatus::Captured`] if the [`Event`] has been
+    /// handled by the [`Widget`]. Then, the [`Event`] will not be propagated
+    /// to any other widgets.
+    ///
+    /// By default, it does nothing and returns [`EventStatus::Ignored`].
     ///
     /// [`Event`]: enum.Event.html
     /// [`Widget`]: trait.Widget.html
     /// [`Layout`]: struct.Layout.html
+    /// [`EventStatus::Captured`]: enum.EventStatus.html#variant.Captured
+    /// [`EventStatus::Ignored`]: enum.EventStatus.html#variant.Ignored
     fn on_event(
         &mut self,
         _event: Event,
         _layout: Layout<'_>,
         _cursor_position: Point,
         _messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
+        EventStatus::Ignored
     }
 }
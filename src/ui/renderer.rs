@@ -2,12 +2,16 @@ mod button;
 mod checkbox;
 mod image;
 mod panel;
+mod pick_list;
 mod progress_bar;
 mod radio;
 mod slider;
 mod text;
 
-use crate::graphics::{Batch, Color, Font, Frame, Image, Mesh, Shape};
+use crate::graphics::{
+    Batch, Color, Font, Frame, HorizontalAlignment, Image, Mesh, Point,
+    Rectangle, Shape, Text, VerticalAlignment,
+};
 use crate::load::{Join, Task};
 use crate::ui::core;
 
@@ -26,14 +30,27 @@ pub struct Renderer {
     pub(crate) sprites: Batch,
     pub(crate) images: Vec<Batch>,
     pub(crate) font: Rc<RefCell<Font>>,
+    pub(crate) overlay_sprites: Batch,
+    pub(crate) overlay_text: Vec<OverlayText>,
     explain_mesh: Mesh,
 }
 
+/// Text drawn on top of every other widget.
+#[derive(Debug)]
+pub(crate) struct OverlayText {
+    pub content: String,
+    pub bounds: Rectangle<f32>,
+    pub color: Color,
+    pub size: f32,
+}
+
 impl std::fmt::Debug for Renderer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Renderer")
             .field("sprites", &self.sprites)
             .field("images", &self.images)
+            .field("overlay_sprites", &self.overlay_sprites)
+            .field("overlay_text", &self.overlay_text)
             .finish()
     }
 }
@@ -45,9 +62,11 @@ impl core::Renderer for Renderer {
         (config.sprites, config.font)
             .join()
             .map(|(sprites, font)| Renderer {
-                sprites: Batch::new(sprites),
+                sprites: Batch::new(sprites.clone()),
                 images: Vec::new(),
                 font: Rc::new(RefCell::new(font)),
+                overlay_sprites: Batch::new(sprites),
+                overlay_text: Vec::new(),
                 explain_mesh: Mesh::new(),
             })
     }
@@ -75,6 +94,29 @@ impl core::Renderer for Renderer {
 
         self.font.borrow_mut().draw(target);
 
+        // Overlays, like the options of an open pick list, are drawn last so
+        // they show up on top of everything else.
+        self.overlay_sprites.draw(target);
+        self.overlay_sprites.clear();
+
+        if !self.overlay_text.is_empty() {
+            let mut font = self.font.borrow_mut();
+
+            for text in self.overlay_text.drain(..) {
+                font.add(Text {
+                    content: &text.content,
+                    position: Point::new(text.bounds.x, text.bounds.y),
+                    bounds: (text.bounds.width, text.bounds.height),
+                    color: text.color,
+                    size: text.size,
+                    horizontal_alignment: HorizontalAlignment::Left,
+                    vertical_alignment: VerticalAlignment::Center,
+                });
+            }
+
+            font.draw(target);
+        }
+
         if !self.explain_mesh.is_empty() {
             self.explain_mesh.draw(target);
             self.explain_mesh = Mesh::new();
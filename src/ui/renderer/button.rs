@@ -61,7 +61,7 @@ impl button::Renderer for Renderer {
                 ..LEFT
             },
             position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -72,6 +72,7 @@ impl button::Renderer for Renderer {
             },
             position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
             scale: (bounds.width - (LEFT.width + RIGHT.width) as f32, 1.0),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -84,7 +85,7 @@ impl button::Renderer for Renderer {
                 bounds.x + bounds.width - RIGHT.width as f32,
                 bounds.y,
             ),
-            scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         self.font.borrow_mut().add(Text {
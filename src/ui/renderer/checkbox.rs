@@ -27,7 +27,7 @@ impl checkbox::Renderer for Renderer {
                 ..SPRITE
             },
             position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         if is_checked {
@@ -37,7 +37,7 @@ impl checkbox::Renderer for Renderer {
                     ..SPRITE
                 },
                 position: Point::new(bounds.x, bounds.y),
-                scale: (1.0, 1.0),
+                ..Sprite::default()
             });
         }
 
@@ -27,6 +27,7 @@ impl image::Renderer for Renderer {
             source,
             position,
             scale,
+            ..Sprite::default()
         });
 
         self.images.push(batch);
@@ -83,6 +83,7 @@ impl panel::Renderer for Renderer {
                 bounds.width - (TOP_LEFT.width + TOP_RIGHT.width) as f32,
                 1.0,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -102,6 +103,7 @@ impl panel::Renderer for Renderer {
                 bounds.height
                     - (TOP_BORDER.height + BOTTOM_BORDER.height) as f32,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -111,6 +113,7 @@ impl panel::Renderer for Renderer {
                 1.0,
                 bounds.height - (TOP_BORDER.height + BOTTOM_LEFT.height) as f32,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -124,6 +127,7 @@ impl panel::Renderer for Renderer {
                 bounds.height
                     - (TOP_BORDER.height + BOTTOM_RIGHT.height) as f32,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -145,6 +149,7 @@ impl panel::Renderer for Renderer {
                 bounds.width - (BOTTOM_LEFT.width + BOTTOM_LEFT.width) as f32,
                 1.0,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -0,0 +1,95 @@
+use crate::graphics::{Color, Point, Rectangle, Sprite};
+use crate::ui::core::MouseCursor;
+use crate::ui::renderer::OverlayText;
+use crate::ui::widget::pick_list;
+use crate::ui::{button, Renderer};
+
+const OPTION_BACKGROUND: Rectangle<u16> = Rectangle {
+    x: 8,
+    y: 8,
+    width: 1,
+    height: 1,
+};
+
+const HOVERED_OPTION_BACKGROUND: Rectangle<u16> = Rectangle {
+    x: 6,
+    y: 34,
+    width: 1,
+    height: 49,
+};
+
+const OPTION_PADDING: f32 = 10.0;
+
+impl pick_list::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &pick_list::State,
+        options: &[String],
+        selected: Option<usize>,
+    ) -> MouseCursor {
+        let label = selected
+            .and_then(|i| options.get(i))
+            .map(String::as_str)
+            .unwrap_or("");
+
+        let mut cursor = button::Renderer::draw(
+            self,
+            cursor_position,
+            bounds,
+            &button::State::new(),
+            label,
+            button::Class::Secondary,
+        );
+
+        if !state.is_open() {
+            return cursor;
+        }
+
+        for (i, option) in options.iter().enumerate() {
+            let option_bounds = pick_list::option_bounds(bounds, i);
+            let mouse_over = option_bounds.contains(cursor_position);
+
+            let source = if mouse_over {
+                cursor = MouseCursor::Pointer;
+
+                HOVERED_OPTION_BACKGROUND
+            } else {
+                OPTION_BACKGROUND
+            };
+
+            self.overlay_sprites.add(Sprite {
+                source,
+                position: Point::new(option_bounds.x, option_bounds.y),
+                scale: (
+                    option_bounds.width / source.width as f32,
+                    option_bounds.height / source.height as f32,
+                ),
+                ..Sprite::default()
+            });
+
+            self.overlay_text.push(OverlayText {
+                content: option.clone(),
+                bounds: Rectangle {
+                    x: option_bounds.x + OPTION_PADDING,
+                    width: option_bounds.width - OPTION_PADDING * 2.0,
+                    ..option_bounds
+                },
+                color: if Some(i) == selected {
+                    Color::WHITE
+                } else {
+                    Color {
+                        r: 0.9,
+                        g: 0.9,
+                        b: 0.9,
+                        a: 1.0,
+                    }
+                },
+                size: 20.0,
+            });
+        }
+
+        cursor
+    }
+}
@@ -72,7 +72,7 @@ fn left_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
             height: LEFT.height,
         },
         position: Point::new(bounds.x, bounds.y),
-        scale: (1.0, 1.0),
+        ..Sprite::default()
     }
 }
 
@@ -85,6 +85,7 @@ fn background_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Spr
         },
         position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
         scale: ((bounds.width - (LEFT.width + RIGHT.width) as f32) * area, 1.0),
+        ..Sprite::default()
     }
 }
 
@@ -100,6 +101,6 @@ fn right_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
             bounds.x + bounds.width - RIGHT.width as f32,
             bounds.y,
         ),
-        scale: (1.0, 1.0),
+        ..Sprite::default()
     }
 }
@@ -26,7 +26,7 @@ impl radio::Renderer for Renderer {
                 ..SPRITE
             },
             position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         if is_selected {
@@ -36,7 +36,7 @@ impl radio::Renderer for Renderer {
                     ..SPRITE
                 },
                 position: Point::new(bounds.x, bounds.y),
-                scale: (1.0, 1.0),
+                ..Sprite::default()
             });
         }
 
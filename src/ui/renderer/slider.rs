@@ -34,6 +34,7 @@ impl slider::Renderer for Renderer {
                 bounds.y + 12.5,
             ),
             scale: (bounds.width - MARKER.width as f32, 1.0),
+            ..Sprite::default()
         });
 
         let (range_start, range_end) = range.into_inner();
@@ -53,7 +54,7 @@ impl slider::Renderer for Renderer {
                 bounds.x + marker_offset.round(),
                 bounds.y + (if state.is_dragging() { 2.0 } else { 0.0 }),
             ),
-            scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         if state.is_dragging() {
@@ -43,9 +43,12 @@ impl text::Renderer for Renderer {
                     ..graphics::Text::default()
                 };
 
-                let (width, height) = font.borrow_mut().measure(text);
+                let measured = font.borrow_mut().measure(&text);
 
-                let size = Size { width, height };
+                let size = Size {
+                    width: measured.width,
+                    height: measured.height,
+                };
 
                 // If the text has no width boundary we avoid caching as the
                 // layout engine may just be measuring text in a row.
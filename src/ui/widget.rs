@@ -29,7 +29,9 @@ mod row;
 pub mod button;
 pub mod checkbox;
 pub mod image;
+pub mod measured;
 pub mod panel;
+pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod slider;
@@ -39,7 +41,9 @@ pub use self::image::Image;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use column::Column;
+pub use measured::Measured;
 pub use panel::Panel;
+pub use pick_list::PickList;
 pub use progress_bar::ProgressBar;
 pub use radio::Radio;
 pub use row::Row;
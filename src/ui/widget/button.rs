@@ -9,7 +9,8 @@
 use crate::graphics::{Point, Rectangle};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Align, Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node,
+    Style, Widget,
 };
 
 use std::hash::Hash;
@@ -142,7 +143,7 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
@@ -155,22 +156,33 @@ where
                         ButtonState::Pressed => {
                             self.state.is_pressed =
                                 bounds.contains(cursor_position);
+
+                            if self.state.is_pressed {
+                                return EventStatus::Captured;
+                            }
                         }
                         ButtonState::Released => {
-                            let is_clicked = self.state.is_pressed
-                                && bounds.contains(cursor_position);
+                            let was_pressed = self.state.is_pressed;
+                            let is_clicked =
+                                was_pressed && bounds.contains(cursor_position);
 
                             self.state.is_pressed = false;
 
                             if is_clicked {
                                 messages.push(on_press);
                             }
+
+                            if was_pressed {
+                                return EventStatus::Captured;
+                            }
                         }
                     }
                 }
             }
             _ => {}
         }
+
+        EventStatus::Ignored
     }
 
     fn draw(
@@ -6,7 +6,8 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Align, Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node,
+    Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -105,7 +106,7 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
@@ -117,10 +118,14 @@ where
 
                 if mouse_over {
                     messages.push((self.on_toggle)(!self.is_checked));
+
+                    return EventStatus::Captured;
                 }
             }
             _ => {}
         }
+
+        EventStatus::Ignored
     }
 
     fn draw(
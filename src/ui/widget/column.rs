@@ -2,8 +2,8 @@ use std::hash::Hash;
 
 use crate::graphics::Point;
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Style,
-    Widget,
+    Align, Element, Event, EventStatus, Hasher, Justify, Layout, MouseCursor,
+    Node, Style, Widget,
 };
 
 /// A container that places its contents vertically.
@@ -168,14 +168,19 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
-        self.children.iter_mut().zip(layout.children()).for_each(
-            |(child, layout)| {
+    ) -> EventStatus {
+        for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+            let status =
                 child
                     .widget
-                    .on_event(event, layout, cursor_position, messages)
-            },
-        );
+                    .on_event(event, layout, cursor_position, messages);
+
+            if status == EventStatus::Captured {
+                return EventStatus::Captured;
+            }
+        }
+
+        EventStatus::Ignored
     }
 
     fn draw(
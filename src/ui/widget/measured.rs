@@ -0,0 +1,147 @@
+//! Read back the computed bounds of a widget.
+//!
+//! A [`Measured`] widget has some local [`State`].
+//!
+//! [`Measured`]: struct.Measured.html
+//! [`State`]: struct.State.html
+use std::cell::Cell;
+
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node, Widget,
+};
+
+/// A wrapper that records the bounds of its content every frame.
+///
+/// The bounds are stored in its [`State`], which you can query in
+/// [`UserInterface::layout`] to adapt the user interface to the size of the
+/// widget during the previous frame. For instance, you could collapse a
+/// sidebar when it becomes too narrow.
+///
+/// A [`Measured`] widget does not affect the layout of its content.
+///
+/// [`State`]: struct.State.html
+/// [`UserInterface::layout`]: ../../trait.UserInterface.html#tymethod.layout
+/// [`Measured`]: struct.Measured.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{measured, Measured, Text};
+///
+/// pub enum Message { /* ... */ }
+///
+/// let state = &measured::State::new();
+///
+/// Measured::<Message>::new(state, Text::new("Measure me!"));
+///
+/// if let Some(bounds) = state.bounds() {
+///     println!("The text was {} pixels wide", bounds.width);
+/// }
+/// ```
+pub struct Measured<'a, Message, Renderer> {
+    state: &'a State,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for Measured<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Measured")
+            .field("state", &self.state)
+            .field("content", &self.content)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Measured<'a, Message, Renderer> {
+    /// Creates a new [`Measured`] widget with the given [`State`] and content.
+    ///
+    /// [`Measured`]: struct.Measured.html
+    /// [`State`]: struct.State.html
+    pub fn new<E>(state: &'a State, content: E) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+    {
+        Measured {
+            state,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Measured<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.content.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) -> EventStatus {
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.state.bounds.set(Some(layout.bounds()));
+
+        self.content.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.content.widget.hash(state);
+    }
+}
+
+/// The local state of a [`Measured`] widget.
+///
+/// [`Measured`]: struct.Measured.html
+#[derive(Debug, Default)]
+pub struct State {
+    bounds: Cell<Option<Rectangle<f32>>>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the bounds of the [`Measured`] widget computed during the last
+    /// frame.
+    ///
+    /// It returns `None` if the widget has not been drawn yet.
+    ///
+    /// [`Measured`]: struct.Measured.html
+    pub fn bounds(&self) -> Option<Rectangle<f32>> {
+        self.bounds.get()
+    }
+}
+
+impl<'a, Message, Renderer> From<Measured<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static,
+    Message: 'static,
+{
+    fn from(
+        measured: Measured<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(measured)
+    }
+}
@@ -3,7 +3,8 @@ use std::hash::Hash;
 
 use crate::graphics::{Point, Rectangle};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node, Style,
+    Widget,
 };
 
 /// A box that can wrap a widget.
@@ -93,15 +94,16 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
-        [&mut self.content]
-            .iter_mut()
-            .zip(layout.children())
-            .for_each(|(child, layout)| {
-                child
-                    .widget
-                    .on_event(event, layout, cursor_position, messages)
-            });
+    ) -> EventStatus {
+        match layout.children().next() {
+            Some(layout) => self.content.widget.on_event(
+                event,
+                layout,
+                cursor_position,
+                messages,
+            ),
+            None => EventStatus::Ignored,
+        }
     }
 
     fn draw(
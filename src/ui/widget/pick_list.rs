@@ -0,0 +1,280 @@
+//! Let your users pick an option from a list.
+//!
+//! A [`PickList`] has some local [`State`].
+//!
+//! [`PickList`]: struct.PickList.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node, Style,
+    Widget,
+};
+
+/// A dropdown that lets the user pick a single option from a list.
+///
+/// When clicked, a [`PickList`] expands a list with all of its options on top
+/// of the rest of the user interface. Picking an option, or clicking anywhere
+/// else, collapses it.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`pick_list::Renderer`] trait.
+///
+/// [`PickList`]: struct.PickList.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`pick_list::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{pick_list, PickList};
+///
+/// pub enum Message {
+///     ResolutionPicked(&'static str),
+/// }
+///
+/// let state = &mut pick_list::State::new();
+/// let resolutions = ["1280x720", "1920x1080", "2560x1440"];
+///
+/// PickList::new(
+///     state,
+///     &resolutions[..],
+///     Some("1920x1080"),
+///     Message::ResolutionPicked,
+/// );
+/// ```
+pub struct PickList<'a, Message, T> {
+    state: &'a mut State,
+    options: &'a [T],
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message>,
+    style: Style,
+}
+
+impl<'a, Message, T> std::fmt::Debug for PickList<'a, Message, T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PickList")
+            .field("state", &self.state)
+            .field("options", &self.options)
+            .field("selected", &self.selected)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'a, Message, T> PickList<'a, Message, T> {
+    /// Creates a new [`PickList`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`PickList`]
+    ///   * the options that can be picked
+    ///   * the currently selected option, if any
+    ///   * a function that will be called when an option is picked. It
+    ///   receives the picked option and must produce a `Message`.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(
+        state: &'a mut State,
+        options: &'a [T],
+        selected: Option<T>,
+        on_select: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        PickList {
+            state,
+            options,
+            selected,
+            on_select: Box::new(on_select),
+            style: Style::default().min_width(100),
+        }
+    }
+
+    /// Sets the width of the [`PickList`] in pixels.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Makes the [`PickList`] fill the horizontal space of its container.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn fill_width(mut self) -> Self {
+        self.style = self.style.fill_width();
+        self
+    }
+}
+
+impl<'a, Message, Renderer, T> Widget<Message, Renderer>
+    for PickList<'a, Message, T>
+where
+    Renderer: self::Renderer,
+    T: Clone + PartialEq + ToString + std::fmt::Debug,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style.height(50))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) -> EventStatus {
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                let bounds = layout.bounds();
+
+                if self.state.is_open {
+                    self.state.is_open = false;
+
+                    let picked = (0..self.options.len()).find(|i| {
+                        option_bounds(bounds, *i).contains(cursor_position)
+                    });
+
+                    if let Some(i) = picked {
+                        messages
+                            .push((self.on_select)(self.options[i].clone()));
+                    }
+
+                    // Clicking anywhere while the list is open only collapses
+                    // it. Widgets underneath the list should not react.
+                    EventStatus::Captured
+                } else if bounds.contains(cursor_position) {
+                    self.state.is_open = true;
+
+                    EventStatus::Captured
+                } else {
+                    EventStatus::Ignored
+                }
+            }
+            _ => EventStatus::Ignored,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let options: Vec<String> =
+            self.options.iter().map(ToString::to_string).collect();
+
+        let selected = self.selected.as_ref().and_then(|selected| {
+            self.options.iter().position(|option| option == selected)
+        });
+
+        renderer.draw(
+            cursor_position,
+            layout.bounds(),
+            self.state,
+            &options,
+            selected,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+}
+
+/// The local state of a [`PickList`].
+///
+/// [`PickList`]: struct.PickList.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_open: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the list of options of the associated [`PickList`] is
+    /// currently expanded or not.
+    ///
+    /// [`PickList`]: struct.PickList.html
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+
+/// Returns the bounds of the option with the given index of an expanded
+/// [`PickList`].
+///
+/// Options are stacked right below the [`PickList`], and each one of them
+/// has its same size. A [`Renderer`] should draw them here.
+///
+/// [`PickList`]: struct.PickList.html
+/// [`Renderer`]: trait.Renderer.html
+pub fn option_bounds(bounds: Rectangle<f32>, index: usize) -> Rectangle<f32> {
+    Rectangle {
+        y: bounds.y + bounds.height * (index + 1) as f32,
+        ..bounds
+    }
+}
+
+/// The renderer of a [`PickList`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`PickList`] in your user interface.
+///
+/// [`PickList`]: struct.PickList.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`PickList`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`PickList`]
+    ///   * the local state of the [`PickList`]
+    ///   * the labels of the options of the [`PickList`]
+    ///   * the index of the selected option, if any
+    ///
+    /// When the [`State`] is open, the options must be drawn on top of any
+    /// other widget, using [`option_bounds`].
+    ///
+    /// [`PickList`]: struct.PickList.html
+    /// [`State`]: struct.State.html
+    /// [`option_bounds`]: fn.option_bounds.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &State,
+        options: &[String],
+        selected: Option<usize>,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer, T> From<PickList<'a, Message, T>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+    T: 'static + Clone + PartialEq + ToString + std::fmt::Debug,
+{
+    fn from(
+        pick_list: PickList<'a, Message, T>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(pick_list)
+    }
+}
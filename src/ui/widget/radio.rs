@@ -4,7 +4,8 @@ use crate::graphics::{
 };
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Align, Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+    Align, Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node,
+    Widget,
 };
 use crate::ui::widget::{text, Column, Row, Text};
 
@@ -125,7 +126,7 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
         match event {
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
@@ -133,10 +134,14 @@ where
             }) => {
                 if layout.bounds().contains(cursor_position) {
                     messages.push(self.on_click);
+
+                    return EventStatus::Captured;
                 }
             }
             _ => {}
         }
+
+        EventStatus::Ignored
     }
 
     fn draw(
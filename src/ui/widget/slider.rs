@@ -10,7 +10,8 @@ use std::ops::RangeInclusive;
 use crate::graphics::{Point, Rectangle};
 use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
-    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+    Element, Event, EventStatus, Hasher, Layout, MouseCursor, Node, Style,
+    Widget,
 };
 
 /// An horizontal bar and a handle that selects a single value from a range of
@@ -114,7 +115,7 @@ where
         layout: Layout<'_>,
         cursor_position: Point,
         messages: &mut Vec<Message>,
-    ) {
+    ) -> EventStatus {
         let mut change = || {
             let bounds = layout.bounds();
 
@@ -140,19 +141,29 @@ where
                     if layout.bounds().contains(cursor_position) {
                         change();
                         self.state.is_dragging = true;
+
+                        return EventStatus::Captured;
                     }
                 }
                 ButtonState::Released => {
-                    self.state.is_dragging = false;
+                    if self.state.is_dragging {
+                        self.state.is_dragging = false;
+
+                        return EventStatus::Captured;
+                    }
                 }
             },
             Event::Mouse(mouse::Event::CursorMoved { .. }) => {
                 if self.state.is_dragging {
                     change();
+
+                    return EventStatus::Captured;
                 }
             }
             _ => {}
         }
+
+        EventStatus::Ignored
     }
 
     fn draw(
@@ -0,0 +1,24 @@
+use coffee::graphics::{Canvas, Color, Gpu};
+use coffee::Result;
+
+#[test]
+fn headless_canvas() -> Result<()> {
+    let mut gpu = match Gpu::headless() {
+        Ok(gpu) => gpu,
+        Err(error) => {
+            // Machines without a display nor a graphics adapter cannot run it
+            eprintln!("Skipping headless test: {}", error);
+            return Ok(());
+        }
+    };
+
+    let mut canvas = Canvas::new(&mut gpu, 4, 4)?;
+    canvas.as_target(&mut gpu).clear(Color::RED);
+
+    let pixels = canvas.read_pixels(&mut gpu).to_rgba();
+
+    assert_eq!(pixels.dimensions(), (4, 4));
+    assert!(pixels.pixels().all(|pixel| pixel.0 == [255, 0, 0, 255]));
+
+    Ok(())
+}